@@ -0,0 +1,109 @@
+use std::collections::BTreeMap;
+
+use indexmap::IndexSet;
+
+use crate::{Level, PlayerState, QuestCheck, QuestRequirement, Skill, UnmetRequirement};
+
+/// A region with an achievement diary.
+///
+/// <https://oldschool.runescape.wiki/w/Achievement_Diary>
+#[derive(
+    Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, strum::Display, strum::EnumIter,
+)]
+pub enum DiaryRegion {
+    Ardougne,
+    Desert,
+    Falador,
+    Fremennik,
+    Kandarin,
+    Karamja,
+    #[strum(serialize = "Kourend & Kebos")]
+    KourendAndKebos,
+    #[strum(serialize = "Lumbridge & Draynor")]
+    LumbridgeAndDraynor,
+    Morytania,
+    Varrock,
+    #[strum(serialize = "Western Provinces")]
+    WesternProvinces,
+    Wilderness,
+}
+
+#[derive(
+    Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, strum::Display, strum::EnumIter,
+)]
+pub enum DiaryTier {
+    Easy,
+    Medium,
+    Hard,
+    Elite,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct DiaryTask {
+    pub name: String,
+    pub requirements: IndexSet<QuestRequirement>,
+}
+
+/// The tasks for a single tier of a region's achievement diary.
+#[derive(Debug)]
+pub struct AchievementDiary {
+    pub region: DiaryRegion,
+    pub tier: DiaryTier,
+    /// The tasks in this tier.
+    ///
+    /// Tasks that only need items or a visit somewhere have no requirements.
+    pub tasks: Vec<DiaryTask>,
+}
+
+impl AchievementDiary {
+    /// Returns the combined requirements of every task in this tier.
+    ///
+    /// Skill requirements are merged so that only the highest level required
+    /// in each skill is included. The merged requirement can only be boosted
+    /// if every requirement in that skill can be.
+    pub fn requirements(&self) -> Vec<QuestRequirement> {
+        let mut skills = BTreeMap::<Skill, (Level, bool)>::new();
+        let mut other = IndexSet::new();
+        for requirement in self.tasks.iter().flat_map(|task| &task.requirements) {
+            match requirement {
+                QuestRequirement::Skill {
+                    skill,
+                    level,
+                    boostable,
+                } => {
+                    let required = skills.entry(*skill).or_insert((*level, *boostable));
+                    *required = (required.0.max(*level), required.1 && *boostable);
+                }
+                _ => {
+                    other.insert(requirement.clone());
+                }
+            }
+        }
+
+        skills
+            .into_iter()
+            .map(|(skill, (level, boostable))| QuestRequirement::Skill {
+                skill,
+                level,
+                boostable,
+            })
+            .chain(other)
+            .collect()
+    }
+
+    /// Checks the combined requirements of this tier against the given player.
+    pub fn check(&self, player: &PlayerState) -> QuestCheck {
+        QuestCheck::new(&self.requirements(), player)
+    }
+
+    /// Checks the combined requirements of this tier against the given player,
+    /// allowing boostable skill requirements to be met with a temporary boost.
+    pub fn check_with_boosts(&self, player: &PlayerState) -> QuestCheck {
+        QuestCheck::with_boosts(&self.requirements(), player)
+    }
+
+    /// Returns the requirements of this tier that the given player has not met.
+    pub fn unmet_requirements(&self, player: &PlayerState) -> Vec<UnmetRequirement> {
+        self.check(player).unmet
+    }
+}
@@ -0,0 +1,30 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, strum::Display, strum::EnumIter)]
+pub enum Activity {
+    GridPoints,
+    LeaguePoints,
+    DeadmanPoints,
+    BountyHunterHunter,
+    BountyHunterRogue,
+    BountyHunterLegacyHunter,
+    BountyHunterLegacyRogue,
+    LastManStanding,
+    PvpArena,
+    SoulWarsZeal,
+}
+
+impl Activity {
+    pub fn name(&self) -> &str {
+        match self {
+            Self::GridPoints => "Grid Points",
+            Self::LeaguePoints => "League Points",
+            Self::DeadmanPoints => "Deadman Points",
+            Self::BountyHunterHunter => "Bounty Hunter - Hunter",
+            Self::BountyHunterRogue => "Bounty Hunter - Rogue",
+            Self::BountyHunterLegacyHunter => "Bounty Hunter (Legacy) - Hunter",
+            Self::BountyHunterLegacyRogue => "Bounty Hunter (Legacy) - Rogue",
+            Self::LastManStanding => "LMS - Rank",
+            Self::PvpArena => "PvP Arena - Rank",
+            Self::SoulWarsZeal => "Soul Wars Zeal",
+        }
+    }
+}
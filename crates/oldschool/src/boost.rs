@@ -0,0 +1,50 @@
+use crate::{Level, Skill};
+
+/// A temporary boost to a skill's level, such as from a potion, stew or pie.
+///
+/// <https://oldschool.runescape.wiki/w/Temporary_skill_boost>
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct Boost {
+    pub name: &'static str,
+    pub skill: Skill,
+    /// The fixed number of levels added by the boost.
+    pub levels: i32,
+    /// The percentage of the base level added on top of [`Boost::levels`].
+    pub percent: i32,
+    /// Whether the boost adds a random amount, in which case
+    /// [`Boost::levels`] is the most it can add.
+    pub random: bool,
+}
+
+impl Boost {
+    pub const fn new(name: &'static str, skill: Skill, levels: i32) -> Self {
+        Self {
+            name,
+            skill,
+            levels,
+            percent: 0,
+            random: false,
+        }
+    }
+
+    pub const fn with_percent(self, percent: i32) -> Self {
+        Self { percent, ..self }
+    }
+
+    pub const fn random(self) -> Self {
+        Self {
+            random: true,
+            ..self
+        }
+    }
+
+    /// Returns the maximum number of levels this boost adds to the given base level.
+    pub fn amount(&self, level: Level) -> i32 {
+        self.levels + level.0 * self.percent / 100
+    }
+
+    /// Returns the given base level with this boost applied.
+    pub fn apply(&self, level: Level) -> Level {
+        Level(level.0 + self.amount(level))
+    }
+}
@@ -1,3 +1,5 @@
+use strum::IntoEnumIterator;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, strum::Display, strum::EnumIter)]
 pub enum Boss {
     AbyssalSire,
@@ -143,4 +145,154 @@ impl Boss {
             Self::Zulrah => "Zulrah",
         }
     }
+
+    /// Returns the category this boss belongs to.
+    pub fn category(&self) -> BossCategory {
+        match self {
+            Self::ChambersOfXeric
+            | Self::ChambersOfXericChallengeMode
+            | Self::TheatreOfBlood
+            | Self::TheatreOfBloodHardMode
+            | Self::TombsOfAmascut
+            | Self::TombsOfAmascutExpertMode => BossCategory::Raid,
+            Self::CommanderZilyana
+            | Self::GeneralGraardor
+            | Self::Kreearra
+            | Self::KrilTsutsaroth
+            | Self::Nex => BossCategory::GodWars,
+            Self::DukeSucellus | Self::TheLeviathan | Self::TheWhisperer | Self::Vardorvis => {
+                BossCategory::DesertTreasureII
+            }
+            Self::Artio
+            | Self::Callisto
+            | Self::Calvarion
+            | Self::ChaosElemental
+            | Self::ChaosFanatic
+            | Self::CrazyArchaeologist
+            | Self::Scorpia
+            | Self::Spindel
+            | Self::Venenatis
+            | Self::Vetion => BossCategory::Wilderness,
+            Self::AbyssalSire
+            | Self::AlchemicalHydra
+            | Self::Araxxor
+            | Self::Cerberus
+            | Self::GrotesqueGuardians
+            | Self::Kraken
+            | Self::ShellbaneGryphon
+            | Self::ThermonuclearSmokeDevil => BossCategory::Slayer,
+            Self::SolHeredit | Self::TzKalZuk | Self::TzTokJad => BossCategory::Challenge,
+            Self::Hespori | Self::Tempoross | Self::Wintertodt | Self::Zalcano => {
+                BossCategory::Skilling
+            }
+            Self::BarrowsChests
+            | Self::LunarChests
+            | Self::Mimic
+            | Self::TheGauntlet
+            | Self::TheCorruptedGauntlet => BossCategory::Minigame,
+            Self::Amoxliatl
+            | Self::Byrophyta
+            | Self::CorporealBeast
+            | Self::DagannothPrime
+            | Self::DagannothRex
+            | Self::DagannothSupreme
+            | Self::DerangedArchaeologist
+            | Self::DoomOfMokhaiotl
+            | Self::GiantMole
+            | Self::KalphiteQueen
+            | Self::KingBlackDragon
+            | Self::Nightmare
+            | Self::PhosanisNightmare
+            | Self::Obor
+            | Self::PhantomMuspah
+            | Self::Sarachnis
+            | Self::Scurrius
+            | Self::Skotizo
+            | Self::TheHueycoatl
+            | Self::TheRoyalTitans
+            | Self::Vorkath
+            | Self::Yama
+            | Self::Zulrah => BossCategory::World,
+        }
+    }
+
+    /// Returns the boss that this boss is a harder or alternate variant of, if any.
+    ///
+    /// For example, the Corrupted Gauntlet is a variant of the Gauntlet, and
+    /// Artio is the singles-plus variant of Callisto.
+    pub fn variant_of(&self) -> Option<Boss> {
+        match self {
+            Self::ChambersOfXericChallengeMode => Some(Self::ChambersOfXeric),
+            Self::TheatreOfBloodHardMode => Some(Self::TheatreOfBlood),
+            Self::TombsOfAmascutExpertMode => Some(Self::TombsOfAmascut),
+            Self::TheCorruptedGauntlet => Some(Self::TheGauntlet),
+            Self::PhosanisNightmare => Some(Self::Nightmare),
+            Self::Artio => Some(Self::Callisto),
+            Self::Calvarion => Some(Self::Vetion),
+            Self::Spindel => Some(Self::Venenatis),
+            _ => None,
+        }
+    }
+
+    /// Returns this boss along with all of its variants.
+    pub fn with_variants(&self) -> Vec<Boss> {
+        std::iter::once(*self)
+            .chain(Self::iter().filter(|boss| boss.variant_of() == Some(*self)))
+            .collect()
+    }
+
+    /// Returns whether this boss is a raid, including its harder modes.
+    pub fn is_raid(&self) -> bool {
+        self.category() == BossCategory::Raid
+    }
+
+    /// Returns whether this boss is fought in the Wilderness.
+    pub fn is_wilderness(&self) -> bool {
+        self.category() == BossCategory::Wilderness
+    }
+}
+
+/// A broad grouping of bosses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, strum::Display, strum::EnumIter)]
+pub enum BossCategory {
+    Raid,
+    #[strum(serialize = "God Wars Dungeon")]
+    GodWars,
+    #[strum(serialize = "Desert Treasure II")]
+    DesertTreasureII,
+    Wilderness,
+    Slayer,
+    /// Bosses fought at the end of a wave-based challenge, such as the Fight Caves.
+    Challenge,
+    Skilling,
+    Minigame,
+    /// Bosses that do not belong to any other category.
+    World,
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_boss_variants() {
+        assert_eq!(
+            Boss::Callisto.with_variants(),
+            vec![Boss::Callisto, Boss::Artio]
+        );
+        assert_eq!(Boss::Artio.with_variants(), vec![Boss::Artio]);
+
+        for boss in Boss::iter() {
+            if let Some(base) = boss.variant_of() {
+                assert_eq!(boss.category(), base.category(), "{boss:?}");
+                assert_eq!(base.variant_of(), None, "{boss:?}");
+            }
+        }
+
+        assert_eq!(Boss::iter().filter(Boss::is_raid).count(), 6);
+        assert!(Boss::Spindel.is_wilderness());
+        assert!(!Boss::Vorkath.is_wilderness());
+    }
 }
@@ -0,0 +1,177 @@
+use std::num::ParseIntError;
+use std::str::FromStr;
+
+use strum::IntoEnumIterator;
+use thiserror::Error;
+
+use crate::{Boss, CombatStyle, Level};
+
+/// The combat stats of a boss.
+///
+/// <https://oldschool.runescape.wiki/w/Monster_stats>
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BossStats {
+    pub boss: Boss,
+    pub hitpoints: u32,
+    pub attack: Level,
+    pub strength: Level,
+    pub defence: Level,
+    pub magic: Level,
+    pub ranged: Level,
+    pub defence_bonuses: DefenceBonuses,
+    pub max_hit: u32,
+    /// The number of game ticks between attacks.
+    pub attack_speed: u8,
+    /// The attack styles that cannot be used against the boss at all.
+    pub immunities: Vec<AttackStyle>,
+}
+
+impl BossStats {
+    /// Returns the attack styles the boss is weakest to.
+    ///
+    /// These are the usable styles with the lowest defence bonus, including
+    /// every style tied for lowest.
+    pub fn weaknesses(&self) -> Vec<AttackStyle> {
+        let usable = AttackStyle::iter().filter(|style| !self.immunities.contains(style));
+        let Some(lowest) = usable
+            .clone()
+            .map(|style| self.defence_bonuses.against(style))
+            .min()
+        else {
+            return Vec::new();
+        };
+
+        usable
+            .filter(|style| self.defence_bonuses.against(*style) == lowest)
+            .collect()
+    }
+
+    /// Returns whether the boss is weak to the given attack style.
+    pub fn is_weak_to(&self, style: AttackStyle) -> bool {
+        self.weaknesses().contains(&style)
+    }
+}
+
+/// A boss's defensive bonuses against each attack style.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct DefenceBonuses {
+    pub stab: i32,
+    pub slash: i32,
+    pub crush: i32,
+    pub magic: i32,
+    pub ranged: i32,
+}
+
+impl DefenceBonuses {
+    /// Returns the defence bonus against the given attack style.
+    pub fn against(&self, style: AttackStyle) -> i32 {
+        match style {
+            AttackStyle::Stab => self.stab,
+            AttackStyle::Slash => self.slash,
+            AttackStyle::Crush => self.crush,
+            AttackStyle::Magic => self.magic,
+            AttackStyle::Ranged => self.ranged,
+        }
+    }
+}
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, strum::Display, strum::EnumIter, strum::EnumString,
+)]
+pub enum AttackStyle {
+    Stab,
+    Slash,
+    Crush,
+    Magic,
+    Ranged,
+}
+
+impl AttackStyle {
+    pub fn combat_style(&self) -> CombatStyle {
+        match self {
+            Self::Stab | Self::Slash | Self::Crush => CombatStyle::Melee,
+            Self::Magic => CombatStyle::Magic,
+            Self::Ranged => CombatStyle::Ranged,
+        }
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum ParseBossStatsError {
+    #[error("expected {expected} columns, found {found}")]
+    ColumnCount { expected: usize, found: usize },
+    #[error("unknown boss: {0:?}")]
+    UnknownBoss(String),
+    #[error("unknown attack style: {0:?}")]
+    UnknownAttackStyle(String),
+    #[error(transparent)]
+    InvalidNumber(#[from] ParseIntError),
+}
+
+impl FromStr for BossStats {
+    type Err = ParseBossStatsError;
+
+    /// Parses [`BossStats`] from a row of the bundled boss stats dataset.
+    ///
+    /// Columns are comma-separated, and multiple immunities are separated by semicolons.
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        const COLUMNS: usize = 15;
+
+        let columns = text.split(',').map(str::trim).collect::<Vec<_>>();
+        let &[
+            name,
+            hitpoints,
+            attack,
+            strength,
+            defence,
+            magic,
+            ranged,
+            stab_defence,
+            slash_defence,
+            crush_defence,
+            magic_defence,
+            ranged_defence,
+            max_hit,
+            attack_speed,
+            immunities,
+        ] = columns.as_slice()
+        else {
+            return Err(ParseBossStatsError::ColumnCount {
+                expected: COLUMNS,
+                found: columns.len(),
+            });
+        };
+
+        let boss = Boss::iter()
+            .find(|boss| boss.name() == name)
+            .ok_or_else(|| ParseBossStatsError::UnknownBoss(name.to_string()))?;
+        let level = |level: &str| level.parse().map(Level);
+
+        Ok(Self {
+            boss,
+            hitpoints: hitpoints.parse()?,
+            attack: level(attack)?,
+            strength: level(strength)?,
+            defence: level(defence)?,
+            magic: level(magic)?,
+            ranged: level(ranged)?,
+            defence_bonuses: DefenceBonuses {
+                stab: stab_defence.parse()?,
+                slash: slash_defence.parse()?,
+                crush: crush_defence.parse()?,
+                magic: magic_defence.parse()?,
+                ranged: ranged_defence.parse()?,
+            },
+            max_hit: max_hit.parse()?,
+            attack_speed: attack_speed.parse()?,
+            immunities: immunities
+                .split(';')
+                .filter(|immunity| !immunity.is_empty())
+                .map(|immunity| {
+                    AttackStyle::from_str(immunity)
+                        .map_err(|_| ParseBossStatsError::UnknownAttackStyle(immunity.to_string()))
+                })
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
@@ -0,0 +1,224 @@
+use crate::{Hiscore, Level, Skill};
+
+/// The skills that contribute to a player's combat level.
+pub const COMBAT_SKILLS: [Skill; 7] = [
+    Skill::Attack,
+    Skill::Strength,
+    Skill::Defence,
+    Skill::Hitpoints,
+    Skill::Ranged,
+    Skill::Magic,
+    Skill::Prayer,
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, strum::Display, strum::EnumIter)]
+pub enum CombatStyle {
+    Melee,
+    Ranged,
+    Magic,
+}
+
+/// A player's combat level, computed from their combat skill levels.
+///
+/// <https://oldschool.runescape.wiki/w/Combat_level>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CombatLevel {
+    pub attack: Level,
+    pub strength: Level,
+    pub defence: Level,
+    pub hitpoints: Level,
+    pub ranged: Level,
+    pub magic: Level,
+    pub prayer: Level,
+}
+
+impl CombatLevel {
+    /// Returns the [`CombatLevel`] for the given skill levels.
+    pub fn new(level: impl Fn(Skill) -> Level) -> Self {
+        Self {
+            attack: level(Skill::Attack),
+            strength: level(Skill::Strength),
+            defence: level(Skill::Defence),
+            hitpoints: level(Skill::Hitpoints),
+            ranged: level(Skill::Ranged),
+            magic: level(Skill::Magic),
+            prayer: level(Skill::Prayer),
+        }
+    }
+
+    /// Returns the [`CombatLevel`] for the skill levels in the given [`Hiscore`].
+    ///
+    /// Skills that are unranked are assumed to be at their starting level.
+    pub fn from_hiscore(hiscore: &Hiscore) -> Self {
+        Self::new(|skill| {
+            let starting_level = skill.starting_level();
+
+            hiscore
+                .skill(skill)
+                .map(|entry| Level(entry.level))
+                .map_or(starting_level, |level| level.max(starting_level))
+        })
+    }
+
+    /// Returns the level of the given skill, if it is a combat skill.
+    pub fn skill(&self, skill: Skill) -> Option<Level> {
+        match skill {
+            Skill::Attack => Some(self.attack),
+            Skill::Strength => Some(self.strength),
+            Skill::Defence => Some(self.defence),
+            Skill::Hitpoints => Some(self.hitpoints),
+            Skill::Ranged => Some(self.ranged),
+            Skill::Magic => Some(self.magic),
+            Skill::Prayer => Some(self.prayer),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn with_skill(mut self, skill: Skill, level: Level) -> Self {
+        match skill {
+            Skill::Attack => self.attack = level,
+            Skill::Strength => self.strength = level,
+            Skill::Defence => self.defence = level,
+            Skill::Hitpoints => self.hitpoints = level,
+            Skill::Ranged => self.ranged = level,
+            Skill::Magic => self.magic = level,
+            Skill::Prayer => self.prayer = level,
+            _ => {}
+        }
+
+        self
+    }
+
+    /// Returns the exact combat level, including the fractional part.
+    pub fn exact(&self) -> f64 {
+        self.scaled() as f64 / Self::SCALE as f64
+    }
+
+    /// Returns the combat level, as displayed in-game.
+    pub fn level(&self) -> Level {
+        Level(self.scaled() / Self::SCALE)
+    }
+
+    /// Returns the combat style that contributes the most to the combat level.
+    ///
+    /// Ties are broken in favor of melee, then ranged.
+    pub fn dominant_style(&self) -> CombatStyle {
+        let (melee, ranged, magic) = self.scaled_styles();
+
+        if melee >= ranged && melee >= magic {
+            CombatStyle::Melee
+        } else if ranged >= magic {
+            CombatStyle::Ranged
+        } else {
+            CombatStyle::Magic
+        }
+    }
+
+    /// Returns the number of levels needed in each combat skill (on its own)
+    /// to reach the next combat level.
+    ///
+    /// Skills that cannot reach the next combat level before level 99 are omitted.
+    pub fn levels_to_next(&self) -> Vec<(Skill, i32)> {
+        let next_level = self.level() + Level(1);
+
+        COMBAT_SKILLS
+            .into_iter()
+            .filter_map(|skill| {
+                let current = self.skill(skill)?;
+
+                (current.0 + 1..=99)
+                    .find(|level| self.with_skill(skill, Level(*level)).level() >= next_level)
+                    .map(|level| (skill, level - current.0))
+            })
+            .collect()
+    }
+
+    /// The combat level formula uses multiples of `1/4` and `13/40`, so the
+    /// intermediate values are scaled by 40 to keep them as integers.
+    const SCALE: i32 = 40;
+
+    fn scaled(&self) -> i32 {
+        let base = 10 * (self.defence.0 + self.hitpoints.0 + self.prayer.0 / 2);
+        let (melee, ranged, magic) = self.scaled_styles();
+
+        base + melee.max(ranged).max(magic)
+    }
+
+    fn scaled_styles(&self) -> (i32, i32, i32) {
+        let melee = 13 * (self.attack.0 + self.strength.0);
+        let ranged = 13 * (self.ranged.0 * 3 / 2);
+        let magic = 13 * (self.magic.0 * 3 / 2);
+
+        (melee, ranged, magic)
+    }
+}
+
+impl Default for CombatLevel {
+    fn default() -> Self {
+        Self::new(|skill| skill.starting_level())
+    }
+}
+
+impl From<&Hiscore> for CombatLevel {
+    fn from(hiscore: &Hiscore) -> Self {
+        Self::from_hiscore(hiscore)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_combat_level() {
+        let starting = CombatLevel::default();
+        assert_eq!(starting.level(), Level(3));
+        assert_eq!(starting.exact(), 3.4);
+        assert_eq!(starting.dominant_style(), CombatStyle::Melee);
+
+        let maxed = CombatLevel::new(|_| Level(99));
+        assert_eq!(maxed.level(), Level(126));
+        assert_eq!(maxed.exact(), 126.1);
+
+        let hiscore = Hiscore::from_str(include_str!("../fixtures/hiscores_1.txt")).unwrap();
+        assert_eq!(CombatLevel::from_hiscore(&hiscore), maxed);
+    }
+
+    #[test]
+    fn test_combat_style() {
+        let ranger = CombatLevel {
+            ranged: Level(70),
+            ..CombatLevel::default()
+        };
+        assert_eq!(ranger.dominant_style(), CombatStyle::Ranged);
+        assert_eq!(ranger.level(), Level(36));
+
+        let mager = CombatLevel {
+            magic: Level(70),
+            ..ranger
+        };
+        assert_eq!(mager.dominant_style(), CombatStyle::Ranged);
+    }
+
+    #[test]
+    fn test_levels_to_next() {
+        assert_eq!(
+            CombatLevel::default().levels_to_next(),
+            vec![
+                (Skill::Attack, 2),
+                (Skill::Strength, 2),
+                (Skill::Defence, 3),
+                (Skill::Hitpoints, 3),
+                (Skill::Ranged, 2),
+                (Skill::Magic, 2),
+                (Skill::Prayer, 5),
+            ]
+        );
+
+        assert_eq!(CombatLevel::new(|_| Level(99)).levels_to_next(), vec![]);
+    }
+}
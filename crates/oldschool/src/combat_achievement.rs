@@ -0,0 +1,135 @@
+use crate::Boss;
+use indexmap::IndexSet;
+
+/// A Combat Achievement task.
+///
+/// <https://oldschool.runescape.wiki/w/Combat_Achievements>
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct CombatAchievement {
+    pub name: &'static str,
+    pub boss: Boss,
+    pub tier: CombatAchievementTier,
+    pub kind: CombatAchievementType,
+}
+
+impl CombatAchievement {
+    pub const fn new(
+        name: &'static str,
+        boss: Boss,
+        tier: CombatAchievementTier,
+        kind: CombatAchievementType,
+    ) -> Self {
+        Self {
+            name,
+            boss,
+            tier,
+            kind,
+        }
+    }
+
+    pub const fn id(&self) -> CombatAchievementId {
+        CombatAchievementId(self.name)
+    }
+
+    /// Returns the number of points awarded for completing this task.
+    pub fn points(&self) -> u32 {
+        self.tier.points_per_task()
+    }
+}
+
+/// Identifies a catalogued Combat Achievement task.
+///
+/// Ids can only be obtained from the catalogue, either with
+/// [`CombatAchievement::id`] or by parsing a task name.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct CombatAchievementId(&'static str);
+
+impl CombatAchievementId {
+    pub const fn as_str(&self) -> &'static str {
+        self.0
+    }
+}
+
+#[derive(
+    Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, strum::Display, strum::EnumIter,
+)]
+pub enum CombatAchievementTier {
+    Easy,
+    Medium,
+    Hard,
+    Elite,
+    Master,
+    Grandmaster,
+}
+
+impl CombatAchievementTier {
+    /// Returns the number of points awarded for each task in this tier.
+    pub const fn points_per_task(&self) -> u32 {
+        match self {
+            Self::Easy => 1,
+            Self::Medium => 2,
+            Self::Hard => 3,
+            Self::Elite => 4,
+            Self::Master => 5,
+            Self::Grandmaster => 6,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, strum::Display, strum::EnumIter)]
+pub enum CombatAchievementType {
+    #[strum(serialize = "Kill Count")]
+    KillCount,
+    Restriction,
+    Speed,
+    Mechanical,
+    Perfection,
+}
+
+/// A player's progress towards their Combat Achievements.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct CombatAchievementProgress {
+    /// The completed tasks.
+    pub completed: IndexSet<CombatAchievementId>,
+}
+
+impl CombatAchievementProgress {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn has_completed(&self, task: &CombatAchievement) -> bool {
+        self.completed.contains(&task.id())
+    }
+
+    /// Returns the total number of points from completed tasks.
+    ///
+    /// Only catalogued tasks can be completed, so this is not yet comparable
+    /// with [`CombatAchievementTier::points_required`].
+    pub fn points(&self) -> u32 {
+        CombatAchievement::all()
+            .iter()
+            .filter(|task| self.has_completed(task))
+            .map(CombatAchievement::points)
+            .sum()
+    }
+
+    /// Returns the tasks for the given boss that have been completed, or `None`
+    /// if its tasks are not catalogued.
+    pub fn completed_for(
+        &self,
+        boss: Boss,
+    ) -> Option<impl Iterator<Item = &'static CombatAchievement>> {
+        CombatAchievement::for_boss(boss).map(|tasks| tasks.filter(|task| self.has_completed(task)))
+    }
+
+    /// Returns the tasks for the given boss that have not been completed, or
+    /// `None` if its tasks are not catalogued.
+    pub fn remaining_for(
+        &self,
+        boss: Boss,
+    ) -> Option<impl Iterator<Item = &'static CombatAchievement>> {
+        CombatAchievement::for_boss(boss)
+            .map(|tasks| tasks.filter(|task| !self.has_completed(task)))
+    }
+}
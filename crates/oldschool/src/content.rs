@@ -1 +1,6 @@
+pub mod achievement_diaries;
+pub mod boosts;
+pub mod boss_stats;
+pub mod combat_achievements;
+pub mod miniquests;
 pub mod quests;
@@ -0,0 +1,2789 @@
+use std::collections::HashMap;
+use std::sync::{Arc, LazyLock};
+
+use indexmap::IndexSet;
+use strum::IntoEnumIterator;
+
+use super::quests::{
+    combat_level_req, kudos_req, miniquest_req, quest_point_req, quest_req, skill_req,
+    skill_req_unboostable,
+};
+use crate::{
+    AchievementDiary, DiaryRegion, DiaryTask, DiaryTier, Level, MiniquestId, QuestId,
+    QuestRequirement, Skill,
+};
+
+impl AchievementDiary {
+    /// Returns the given tier of the given region's achievement diary.
+    pub fn get(region: DiaryRegion, tier: DiaryTier) -> Arc<AchievementDiary> {
+        DIARIES[&(region, tier)].clone()
+    }
+
+    /// Returns every tier of every region's achievement diary.
+    pub fn all() -> impl Iterator<Item = Arc<AchievementDiary>> {
+        DiaryRegion::iter()
+            .flat_map(|region| DiaryTier::iter().map(move |tier| Self::get(region, tier)))
+    }
+}
+
+static DIARIES: LazyLock<HashMap<(DiaryRegion, DiaryTier), Arc<AchievementDiary>>> =
+    LazyLock::new(make_diaries);
+
+fn make_diaries() -> HashMap<(DiaryRegion, DiaryTier), Arc<AchievementDiary>> {
+    let diaries = [
+        ardougne_easy(),
+        ardougne_medium(),
+        ardougne_hard(),
+        ardougne_elite(),
+        desert_easy(),
+        desert_medium(),
+        desert_hard(),
+        desert_elite(),
+        falador_easy(),
+        falador_medium(),
+        falador_hard(),
+        falador_elite(),
+        fremennik_easy(),
+        fremennik_medium(),
+        fremennik_hard(),
+        fremennik_elite(),
+        kandarin_easy(),
+        kandarin_medium(),
+        kandarin_hard(),
+        kandarin_elite(),
+        karamja_easy(),
+        karamja_medium(),
+        karamja_hard(),
+        karamja_elite(),
+        kourend_and_kebos_easy(),
+        kourend_and_kebos_medium(),
+        kourend_and_kebos_hard(),
+        kourend_and_kebos_elite(),
+        lumbridge_and_draynor_easy(),
+        lumbridge_and_draynor_medium(),
+        lumbridge_and_draynor_hard(),
+        lumbridge_and_draynor_elite(),
+        morytania_easy(),
+        morytania_medium(),
+        morytania_hard(),
+        morytania_elite(),
+        varrock_easy(),
+        varrock_medium(),
+        varrock_hard(),
+        varrock_elite(),
+        western_provinces_easy(),
+        western_provinces_medium(),
+        western_provinces_hard(),
+        western_provinces_elite(),
+        wilderness_easy(),
+        wilderness_medium(),
+        wilderness_hard(),
+        wilderness_elite(),
+    ];
+
+    diaries
+        .into_iter()
+        .map(|diary| ((diary.region, diary.tier), Arc::new(diary)))
+        .collect()
+}
+
+fn task(name: &str, requirements: impl IntoIterator<Item = QuestRequirement>) -> DiaryTask {
+    DiaryTask {
+        name: name.to_string(),
+        requirements: IndexSet::from_iter(requirements),
+    }
+}
+
+/// Requires every quest to have been completed, as for wearing the Quest point cape.
+fn quest_cape_req() -> QuestRequirement {
+    QuestRequirement::All(QuestId::all().iter().cloned().map(quest_req).collect())
+}
+
+/// Requires either a level 99 skill or the Quest point cape.
+fn skillcape_or_quest_cape_req() -> QuestRequirement {
+    QuestRequirement::Any(
+        Skill::iter()
+            .map(|skill| skill_req(skill, Level::MAX))
+            .chain([quest_cape_req()])
+            .collect(),
+    )
+}
+
+fn ardougne_easy() -> AchievementDiary {
+    AchievementDiary {
+        region: DiaryRegion::Ardougne,
+        tier: DiaryTier::Easy,
+        tasks: vec![
+            task(
+                "Have Wizard Cromperty teleport you to the Rune essence mine",
+                [quest_req(QuestId::RUNE_MYSTERIES)],
+            ),
+            task(
+                "Steal a cake from the East Ardougne market stalls",
+                [skill_req(Skill::Thieving, Level(5))],
+            ),
+            task(
+                "Sell silk to the Silk trader in East Ardougne for 60 coins each",
+                [],
+            ),
+            task("Use the altar in East Ardougne's church", []),
+            task(
+                "Go out fishing on the Fishing Trawler",
+                [skill_req(Skill::Fishing, Level(15))],
+            ),
+            task(
+                "Enter the Combat Training Camp north of West Ardougne",
+                [quest_req(QuestId::BIOHAZARD)],
+            ),
+            task("Have Tindel Marchant identify a rusty sword for you", []),
+            task("Use the Ardougne lever to teleport to the Wilderness", []),
+            task("View Aleck's Hunter Emporium in Yanille", []),
+            task(
+                "Check what pets you have insured with Probita in East Ardougne",
+                [],
+            ),
+        ],
+    }
+}
+
+fn ardougne_medium() -> AchievementDiary {
+    AchievementDiary {
+        region: DiaryRegion::Ardougne,
+        tier: DiaryTier::Medium,
+        tasks: vec![
+            task(
+                "Enter the unicorn pen in Ardougne zoo using Fairy rings",
+                [quest_req(QuestId::FAIRYTALE_II)],
+            ),
+            task(
+                "Grapple over Yanille's south wall",
+                [
+                    skill_req(Skill::Agility, Level(39)),
+                    skill_req(Skill::Strength, Level(38)),
+                    skill_req(Skill::Ranged, Level(21)),
+                ],
+            ),
+            task(
+                "Harvest some strawberries from the Ardougne farming patch",
+                [skill_req(Skill::Farming, Level(31))],
+            ),
+            task(
+                "Cast the Ardougne Teleport spell",
+                [
+                    skill_req(Skill::Magic, Level(51)),
+                    quest_req(QuestId::PLAGUE_CITY),
+                ],
+            ),
+            task(
+                "Travel to Castle Wars by Hot Air Balloon",
+                [
+                    skill_req(Skill::Firemaking, Level(50)),
+                    quest_req(QuestId::ENLIGHTENED_JOURNEY),
+                ],
+            ),
+            task(
+                "Claim buckets of sand from Bert in Yanille",
+                [
+                    skill_req(Skill::Crafting, Level(49)),
+                    quest_req(QuestId::THE_HAND_IN_THE_SAND),
+                ],
+            ),
+            task(
+                "Catch any fish on the Fishing Platform",
+                [quest_req(QuestId::SEA_SLUG)],
+            ),
+            task(
+                "Pickpocket the master farmer north of East Ardougne",
+                [skill_req(Skill::Thieving, Level(38))],
+            ),
+            task(
+                "Collect some cave nightshade from the Skavid caves",
+                [quest_req(QuestId::WATCHTOWER)],
+            ),
+            task(
+                "Kill a swordchick in the Tower of Life",
+                [quest_req(QuestId::TOWER_OF_LIFE)],
+            ),
+            task(
+                "Equip Iban's upgraded staff or upgrade an Iban staff",
+                [
+                    skill_req(Skill::Magic, Level(50)),
+                    skill_req(Skill::Attack, Level(50)),
+                    quest_req(QuestId::UNDERGROUND_PASS),
+                ],
+            ),
+            task(
+                "Visit the island east of the Necromancer tower",
+                [quest_req(QuestId::FAIRYTALE_II)],
+            ),
+        ],
+    }
+}
+
+fn ardougne_hard() -> AchievementDiary {
+    AchievementDiary {
+        region: DiaryRegion::Ardougne,
+        tier: DiaryTier::Hard,
+        tasks: vec![
+            task(
+                "Recharge some jewellery at the Totem pole in the Legends' Guild",
+                [quest_req(QuestId::LEGENDS_QUEST)],
+            ),
+            task(
+                "Enter the Magic Guild",
+                [skill_req(Skill::Magic, Level(66))],
+            ),
+            task(
+                "Attempt to steal from a chest in Ardougne Castle",
+                [skill_req(Skill::Thieving, Level(72))],
+            ),
+            task(
+                "Have a zookeeper put you in Ardougne Zoo's monkey cage",
+                [quest_req(QuestId::MONKEY_MADNESS_I)],
+            ),
+            task(
+                "Teleport to the Watchtower",
+                [
+                    skill_req(Skill::Magic, Level(58)),
+                    quest_req(QuestId::WATCHTOWER),
+                ],
+            ),
+            task(
+                "Catch a red salamander",
+                [skill_req(Skill::Hunter, Level(59))],
+            ),
+            task(
+                "Check the health of a palm tree near Tree Gnome Village",
+                [skill_req(Skill::Farming, Level(68))],
+            ),
+            task(
+                "Pick some poison ivy berries from the patch south of East Ardougne",
+                [skill_req(Skill::Farming, Level(70))],
+            ),
+            task(
+                "Smith a mithril platebody near Ardougne",
+                [skill_req(Skill::Smithing, Level(68))],
+            ),
+            task(
+                "Enter your POH from Yanille",
+                [skill_req(Skill::Construction, Level(50))],
+            ),
+            task(
+                "Smith a dragon sq shield in West Ardougne",
+                [
+                    skill_req(Skill::Smithing, Level(60)),
+                    quest_req(QuestId::LEGENDS_QUEST),
+                ],
+            ),
+            task(
+                "Craft some death runes at the Death altar",
+                [
+                    skill_req(Skill::Runecraft, Level(65)),
+                    quest_req(QuestId::MOURNINGS_END_PART_II),
+                ],
+            ),
+        ],
+    }
+}
+
+fn ardougne_elite() -> AchievementDiary {
+    AchievementDiary {
+        region: DiaryRegion::Ardougne,
+        tier: DiaryTier::Elite,
+        tasks: vec![
+            task(
+                "Catch a manta ray in the Fishing Trawler and cook it in Port Khazard",
+                [
+                    skill_req(Skill::Fishing, Level(81)),
+                    skill_req(Skill::Cooking, Level(91)),
+                ],
+            ),
+            task(
+                "Successfully picklock the door to the basement of Yanille Agility Dungeon",
+                [skill_req(Skill::Thieving, Level(82))],
+            ),
+            task("Pickpocket a hero", [skill_req(Skill::Thieving, Level(80))]),
+            task(
+                "Make a rune crossbow yourself from scratch within Witchaven or Yanille",
+                [
+                    skill_req(Skill::Crafting, Level(10)),
+                    skill_req(Skill::Smithing, Level(91)),
+                    skill_req(Skill::Fletching, Level(69)),
+                ],
+            ),
+            task(
+                "Imbue a salve amulet at Nightmare Zone or equip an imbued salve amulet",
+                [quest_req(QuestId::HAUNTED_MINE)],
+            ),
+            task(
+                "Pick some torstol from the patch north of East Ardougne",
+                [skill_req(Skill::Farming, Level(85))],
+            ),
+            task(
+                "Complete a lap of Ardougne's rooftop agility course",
+                [skill_req(Skill::Agility, Level(90))],
+            ),
+            task(
+                "Cast Ice Barrage on another player within Castle Wars",
+                [
+                    skill_req(Skill::Magic, Level(94)),
+                    quest_req(QuestId::DESERT_TREASURE_I),
+                ],
+            ),
+        ],
+    }
+}
+
+fn desert_easy() -> AchievementDiary {
+    AchievementDiary {
+        region: DiaryRegion::Desert,
+        tier: DiaryTier::Easy,
+        tasks: vec![
+            task(
+                "Catch a golden warbler",
+                [skill_req(Skill::Hunter, Level(5))],
+            ),
+            task("Mine 5 clay in the north-eastern desert", []),
+            task("Enter the Kalphite Lair", []),
+            task("Enter the Desert with a set of desert robes equipped", []),
+            task("Kill a vulture", []),
+            task("Have the Nardah herbalist clean a herb for you", []),
+            task("Collect 5 potato cacti from the Kalphite Hive", []),
+            task("Sell some artefacts to Simon Templeton", []),
+            task(
+                "Open the sarcophagus in the first room of Pyramid Plunder",
+                [
+                    skill_req(Skill::Thieving, Level(21)),
+                    quest_req(QuestId::ICTHLARINS_LITTLE_HELPER),
+                ],
+            ),
+            task("Cut a desert cactus open to fill a waterskin", []),
+            task(
+                "Travel from the Shantay Pass to Pollnivneach by magic carpet",
+                [],
+            ),
+        ],
+    }
+}
+
+fn desert_medium() -> AchievementDiary {
+    AchievementDiary {
+        region: DiaryRegion::Desert,
+        tier: DiaryTier::Medium,
+        tasks: vec![
+            task(
+                "Climb to the summit of the Agility Pyramid",
+                [skill_req(Skill::Agility, Level(30))],
+            ),
+            task(
+                "Slay a desert lizard",
+                [skill_req(Skill::Slayer, Level(22))],
+            ),
+            task(
+                "Catch an orange salamander",
+                [skill_req(Skill::Hunter, Level(47))],
+            ),
+            task(
+                "Steal a feather from the Desert Phoenix",
+                [skill_req(Skill::Thieving, Level(25))],
+            ),
+            task(
+                "Travel to Uzer via magic carpet",
+                [quest_req(QuestId::THE_GOLEM)],
+            ),
+            task(
+                "Travel to the Desert via the Eagle transport system",
+                [quest_req(QuestId::EAGLES_PEAK)],
+            ),
+            task(
+                "Pray at the Elidinis statuette in Nardah",
+                [quest_req(QuestId::SPIRITS_OF_THE_ELID)],
+            ),
+            task(
+                "Create a combat potion in the Desert",
+                [skill_req(Skill::Herblore, Level(36))],
+            ),
+            task(
+                "Teleport to Enakhra's Temple with the Camulet",
+                [quest_req(QuestId::ENAKHRAS_LAMENT)],
+            ),
+            task("Visit the genie", [quest_req(QuestId::SPIRITS_OF_THE_ELID)]),
+            task(
+                "Teleport to Pollnivneach with a redirected teleport to house tablet",
+                [skill_req(Skill::Construction, Level(20))],
+            ),
+            task(
+                "Chop some teak logs near Uzer",
+                [skill_req(Skill::Woodcutting, Level(35))],
+            ),
+        ],
+    }
+}
+
+fn desert_hard() -> AchievementDiary {
+    AchievementDiary {
+        region: DiaryRegion::Desert,
+        tier: DiaryTier::Hard,
+        tasks: vec![
+            task(
+                "Knock out and pickpocket a Menaphite thug",
+                [
+                    skill_req(Skill::Thieving, Level(65)),
+                    quest_req(QuestId::THE_FEUD),
+                ],
+            ),
+            task("Mine some granite", [skill_req(Skill::Mining, Level(45))]),
+            task(
+                "Refill your waterskins in the Desert using Lunar magic",
+                [
+                    skill_req(Skill::Magic, Level(68)),
+                    quest_req(QuestId::DREAM_MENTOR),
+                ],
+            ),
+            task("Kill the Kalphite Queen", []),
+            task(
+                "Complete a lap of the Pollnivneach agility course",
+                [skill_req(Skill::Agility, Level(70))],
+            ),
+            task(
+                "Slay a dust devil in the desert cave with a Slayer helmet equipped",
+                [
+                    skill_req(Skill::Slayer, Level(65)),
+                    skill_req(Skill::Defence, Level(10)),
+                    skill_req(Skill::Crafting, Level(55)),
+                ],
+            ),
+            task(
+                "Activate Ancient Magicks at the altar in the Jaldraocht Pyramid",
+                [quest_req(QuestId::DESERT_TREASURE_I)],
+            ),
+            task(
+                "Defeat a locust rider with Keris",
+                [
+                    skill_req(Skill::Attack, Level(50)),
+                    quest_req(QuestId::CONTACT),
+                ],
+            ),
+            task(
+                "Burn some yew logs on the Nardah Mayor's balcony",
+                [skill_req(Skill::Firemaking, Level(60))],
+            ),
+            task(
+                "Create a mithril platebody in Nardah",
+                [skill_req(Skill::Smithing, Level(68))],
+            ),
+        ],
+    }
+}
+
+fn desert_elite() -> AchievementDiary {
+    AchievementDiary {
+        region: DiaryRegion::Desert,
+        tier: DiaryTier::Elite,
+        tasks: vec![
+            task(
+                "Bake a wild pie at the Nardah clay oven",
+                [skill_req(Skill::Cooking, Level(85))],
+            ),
+            task(
+                "Cast Ice Barrage against a foe in the Desert",
+                [
+                    skill_req(Skill::Magic, Level(94)),
+                    quest_req(QuestId::DESERT_TREASURE_I),
+                ],
+            ),
+            task(
+                "Fletch some dragon darts at the Bedabin Camp",
+                [
+                    skill_req(Skill::Fletching, Level(95)),
+                    quest_req(QuestId::THE_TOURIST_TRAP),
+                ],
+            ),
+            task(
+                "Speak to the KQ head in your POH",
+                [
+                    skill_req(Skill::Construction, Level(78)),
+                    quest_req(QuestId::PRIEST_IN_PERIL),
+                ],
+            ),
+            task(
+                "Steal from the Grand Gold Chest in the final room of Pyramid Plunder",
+                [
+                    skill_req(Skill::Thieving, Level(91)),
+                    quest_req(QuestId::ICTHLARINS_LITTLE_HELPER),
+                ],
+            ),
+            task(
+                "Restore at least 85 Prayer points when praying at the altar in Sophanem",
+                [
+                    skill_req_unboostable(Skill::Prayer, Level(85)),
+                    quest_req(QuestId::ICTHLARINS_LITTLE_HELPER),
+                ],
+            ),
+        ],
+    }
+}
+
+fn falador_easy() -> AchievementDiary {
+    AchievementDiary {
+        region: DiaryRegion::Falador,
+        tier: DiaryTier::Easy,
+        tasks: vec![
+            task(
+                "Find out what your family crest is from Sir Renitee",
+                [skill_req(Skill::Construction, Level(16))],
+            ),
+            task(
+                "Climb over the western Falador wall",
+                [skill_req(Skill::Agility, Level(5))],
+            ),
+            task("Browse Sarah's Farming Shop", []),
+            task("Get a haircut from the Falador hairdresser", []),
+            task("Fill a bucket from the pump north of Falador West Bank", []),
+            task("Kill a duck in Falador Park", []),
+            task("Make a mind tiara", [quest_req(QuestId::RUNE_MYSTERIES)]),
+            task("Take the boat to Entrana", []),
+            task("Repair a broken strut in the Motherlode Mine", []),
+            task(
+                "Claim a security book from the Security Guard upstairs at Port Sarim jail",
+                [],
+            ),
+            task(
+                "Smith some blurite limbs on Doric's anvil",
+                [
+                    skill_req(Skill::Mining, Level(10)),
+                    skill_req(Skill::Smithing, Level(13)),
+                    quest_req(QuestId::THE_KNIGHTS_SWORD),
+                    quest_req(QuestId::DORICS_QUEST),
+                ],
+            ),
+        ],
+    }
+}
+
+fn falador_medium() -> AchievementDiary {
+    AchievementDiary {
+        region: DiaryRegion::Falador,
+        tier: DiaryTier::Medium,
+        tasks: vec![
+            task(
+                "Light a bullseye lantern at the Chemist's in Rimmington",
+                [skill_req(Skill::Firemaking, Level(49))],
+            ),
+            task(
+                "Telegrab some Wine of Zamorak at the Chaos Temple by the Wilderness",
+                [skill_req(Skill::Magic, Level(33))],
+            ),
+            task("Unlock the crystal chest in Taverley", []),
+            task(
+                "Place a scarecrow in the Falador farm flower patch",
+                [skill_req(Skill::Farming, Level(23))],
+            ),
+            task(
+                "Kill a mogre at Mudskipper Point",
+                [
+                    skill_req(Skill::Slayer, Level(32)),
+                    miniquest_req(MiniquestId::SKIPPY_AND_THE_MOGRES),
+                ],
+            ),
+            task(
+                "Visit the Port Sarim Rat Pits",
+                [quest_req(QuestId::RATCATCHERS)],
+            ),
+            task(
+                "Grapple up and then jump off the north Falador wall",
+                [
+                    skill_req(Skill::Agility, Level(11)),
+                    skill_req(Skill::Strength, Level(37)),
+                    skill_req(Skill::Ranged, Level(19)),
+                ],
+            ),
+            task(
+                "Pickpocket a Falador guard",
+                [skill_req(Skill::Thieving, Level(40))],
+            ),
+            task(
+                "Pray at the Altar of Guthix in Taverley whilst wearing full Initiate",
+                [
+                    skill_req_unboostable(Skill::Prayer, Level(10)),
+                    skill_req(Skill::Defence, Level(20)),
+                    quest_req(QuestId::RECRUITMENT_DRIVE),
+                ],
+            ),
+            task(
+                "Mine some gold ore at the Crafting Guild",
+                [
+                    skill_req(Skill::Mining, Level(40)),
+                    skill_req(Skill::Crafting, Level(40)),
+                ],
+            ),
+            task(
+                "Squeeze through the crevice in the Dwarven mines",
+                [skill_req(Skill::Agility, Level(42))],
+            ),
+            task(
+                "Chop and burn some willow logs in Taverley",
+                [
+                    skill_req(Skill::Woodcutting, Level(30)),
+                    skill_req(Skill::Firemaking, Level(30)),
+                ],
+            ),
+            task(
+                "Craft a basket on the Falador farm loom",
+                [skill_req(Skill::Crafting, Level(36))],
+            ),
+            task("Teleport to Falador", [skill_req(Skill::Magic, Level(37))]),
+        ],
+    }
+}
+
+fn falador_hard() -> AchievementDiary {
+    AchievementDiary {
+        region: DiaryRegion::Falador,
+        tier: DiaryTier::Hard,
+        tasks: vec![
+            task(
+                "Craft 140 mind runes simultaneously from Essence",
+                [skill_req(Skill::Runecraft, Level(56))],
+            ),
+            task(
+                "Change your family crest to the Saradomin symbol",
+                [skill_req_unboostable(Skill::Prayer, Level(70))],
+            ),
+            task("Kill the Giant Mole beneath Falador Park", []),
+            task(
+                "Kill a skeletal wyvern in the Asgarnia Ice Dungeon",
+                [skill_req(Skill::Slayer, Level(72))],
+            ),
+            task(
+                "Complete a lap of the Falador rooftop agility course",
+                [skill_req(Skill::Agility, Level(50))],
+            ),
+            task(
+                "Enter the Mining Guild wearing full prospector",
+                [skill_req(Skill::Mining, Level(60))],
+            ),
+            task(
+                "Kill the blue dragon under the Heroes' Guild",
+                [quest_req(QuestId::HEROES_QUEST)],
+            ),
+            task(
+                "Crack a wall safe within Rogues' Den",
+                [skill_req(Skill::Thieving, Level(50))],
+            ),
+            task(
+                "Recharge your prayer in the Port Sarim church while wearing full Proselyte",
+                [
+                    skill_req(Skill::Defence, Level(30)),
+                    quest_req(QuestId::THE_SLUG_MENACE),
+                ],
+            ),
+            task(
+                "Enter the Warriors' Guild",
+                [QuestRequirement::Any(vec![
+                    QuestRequirement::SkillTotal {
+                        skills: vec![Skill::Attack, Skill::Strength],
+                        level: Level(130),
+                    },
+                    skill_req(Skill::Attack, Level(99)),
+                    skill_req(Skill::Strength, Level(99)),
+                ])],
+            ),
+            task(
+                "Equip a dwarven helmet within the dwarven mines",
+                [
+                    skill_req(Skill::Defence, Level(50)),
+                    quest_req(QuestId::GRIM_TALES),
+                ],
+            ),
+        ],
+    }
+}
+
+fn falador_elite() -> AchievementDiary {
+    AchievementDiary {
+        region: DiaryRegion::Falador,
+        tier: DiaryTier::Elite,
+        tasks: vec![
+            task(
+                "Craft 252 air runes simultaneously from Essence without the use of Extracts",
+                [skill_req(Skill::Runecraft, Level(88))],
+            ),
+            task(
+                "Purchase a white 2h sword from Sir Vyvin",
+                [quest_req(QuestId::WANTED)],
+            ),
+            task(
+                "Find at least 3 magic roots at once when digging up your magic tree in Falador",
+                [
+                    skill_req(Skill::Farming, Level(91)),
+                    skill_req(Skill::Woodcutting, Level(75)),
+                ],
+            ),
+            task(
+                "Perform a skillcape or quest cape emote at the top of Falador Castle",
+                [skillcape_or_quest_cape_req()],
+            ),
+            task(
+                "Jump over the strange floor in Taverley Dungeon",
+                [skill_req(Skill::Agility, Level(80))],
+            ),
+            task(
+                "Mix a Saradomin brew in Falador east bank",
+                [skill_req(Skill::Herblore, Level(81))],
+            ),
+        ],
+    }
+}
+
+fn fremennik_easy() -> AchievementDiary {
+    AchievementDiary {
+        region: DiaryRegion::Fremennik,
+        tier: DiaryTier::Easy,
+        tasks: vec![
+            task(
+                "Catch a cerulean twitch",
+                [skill_req(Skill::Hunter, Level(11))],
+            ),
+            task(
+                "Change your boots at Yrsa's Shoe Store",
+                [quest_req(QuestId::THE_FREMENNIK_TRIALS)],
+            ),
+            task("Kill 5 rock crabs", []),
+            task(
+                "Craft a tiara from scratch in Rellekka",
+                [
+                    skill_req(Skill::Mining, Level(20)),
+                    skill_req(Skill::Smithing, Level(20)),
+                    skill_req(Skill::Crafting, Level(23)),
+                    quest_req(QuestId::THE_FREMENNIK_TRIALS),
+                ],
+            ),
+            task("Browse the Stonemasons shop", []),
+            task("Collect a snape grass on Waterbirth Island", []),
+            task(
+                "Steal from the Keldagrim crafting or baker's stall",
+                [
+                    skill_req(Skill::Thieving, Level(5)),
+                    quest_req(QuestId::THE_GIANT_DWARF),
+                ],
+            ),
+            task("Fill a bucket with water at the Rellekka well", []),
+            task(
+                "Enter the Troll Stronghold",
+                [quest_req(QuestId::DEATH_PLATEAU)],
+            ),
+            task(
+                "Chop and burn some oak logs in the Fremennik Province",
+                [
+                    skill_req(Skill::Woodcutting, Level(15)),
+                    skill_req(Skill::Firemaking, Level(15)),
+                ],
+            ),
+        ],
+    }
+}
+
+fn fremennik_medium() -> AchievementDiary {
+    AchievementDiary {
+        region: DiaryRegion::Fremennik,
+        tier: DiaryTier::Medium,
+        tasks: vec![
+            task(
+                "Slay a brine rat",
+                [
+                    skill_req(Skill::Slayer, Level(47)),
+                    quest_req(QuestId::OLAFS_QUEST),
+                ],
+            ),
+            task(
+                "Travel to the Snowy Hunter Area via Eagle",
+                [quest_req(QuestId::EAGLES_PEAK)],
+            ),
+            task(
+                "Mine some coal in Rellekka",
+                [
+                    skill_req(Skill::Mining, Level(30)),
+                    quest_req(QuestId::THE_FREMENNIK_TRIALS),
+                ],
+            ),
+            task(
+                "Steal from the Rellekka fish stalls",
+                [
+                    skill_req(Skill::Thieving, Level(42)),
+                    quest_req(QuestId::THE_FREMENNIK_TRIALS),
+                ],
+            ),
+            task(
+                "Travel to Miscellania by fairy ring",
+                [
+                    quest_req(QuestId::FAIRYTALE_II),
+                    quest_req(QuestId::THE_FREMENNIK_TRIALS),
+                ],
+            ),
+            task(
+                "Catch a snowy knight",
+                [skill_req(Skill::Hunter, Level(35))],
+            ),
+            task(
+                "Pick up your pet rock from your POH menagerie",
+                [
+                    skill_req(Skill::Construction, Level(37)),
+                    quest_req(QuestId::THE_FREMENNIK_TRIALS),
+                ],
+            ),
+            task(
+                "Visit the Lighthouse from Waterbirth Island",
+                [
+                    quest_req(QuestId::HORROR_FROM_THE_DEEP),
+                    quest_req(QuestId::THE_FREMENNIK_TRIALS),
+                ],
+            ),
+            task(
+                "Mine some gold at the Arzinian mine",
+                [
+                    skill_req(Skill::Mining, Level(40)),
+                    quest_req(QuestId::BETWEEN_A_ROCK),
+                ],
+            ),
+        ],
+    }
+}
+
+fn fremennik_hard() -> AchievementDiary {
+    AchievementDiary {
+        region: DiaryRegion::Fremennik,
+        tier: DiaryTier::Hard,
+        tasks: vec![
+            task(
+                "Teleport to Trollheim",
+                [
+                    skill_req(Skill::Magic, Level(61)),
+                    quest_req(QuestId::EADGARS_RUSE),
+                ],
+            ),
+            task(
+                "Catch a sabre-toothed kyatt",
+                [skill_req(Skill::Hunter, Level(55))],
+            ),
+            task(
+                "Mix a super defence potion in the Fremennik Province",
+                [skill_req(Skill::Herblore, Level(66))],
+            ),
+            task(
+                "Steal from the Keldagrim gem stall",
+                [
+                    skill_req(Skill::Thieving, Level(75)),
+                    quest_req(QuestId::THE_GIANT_DWARF),
+                ],
+            ),
+            task(
+                "Craft a Fremennik shield on Neitiznot",
+                [
+                    skill_req(Skill::Woodcutting, Level(56)),
+                    quest_req(QuestId::THE_FREMENNIK_ISLES),
+                ],
+            ),
+            task(
+                "Mine 5 adamantite ores on Jatizso",
+                [
+                    skill_req(Skill::Mining, Level(70)),
+                    quest_req(QuestId::THE_FREMENNIK_ISLES),
+                ],
+            ),
+            task(
+                "Obtain the Blast Furnace Foreman's permission to use the Blast Furnace for free",
+                [
+                    skill_req(Skill::Smithing, Level(60)),
+                    quest_req(QuestId::THE_GIANT_DWARF),
+                ],
+            ),
+            task(
+                "Teleport to Waterbirth Island",
+                [
+                    skill_req(Skill::Magic, Level(72)),
+                    quest_req(QuestId::LUNAR_DIPLOMACY),
+                ],
+            ),
+        ],
+    }
+}
+
+fn fremennik_elite() -> AchievementDiary {
+    AchievementDiary {
+        region: DiaryRegion::Fremennik,
+        tier: DiaryTier::Elite,
+        tasks: vec![
+            task("Kill each of the Dagannoth Kings", []),
+            task(
+                "Craft 56 astral runes simultaneously from Essence",
+                [
+                    skill_req(Skill::Runecraft, Level(82)),
+                    quest_req(QuestId::LUNAR_DIPLOMACY),
+                ],
+            ),
+            task(
+                "Create a dragonstone amulet in the Neitiznot furnace",
+                [
+                    skill_req(Skill::Crafting, Level(80)),
+                    quest_req(QuestId::THE_FREMENNIK_ISLES),
+                ],
+            ),
+            task(
+                "Complete a lap of the Rellekka agility course",
+                [skill_req(Skill::Agility, Level(80))],
+            ),
+            task(
+                "Kill each of the God Wars Dungeon generals",
+                [
+                    skill_req(Skill::Agility, Level(70)),
+                    skill_req(Skill::Strength, Level(70)),
+                    skill_req(Skill::Hitpoints, Level(70)),
+                    skill_req(Skill::Ranged, Level(70)),
+                    quest_req(QuestId::TROLL_STRONGHOLD),
+                ],
+            ),
+            task(
+                "Slay a spiritual mage within the God Wars Dungeon",
+                [
+                    skill_req(Skill::Slayer, Level(83)),
+                    quest_req(QuestId::TROLL_STRONGHOLD),
+                ],
+            ),
+        ],
+    }
+}
+
+fn kandarin_easy() -> AchievementDiary {
+    AchievementDiary {
+        region: DiaryRegion::Kandarin,
+        tier: DiaryTier::Easy,
+        tasks: vec![
+            task(
+                "Catch a mackerel at Catherby",
+                [skill_req(Skill::Fishing, Level(16))],
+            ),
+            task("Buy a candle from the candle maker in Catherby", []),
+            task("Collect 5 flax from the Seers' Village flax field", []),
+            task("Play the church organ in the Seers' Village church", []),
+            task(
+                "Plant jute seeds in the farming patch north of McGrubor's Wood",
+                [skill_req(Skill::Farming, Level(13))],
+            ),
+            task("Have Galahad make you a cup of tea", []),
+            task(
+                "Defeat one of each elemental in the Elemental Workshop",
+                [quest_req(QuestId::ELEMENTAL_WORKSHOP_I)],
+            ),
+            task("Get a pet fish from Harry in Catherby", []),
+            task("Buy a stew from the Seers' Village pub", []),
+            task(
+                "Speak to Sherlock between the Sorcerer's Tower and Keep Le Faye",
+                [],
+            ),
+            task(
+                "Cross the Coal Trucks log shortcut",
+                [skill_req(Skill::Agility, Level(20))],
+            ),
+        ],
+    }
+}
+
+fn kandarin_medium() -> AchievementDiary {
+    AchievementDiary {
+        region: DiaryRegion::Kandarin,
+        tier: DiaryTier::Medium,
+        tasks: vec![
+            task(
+                "Complete a lap of the Barbarian agility course",
+                [
+                    skill_req(Skill::Agility, Level(35)),
+                    miniquest_req(MiniquestId::ALFRED_GRIMHANDS_BARCRAWL),
+                ],
+            ),
+            task(
+                "Create a super anti-poison potion from scratch in the Seers'/Catherby area",
+                [skill_req(Skill::Herblore, Level(48))],
+            ),
+            task(
+                "Enter the Ranging Guild",
+                [skill_req(Skill::Ranged, Level(40))],
+            ),
+            task(
+                "Use the grapple shortcut to get from the water obelisk to Catherby shore",
+                [
+                    skill_req(Skill::Agility, Level(36)),
+                    skill_req(Skill::Strength, Level(22)),
+                    skill_req(Skill::Ranged, Level(39)),
+                ],
+            ),
+            task(
+                "Catch and cook a bass in Catherby",
+                [
+                    skill_req(Skill::Fishing, Level(46)),
+                    skill_req(Skill::Cooking, Level(43)),
+                ],
+            ),
+            task("Teleport to Camelot", [skill_req(Skill::Magic, Level(45))]),
+            task(
+                "String a maple shortbow in Seers' Village bank",
+                [skill_req(Skill::Fletching, Level(50))],
+            ),
+            task(
+                "Pick some limpwurt root from the farming patch in Catherby",
+                [skill_req(Skill::Farming, Level(26))],
+            ),
+            task(
+                "Create a mind helmet",
+                [quest_req(QuestId::ELEMENTAL_WORKSHOP_II)],
+            ),
+            task(
+                "Kill a fire giant inside Baxtorian Waterfall",
+                [quest_req(QuestId::WATERFALL_QUEST)],
+            ),
+            task("Complete a wave of Barbarian Assault", []),
+            task(
+                "Steal from the chest in Hemenster",
+                [skill_req(Skill::Thieving, Level(47))],
+            ),
+            task(
+                "Travel to McGrubor's Wood by fairy ring",
+                [quest_req(QuestId::FAIRYTALE_II)],
+            ),
+            task(
+                "Mine some coal near the coal trucks",
+                [skill_req(Skill::Mining, Level(30))],
+            ),
+        ],
+    }
+}
+
+fn kandarin_hard() -> AchievementDiary {
+    AchievementDiary {
+        region: DiaryRegion::Kandarin,
+        tier: DiaryTier::Hard,
+        tasks: vec![
+            task(
+                "Catch a leaping sturgeon",
+                [
+                    skill_req(Skill::Fishing, Level(70)),
+                    skill_req(Skill::Agility, Level(45)),
+                    skill_req(Skill::Strength, Level(45)),
+                    miniquest_req(MiniquestId::BARBARIAN_TRAINING),
+                ],
+            ),
+            task(
+                "Complete a lap of the Seers' Village agility course",
+                [skill_req(Skill::Agility, Level(60))],
+            ),
+            task(
+                "Create a yew longbow from scratch around Seers' Village",
+                [
+                    skill_req(Skill::Woodcutting, Level(60)),
+                    skill_req(Skill::Fletching, Level(70)),
+                    skill_req(Skill::Crafting, Level(10)),
+                ],
+            ),
+            task(
+                "Enter the Seers' Village courthouse with Piety turned on",
+                [
+                    skill_req_unboostable(Skill::Prayer, Level(70)),
+                    skill_req(Skill::Defence, Level(70)),
+                    quest_req(QuestId::KINGS_RANSOM),
+                ],
+            ),
+            task("Charge a water orb", [skill_req(Skill::Magic, Level(56))]),
+            task(
+                "Burn some maple logs with a bow in Seers' Village",
+                [
+                    skill_req(Skill::Firemaking, Level(65)),
+                    miniquest_req(MiniquestId::BARBARIAN_TRAINING),
+                ],
+            ),
+            task(
+                "Kill a shadow hound in the Shadow Dungeon",
+                [quest_req(QuestId::DESERT_TREASURE_I)],
+            ),
+            task("Kill a mithril dragon", []),
+            task(
+                "Purchase and equip a granite body from Barbarian Assault",
+                [
+                    skill_req(Skill::Strength, Level(50)),
+                    skill_req(Skill::Defence, Level(50)),
+                ],
+            ),
+            task(
+                "Have the Seers' estate agent decorate your house with Fancy Stone",
+                [skill_req(Skill::Construction, Level(50))],
+            ),
+            task(
+                "Smith an adamant spear at Otto's Grotto",
+                [
+                    skill_req(Skill::Smithing, Level(75)),
+                    miniquest_req(MiniquestId::BARBARIAN_TRAINING),
+                ],
+            ),
+        ],
+    }
+}
+
+fn kandarin_elite() -> AchievementDiary {
+    AchievementDiary {
+        region: DiaryRegion::Kandarin,
+        tier: DiaryTier::Elite,
+        tasks: vec![
+            task(
+                "Read the blackboard at Barbarian Assault after reaching level 5 in every role",
+                [],
+            ),
+            task(
+                "Pick some dwarf weed from the herb patch at Catherby",
+                [skill_req(Skill::Farming, Level(79))],
+            ),
+            task(
+                "Fish and cook 5 sharks in Catherby using the Cooking gauntlets",
+                [
+                    skill_req(Skill::Fishing, Level(76)),
+                    skill_req(Skill::Cooking, Level(80)),
+                    quest_req(QuestId::FAMILY_CREST),
+                ],
+            ),
+            task(
+                "Mix a stamina mix on top of the Seers' Village bank",
+                [
+                    skill_req(Skill::Herblore, Level(86)),
+                    skill_req(Skill::Agility, Level(60)),
+                ],
+            ),
+            task(
+                "Smith a rune hasta at Otto's Grotto",
+                [
+                    skill_req(Skill::Smithing, Level(90)),
+                    miniquest_req(MiniquestId::BARBARIAN_TRAINING),
+                ],
+            ),
+            task(
+                "Construct a pyre ship from magic logs",
+                [
+                    skill_req(Skill::Firemaking, Level(85)),
+                    skill_req(Skill::Crafting, Level(85)),
+                    miniquest_req(MiniquestId::BARBARIAN_TRAINING),
+                ],
+            ),
+            task(
+                "Teleport to Catherby",
+                [
+                    skill_req(Skill::Magic, Level(87)),
+                    quest_req(QuestId::LUNAR_DIPLOMACY),
+                ],
+            ),
+        ],
+    }
+}
+
+fn karamja_easy() -> AchievementDiary {
+    AchievementDiary {
+        region: DiaryRegion::Karamja,
+        tier: DiaryTier::Easy,
+        tasks: vec![
+            task(
+                "Pick 5 bananas from the plantation located east of the volcano",
+                [],
+            ),
+            task(
+                "Use the rope swing to travel to the Moss Giant Island north-west of Karamja",
+                [skill_req(Skill::Agility, Level(10))],
+            ),
+            task(
+                "Mine some gold from the rocks on the north-west peninsula of Karamja",
+                [skill_req(Skill::Mining, Level(40))],
+            ),
+            task("Travel to Port Sarim via the dock, east of Musa Point", []),
+            task("Travel to Ardougne via the port near Brimhaven", []),
+            task(
+                "Explore Cairn Island to the west of Karamja",
+                [skill_req(Skill::Agility, Level(15))],
+            ),
+            task("Use the Fishing spots north of the banana plantation", []),
+            task("Collect 5 seaweed from anywhere on Karamja", []),
+            task("Attempt the TzHaar Fight Pits or Fight Cave", []),
+            task("Kill a jogre in the Pothole Dungeon", []),
+        ],
+    }
+}
+
+fn karamja_medium() -> AchievementDiary {
+    AchievementDiary {
+        region: DiaryRegion::Karamja,
+        tier: DiaryTier::Medium,
+        tasks: vec![
+            task("Claim a ticket from the Agility Arena in Brimhaven", []),
+            task(
+                "Discover the hidden wall in the dungeon below the volcano",
+                [quest_req(QuestId::DRAGON_SLAYER_I)],
+            ),
+            task(
+                "Visit the Isle of Crandor via the dungeon below the volcano",
+                [quest_req(QuestId::DRAGON_SLAYER_I)],
+            ),
+            task(
+                "Use Vigroy and Hajedy's cart service",
+                [quest_req(QuestId::SHILO_VILLAGE)],
+            ),
+            task(
+                "Earn 100% favour in the village of Tai Bwo Wannai",
+                [
+                    skill_req(Skill::Woodcutting, Level(10)),
+                    quest_req(QuestId::JUNGLE_POTION),
+                ],
+            ),
+            task("Cook a spider on a stick", []),
+            task(
+                "Charter the Lady of the Waves from Cairn Isle to Port Khazard",
+                [quest_req(QuestId::SHILO_VILLAGE)],
+            ),
+            task(
+                "Cut a log from a teak tree",
+                [
+                    skill_req(Skill::Woodcutting, Level(35)),
+                    quest_req(QuestId::JUNGLE_POTION),
+                ],
+            ),
+            task(
+                "Cut a log from a mahogany tree",
+                [
+                    skill_req(Skill::Woodcutting, Level(50)),
+                    quest_req(QuestId::JUNGLE_POTION),
+                ],
+            ),
+            task(
+                "Catch a karambwan",
+                [
+                    skill_req(Skill::Fishing, Level(65)),
+                    quest_req(QuestId::TAI_BWO_WANNAI_TRIO),
+                ],
+            ),
+            task(
+                "Exchange gems for a machete",
+                [quest_req(QuestId::JUNGLE_POTION)],
+            ),
+            task(
+                "Use the gnome glider to travel to Karamja",
+                [quest_req(QuestId::THE_GRAND_TREE)],
+            ),
+            task(
+                "Grow a healthy fruit tree in the patch near Brimhaven",
+                [skill_req(Skill::Farming, Level(27))],
+            ),
+            task(
+                "Trap a horned graahk",
+                [skill_req(Skill::Hunter, Level(41))],
+            ),
+            task(
+                "Chop the vines to gain deeper access to Brimhaven Dungeon",
+                [skill_req(Skill::Woodcutting, Level(10))],
+            ),
+            task(
+                "Cross the lava using the stepping stones within Brimhaven Dungeon",
+                [skill_req(Skill::Agility, Level(12))],
+            ),
+            task(
+                "Climb the stairs within Brimhaven Dungeon",
+                [skill_req(Skill::Woodcutting, Level(10))],
+            ),
+            task(
+                "Charter a ship from the shipyard in the far east of Karamja",
+                [quest_req(QuestId::THE_GRAND_TREE)],
+            ),
+            task(
+                "Mine a red topaz from a gem rock",
+                [
+                    skill_req(Skill::Mining, Level(40)),
+                    quest_req(QuestId::SHILO_VILLAGE),
+                ],
+            ),
+        ],
+    }
+}
+
+fn karamja_hard() -> AchievementDiary {
+    AchievementDiary {
+        region: DiaryRegion::Karamja,
+        tier: DiaryTier::Hard,
+        tasks: vec![
+            task("Become the Champion of the Fight Pits", []),
+            task("Kill a Ket-Zek in the Fight Caves", []),
+            task("Eat an oomlie wrap", [skill_req(Skill::Cooking, Level(50))]),
+            task(
+                "Craft some nature runes",
+                [skill_req(Skill::Runecraft, Level(44))],
+            ),
+            task(
+                "Cook a karambwan thoroughly",
+                [
+                    skill_req(Skill::Cooking, Level(30)),
+                    quest_req(QuestId::TAI_BWO_WANNAI_TRIO),
+                ],
+            ),
+            task(
+                "Kill a deathwing in the dungeon under the Kharazi Jungle",
+                [
+                    skill_req(Skill::Woodcutting, Level(15)),
+                    skill_req(Skill::Strength, Level(15)),
+                    skill_req(Skill::Agility, Level(50)),
+                    skill_req(Skill::Thieving, Level(50)),
+                    skill_req(Skill::Mining, Level(52)),
+                    quest_req(QuestId::LEGENDS_QUEST),
+                ],
+            ),
+            task(
+                "Use the crossbow shortcut south of the volcano",
+                [
+                    skill_req(Skill::Agility, Level(53)),
+                    skill_req(Skill::Ranged, Level(42)),
+                    skill_req(Skill::Strength, Level(21)),
+                ],
+            ),
+            task(
+                "Collect 5 palm leaves",
+                [
+                    skill_req(Skill::Woodcutting, Level(15)),
+                    quest_req(QuestId::LEGENDS_QUEST),
+                ],
+            ),
+            task(
+                "Be assigned a Slayer task by Duradel north of Shilo Village",
+                [
+                    skill_req(Skill::Slayer, Level(50)),
+                    combat_level_req(Level(100)),
+                    quest_req(QuestId::SHILO_VILLAGE),
+                ],
+            ),
+            task(
+                "Kill a metal dragon in Brimhaven Dungeon",
+                [
+                    skill_req(Skill::Woodcutting, Level(34)),
+                    skill_req(Skill::Agility, Level(30)),
+                ],
+            ),
+        ],
+    }
+}
+
+fn karamja_elite() -> AchievementDiary {
+    AchievementDiary {
+        region: DiaryRegion::Karamja,
+        tier: DiaryTier::Elite,
+        tasks: vec![
+            task(
+                "Craft 56 nature runes at once",
+                [skill_req(Skill::Runecraft, Level(91))],
+            ),
+            task(
+                "Check the health of a palm tree in Brimhaven",
+                [skill_req(Skill::Farming, Level(68))],
+            ),
+            task(
+                "Create an antivenom potion whilst standing in the horse shoe mine",
+                [skill_req(Skill::Herblore, Level(87))],
+            ),
+            task(
+                "Check the health of your calquat tree patch",
+                [skill_req(Skill::Farming, Level(72))],
+            ),
+            task("Equip a fire cape or infernal cape in TzHaar City", []),
+        ],
+    }
+}
+
+fn kourend_and_kebos_easy() -> AchievementDiary {
+    AchievementDiary {
+        region: DiaryRegion::KourendAndKebos,
+        tier: DiaryTier::Easy,
+        tasks: vec![
+            task(
+                "Mine some iron at the Mount Karuulm mine",
+                [skill_req(Skill::Mining, Level(15))],
+            ),
+            task("Kill a sand crab", []),
+            task("Hand in a book at the Arceuus Library", []),
+            task(
+                "Steal from a Hosidius food stall",
+                [skill_req(Skill::Thieving, Level(25))],
+            ),
+            task("Browse the Warrens General Store", []),
+            task("Take a boat to Land's End", []),
+            task("Pray at the altar in Kourend Castle", []),
+            task("Dig up some saltpetre", []),
+            task(
+                "Enter your player-owned house from Hosidius",
+                [skill_req(Skill::Construction, Level(25))],
+            ),
+            task("Do a lap of the Shayzien Agility Course", []),
+            task(
+                "Create a strength potion in the Lovakengj pub",
+                [skill_req(Skill::Herblore, Level(12))],
+            ),
+            task(
+                "Fish a trout from the River Molch",
+                [skill_req(Skill::Fishing, Level(20))],
+            ),
+        ],
+    }
+}
+
+fn kourend_and_kebos_medium() -> AchievementDiary {
+    AchievementDiary {
+        region: DiaryRegion::KourendAndKebos,
+        tier: DiaryTier::Medium,
+        tasks: vec![
+            task(
+                "Travel to the fairy ring south of Mount Karuulm",
+                [quest_req(QuestId::FAIRYTALE_II)],
+            ),
+            task("Kill a lizardman", []),
+            task(
+                "Use Kharedst's memoirs to teleport to all five cities in Great Kourend",
+                [
+                    quest_req(QuestId::THE_DEPTHS_OF_DESPAIR),
+                    quest_req(QuestId::THE_QUEEN_OF_THIEVES),
+                    quest_req(QuestId::TALE_OF_THE_RIGHTEOUS),
+                    quest_req(QuestId::THE_FORSAKEN_TOWER),
+                    quest_req(QuestId::THE_ASCENT_OF_ARCEUUS),
+                ],
+            ),
+            task(
+                "Mine some volcanic sulphur",
+                [skill_req(Skill::Mining, Level(42))],
+            ),
+            task(
+                "Enter the Farming Guild",
+                [skill_req(Skill::Farming, Level(45))],
+            ),
+            task("Switch to the Arceuus spellbook via Tyss", []),
+            task(
+                "Repair a Piscarilius crane",
+                [skill_req(Skill::Crafting, Level(30))],
+            ),
+            task("Deliver some intelligence to Captain Ginea", []),
+            task(
+                "Catch a bluegill on Molch Island",
+                [
+                    skill_req(Skill::Fishing, Level(43)),
+                    skill_req(Skill::Hunter, Level(35)),
+                ],
+            ),
+            task(
+                "Use the boulder leap in the Arceuus essence mine",
+                [skill_req(Skill::Agility, Level(49))],
+            ),
+            task(
+                "Subdue the Wintertodt",
+                [skill_req(Skill::Firemaking, Level(50))],
+            ),
+            task(
+                "Catch a chinchompa in the Kourend Woodland",
+                [
+                    skill_req(Skill::Hunter, Level(53)),
+                    quest_req(QuestId::EAGLES_PEAK),
+                ],
+            ),
+            task(
+                "Chop some mahogany logs north of the Farming Guild",
+                [skill_req(Skill::Woodcutting, Level(50))],
+            ),
+        ],
+    }
+}
+
+fn kourend_and_kebos_hard() -> AchievementDiary {
+    AchievementDiary {
+        region: DiaryRegion::KourendAndKebos,
+        tier: DiaryTier::Hard,
+        tasks: vec![
+            task(
+                "Enter the Woodcutting Guild",
+                [skill_req(Skill::Woodcutting, Level(60))],
+            ),
+            task(
+                "Smelt an adamantite bar in The Forsaken Tower",
+                [
+                    skill_req(Skill::Smithing, Level(70)),
+                    quest_req(QuestId::THE_FORSAKEN_TOWER),
+                ],
+            ),
+            task("Kill a lizardman shaman in Molch", []),
+            task("Mine some lovakite", [skill_req(Skill::Mining, Level(65))]),
+            task(
+                "Plant some logavano seeds at the Tithe Farm",
+                [skill_req(Skill::Farming, Level(74))],
+            ),
+            task("Kill a zombie in the Shayzien Crypts", []),
+            task(
+                "Teleport to Xeric's Heart using Xeric's Talisman",
+                [miniquest_req(MiniquestId::ARCHITECTURAL_ALLIANCE)],
+            ),
+            task(
+                "Deliver an artefact to Captain Khaled",
+                [skill_req(Skill::Thieving, Level(49))],
+            ),
+            task(
+                "Kill a wyrm in the Karuulm Slayer Dungeon",
+                [skill_req(Skill::Slayer, Level(62))],
+            ),
+            task(
+                "Cast Monster Examine on a mountain troll south of Mount Quidamortem",
+                [
+                    skill_req(Skill::Magic, Level(66)),
+                    quest_req(QuestId::DREAM_MENTOR),
+                ],
+            ),
+        ],
+    }
+}
+
+fn kourend_and_kebos_elite() -> AchievementDiary {
+    AchievementDiary {
+        region: DiaryRegion::KourendAndKebos,
+        tier: DiaryTier::Elite,
+        tasks: vec![
+            task(
+                "Craft one or more blood runes from Essence",
+                [
+                    skill_req(Skill::Runecraft, Level(77)),
+                    skill_req(Skill::Mining, Level(38)),
+                    skill_req(Skill::Crafting, Level(38)),
+                ],
+            ),
+            task(
+                "Chop some redwood logs",
+                [skill_req(Skill::Woodcutting, Level(90))],
+            ),
+            task("Defeat Skotizo in the Catacombs of Kourend", []),
+            task(
+                "Catch an anglerfish and cook it whilst in Great Kourend",
+                [
+                    skill_req(Skill::Fishing, Level(82)),
+                    skill_req(Skill::Cooking, Level(84)),
+                ],
+            ),
+            task(
+                "Kill a hydra in the Karuulm Slayer Dungeon",
+                [skill_req(Skill::Slayer, Level(95))],
+            ),
+            task(
+                "Create an Ape Atoll teleport tablet",
+                [skill_req(Skill::Magic, Level(90))],
+            ),
+            task("Complete a raid in the Chambers of Xeric", []),
+            task(
+                "Create your own battlestaff from scratch within the Farming Guild",
+                [
+                    skill_req(Skill::Farming, Level(85)),
+                    skill_req(Skill::Fletching, Level(40)),
+                ],
+            ),
+        ],
+    }
+}
+
+fn lumbridge_and_draynor_easy() -> AchievementDiary {
+    AchievementDiary {
+        region: DiaryRegion::LumbridgeAndDraynor,
+        tier: DiaryTier::Easy,
+        tasks: vec![
+            task(
+                "Complete a lap of the Draynor Village agility course",
+                [skill_req(Skill::Agility, Level(10))],
+            ),
+            task(
+                "Slay a cave bug beneath Lumbridge Swamp",
+                [skill_req(Skill::Slayer, Level(7))],
+            ),
+            task(
+                "Have Sedridor teleport you to the Essence Mine",
+                [quest_req(QuestId::RUNE_MYSTERIES)],
+            ),
+            task(
+                "Craft some water runes",
+                [skill_req(Skill::Runecraft, Level(5))],
+            ),
+            task("Learn your age from Hans in Lumbridge", []),
+            task("Pickpocket a man or woman in Lumbridge", []),
+            task(
+                "Chop and burn some oak logs in Lumbridge",
+                [
+                    skill_req(Skill::Woodcutting, Level(15)),
+                    skill_req(Skill::Firemaking, Level(15)),
+                ],
+            ),
+            task("Kill a zombie in the Draynor Sewers", []),
+            task(
+                "Catch some anchovies in Al Kharid",
+                [skill_req(Skill::Fishing, Level(15))],
+            ),
+            task(
+                "Bake some bread on the Lumbridge castle kitchen range",
+                [quest_req(QuestId::COOKS_ASSISTANT)],
+            ),
+            task(
+                "Mine some iron ore at the Al Kharid mine",
+                [skill_req(Skill::Mining, Level(15))],
+            ),
+            task("Enter the H.A.M. Hideout", []),
+        ],
+    }
+}
+
+fn lumbridge_and_draynor_medium() -> AchievementDiary {
+    AchievementDiary {
+        region: DiaryRegion::LumbridgeAndDraynor,
+        tier: DiaryTier::Medium,
+        tasks: vec![
+            task(
+                "Complete a lap of the Al Kharid agility course",
+                [skill_req(Skill::Agility, Level(20))],
+            ),
+            task(
+                "Grapple across the River Lum",
+                [
+                    skill_req(Skill::Agility, Level(8)),
+                    skill_req(Skill::Strength, Level(19)),
+                    skill_req(Skill::Ranged, Level(37)),
+                ],
+            ),
+            task(
+                "Purchase an upgraded device from Ava",
+                [
+                    skill_req(Skill::Ranged, Level(50)),
+                    quest_req(QuestId::ANIMAL_MAGNETISM),
+                ],
+            ),
+            task(
+                "Travel to the Wizards' Tower by fairy ring",
+                [quest_req(QuestId::FAIRYTALE_II)],
+            ),
+            task(
+                "Cast the teleport to Lumbridge spell",
+                [skill_req(Skill::Magic, Level(31))],
+            ),
+            task(
+                "Catch some salmon in Lumbridge",
+                [skill_req(Skill::Fishing, Level(30))],
+            ),
+            task(
+                "Craft a coif in the Lumbridge cow pen",
+                [skill_req(Skill::Crafting, Level(38))],
+            ),
+            task(
+                "Chop some willow logs in Draynor Village",
+                [skill_req(Skill::Woodcutting, Level(30))],
+            ),
+            task(
+                "Pickpocket Martin the Master Gardener",
+                [skill_req(Skill::Thieving, Level(38))],
+            ),
+            task(
+                "Get a slayer task from Chaeldar",
+                [combat_level_req(Level(70)), quest_req(QuestId::LOST_CITY)],
+            ),
+            task(
+                "Catch an essence or eclectic impling in Puro-Puro",
+                [
+                    skill_req(Skill::Hunter, Level(42)),
+                    quest_req(QuestId::LOST_CITY),
+                ],
+            ),
+            task(
+                "Craft some lava runes at the fire altar in Al Kharid",
+                [skill_req(Skill::Runecraft, Level(23))],
+            ),
+        ],
+    }
+}
+
+fn lumbridge_and_draynor_hard() -> AchievementDiary {
+    AchievementDiary {
+        region: DiaryRegion::LumbridgeAndDraynor,
+        tier: DiaryTier::Hard,
+        tasks: vec![
+            task(
+                "Cast Bones to Peaches in Al Kharid palace",
+                [skill_req(Skill::Magic, Level(60))],
+            ),
+            task(
+                "Squeeze past the jutting wall on your way to the cosmic altar",
+                [
+                    skill_req(Skill::Agility, Level(46)),
+                    quest_req(QuestId::LOST_CITY),
+                ],
+            ),
+            task(
+                "Craft 56 cosmic runes simultaneously from Essence",
+                [
+                    skill_req(Skill::Runecraft, Level(59)),
+                    quest_req(QuestId::LOST_CITY),
+                ],
+            ),
+            task(
+                "Travel from Lumbridge to Edgeville on a waka canoe",
+                [skill_req(Skill::Woodcutting, Level(57))],
+            ),
+            task(
+                "Collect at least 100 Tears of Guthix in one visit",
+                [quest_req(QuestId::TEARS_OF_GUTHIX)],
+            ),
+            task(
+                "Take the train from Dorgesh-Kaan to Keldagrim",
+                [quest_req(QuestId::ANOTHER_SLICE_OF_HAM)],
+            ),
+            task(
+                "Purchase some Barrows gloves from the Lumbridge bank chest",
+                [quest_req(QuestId::RECIPE_FOR_DISASTER)],
+            ),
+            task(
+                "Pick some belladonna from the farming patch at Draynor Manor",
+                [skill_req(Skill::Farming, Level(63))],
+            ),
+            task(
+                "Light your mining helmet in the Lumbridge castle basement",
+                [skill_req(Skill::Firemaking, Level(65))],
+            ),
+            task(
+                "Recharge your prayer at the Emir's Arena with Smite activated",
+                [skill_req_unboostable(Skill::Prayer, Level(52))],
+            ),
+            task(
+                "Craft, string and enchant an amulet of power in Lumbridge",
+                [
+                    skill_req(Skill::Crafting, Level(70)),
+                    skill_req(Skill::Magic, Level(57)),
+                ],
+            ),
+        ],
+    }
+}
+
+fn lumbridge_and_draynor_elite() -> AchievementDiary {
+    AchievementDiary {
+        region: DiaryRegion::LumbridgeAndDraynor,
+        tier: DiaryTier::Elite,
+        tasks: vec![
+            task(
+                "Steal from a Dorgesh-Kaan rich chest",
+                [
+                    skill_req(Skill::Thieving, Level(78)),
+                    quest_req(QuestId::DEATH_TO_THE_DORGESHUUN),
+                ],
+            ),
+            task(
+                "Grapple across a pylon on the Dorgesh-Kaan Agility Course",
+                [
+                    skill_req(Skill::Agility, Level(70)),
+                    skill_req(Skill::Ranged, Level(70)),
+                    skill_req(Skill::Strength, Level(70)),
+                    quest_req(QuestId::DEATH_TO_THE_DORGESHUUN),
+                ],
+            ),
+            task(
+                "Chop some magic logs at the Mage Training Arena",
+                [skill_req(Skill::Woodcutting, Level(75))],
+            ),
+            task(
+                "Smith an adamant platebody down Draynor sewer",
+                [skill_req(Skill::Smithing, Level(88))],
+            ),
+            task(
+                "Craft 140 or more water runes at once from Essence",
+                [skill_req(Skill::Runecraft, Level(76))],
+            ),
+            task(
+                "Perform the Quest point cape emote in the Wise Old Man's house",
+                [quest_cape_req()],
+            ),
+        ],
+    }
+}
+
+fn morytania_easy() -> AchievementDiary {
+    AchievementDiary {
+        region: DiaryRegion::Morytania,
+        tier: DiaryTier::Easy,
+        tasks: vec![
+            task(
+                "Craft any snelm from scratch in Morytania",
+                [skill_req(Skill::Crafting, Level(15))],
+            ),
+            task(
+                "Cook a thin snail on the Port Phasmatys range",
+                [skill_req(Skill::Cooking, Level(12))],
+            ),
+            task(
+                "Get a slayer task from Mazchna",
+                [combat_level_req(Level(20))],
+            ),
+            task(
+                "Kill a banshee in the Slayer Tower",
+                [skill_req(Skill::Slayer, Level(15))],
+            ),
+            task("Have Sbott in Canifis tan something for you", []),
+            task("Enter Mort Myre Swamp", [quest_req(QuestId::NATURE_SPIRIT)]),
+            task("Kill a ghoul", []),
+            task(
+                "Place a scarecrow in the Morytania flower patch",
+                [skill_req(Skill::Farming, Level(23))],
+            ),
+            task("Offer some bonemeal at the Ectofuntus", []),
+            task(
+                "Kill a werewolf in its human form using the Wolfbane dagger",
+                [quest_req(QuestId::PRIEST_IN_PERIL)],
+            ),
+            task(
+                "Restore your prayer points at the nature altar",
+                [quest_req(QuestId::NATURE_SPIRIT)],
+            ),
+        ],
+    }
+}
+
+fn morytania_medium() -> AchievementDiary {
+    AchievementDiary {
+        region: DiaryRegion::Morytania,
+        tier: DiaryTier::Medium,
+        tasks: vec![
+            task(
+                "Catch a swamp lizard",
+                [skill_req(Skill::Hunter, Level(29))],
+            ),
+            task(
+                "Complete a lap of the Canifis agility course",
+                [skill_req(Skill::Agility, Level(40))],
+            ),
+            task(
+                "Obtain some bark from a hollow tree",
+                [skill_req(Skill::Woodcutting, Level(45))],
+            ),
+            task(
+                "Travel to Dragontooth Isle",
+                [quest_req(QuestId::GHOSTS_AHOY)],
+            ),
+            task(
+                "Kill a terror dog",
+                [
+                    skill_req(Skill::Slayer, Level(40)),
+                    miniquest_req(MiniquestId::LAIR_OF_TARN_RAZORLOR),
+                ],
+            ),
+            task(
+                "Complete a game of Trouble Brewing",
+                [
+                    skill_req(Skill::Cooking, Level(40)),
+                    quest_req(QuestId::CABIN_FEVER),
+                ],
+            ),
+            task(
+                "Make a batch of cannonballs at the Port Phasmatys furnace",
+                [
+                    skill_req(Skill::Smithing, Level(35)),
+                    quest_req(QuestId::DWARF_CANNON),
+                    quest_req(QuestId::GHOSTS_AHOY),
+                ],
+            ),
+            task(
+                "Kill a fever spider on Braindeath Island",
+                [
+                    skill_req(Skill::Slayer, Level(42)),
+                    quest_req(QuestId::RUM_DEAL),
+                ],
+            ),
+            task(
+                "Use an ectophial to return to Port Phasmatys",
+                [quest_req(QuestId::GHOSTS_AHOY)],
+            ),
+            task(
+                "Mix a Guthix balance potion while in Morytania",
+                [
+                    skill_req(Skill::Herblore, Level(22)),
+                    quest_req(QuestId::IN_AID_OF_THE_MYREQUE),
+                ],
+            ),
+        ],
+    }
+}
+
+fn morytania_hard() -> AchievementDiary {
+    AchievementDiary {
+        region: DiaryRegion::Morytania,
+        tier: DiaryTier::Hard,
+        tasks: vec![
+            task(
+                "Enter the Kharyrll portal in your POH through a Portal Chamber",
+                [
+                    skill_req(Skill::Magic, Level(66)),
+                    skill_req(Skill::Construction, Level(50)),
+                    quest_req(QuestId::DESERT_TREASURE_I),
+                ],
+            ),
+            task(
+                "Climb the advanced spike chain within Slayer Tower",
+                [skill_req(Skill::Agility, Level(71))],
+            ),
+            task(
+                "Harvest some watermelon from the allotment patch on Harmony Island",
+                [
+                    skill_req(Skill::Farming, Level(47)),
+                    quest_req(QuestId::THE_GREAT_BRAIN_ROBBERY),
+                ],
+            ),
+            task(
+                "Chop and burn some mahogany logs on Mos Le'Harmless",
+                [
+                    skill_req(Skill::Woodcutting, Level(50)),
+                    skill_req(Skill::Firemaking, Level(50)),
+                    quest_req(QuestId::CABIN_FEVER),
+                ],
+            ),
+            task(
+                "Complete a temple trek with a hard companion",
+                [quest_req(QuestId::IN_AID_OF_THE_MYREQUE)],
+            ),
+            task(
+                "Kill a cave horror",
+                [
+                    skill_req(Skill::Slayer, Level(58)),
+                    quest_req(QuestId::CABIN_FEVER),
+                ],
+            ),
+            task(
+                "Harvest some bittercap mushrooms",
+                [skill_req(Skill::Farming, Level(53))],
+            ),
+            task(
+                "Pray at the Altar of Nature with Piety activated",
+                [
+                    skill_req_unboostable(Skill::Prayer, Level(70)),
+                    skill_req(Skill::Defence, Level(70)),
+                    quest_req(QuestId::NATURE_SPIRIT),
+                    quest_req(QuestId::KINGS_RANSOM),
+                ],
+            ),
+            task(
+                "Use the shortcut under the wall of Darkmeyer",
+                [
+                    skill_req(Skill::Agility, Level(65)),
+                    quest_req(QuestId::SINS_OF_THE_FATHER),
+                ],
+            ),
+            task(
+                "Mine some mithril ore in the Abandoned Mine",
+                [
+                    skill_req(Skill::Mining, Level(55)),
+                    quest_req(QuestId::HAUNTED_MINE),
+                ],
+            ),
+        ],
+    }
+}
+
+fn morytania_elite() -> AchievementDiary {
+    AchievementDiary {
+        region: DiaryRegion::Morytania,
+        tier: DiaryTier::Elite,
+        tasks: vec![
+            task(
+                "Catch a shark in Burgh de Rott with your bare hands",
+                [
+                    skill_req(Skill::Fishing, Level(96)),
+                    skill_req(Skill::Strength, Level(76)),
+                    quest_req(QuestId::IN_AID_OF_THE_MYREQUE),
+                    miniquest_req(MiniquestId::BARBARIAN_TRAINING),
+                ],
+            ),
+            task(
+                "Cremate any shade remains on a magic or redwood pyre",
+                [
+                    skill_req(Skill::Firemaking, Level(80)),
+                    quest_req(QuestId::SHADES_OF_MORTTON),
+                ],
+            ),
+            task(
+                "Fertilize the Morytania herb patch using Lunar Magic",
+                [
+                    skill_req(Skill::Magic, Level(83)),
+                    quest_req(QuestId::LUNAR_DIPLOMACY),
+                ],
+            ),
+            task(
+                "Craft a black dragonhide body in Canifis bank",
+                [skill_req(Skill::Crafting, Level(84))],
+            ),
+            task(
+                "Kill an abyssal demon in the Slayer Tower",
+                [skill_req(Skill::Slayer, Level(85))],
+            ),
+            task(
+                "Loot the Barrows chest while wearing any complete Barrows set",
+                [skill_req(Skill::Defence, Level(70))],
+            ),
+        ],
+    }
+}
+
+fn varrock_easy() -> AchievementDiary {
+    AchievementDiary {
+        region: DiaryRegion::Varrock,
+        tier: DiaryTier::Easy,
+        tasks: vec![
+            task("Have Thessalia show you what outfits you can wear", []),
+            task(
+                "Have Aubury teleport you to the Essence mine",
+                [quest_req(QuestId::RUNE_MYSTERIES)],
+            ),
+            task(
+                "Mine some iron in the south east mining patch near Varrock",
+                [skill_req(Skill::Mining, Level(15))],
+            ),
+            task("Make a normal plank at the Sawmill", []),
+            task("Enter the second level of the Stronghold of Security", []),
+            task(
+                "Jump over the fence south of Varrock",
+                [skill_req(Skill::Agility, Level(13))],
+            ),
+            task("Chop down a dying tree in the Lumber Yard", []),
+            task("Buy a newspaper", []),
+            task("Give a dog a bone!", []),
+            task(
+                "Spin a bowl on the pottery wheel and fire it in the oven in Barbarian Village",
+                [skill_req(Skill::Crafting, Level(8))],
+            ),
+            task(
+                "Speak to Haig Halen after obtaining at least 50 Kudos",
+                [kudos_req(50)],
+            ),
+            task(
+                "Craft some earth runes from Essence",
+                [skill_req(Skill::Runecraft, Level(9))],
+            ),
+            task(
+                "Catch some trout in the River Lum at Barbarian Village",
+                [skill_req(Skill::Fishing, Level(20))],
+            ),
+            task(
+                "Steal from the tea stall in Varrock",
+                [skill_req(Skill::Thieving, Level(5))],
+            ),
+        ],
+    }
+}
+
+fn varrock_medium() -> AchievementDiary {
+    AchievementDiary {
+        region: DiaryRegion::Varrock,
+        tier: DiaryTier::Medium,
+        tasks: vec![
+            task(
+                "Have the Apothecary in Varrock make you a strength potion",
+                [],
+            ),
+            task("Enter the Champions' Guild", [quest_point_req(32)]),
+            task(
+                "Select a colour for your kitten",
+                [
+                    quest_req(QuestId::GERTRUDES_CAT),
+                    quest_req(QuestId::GARDEN_OF_TRANQUILLITY),
+                ],
+            ),
+            task(
+                "Use the spirit tree north of Varrock",
+                [quest_req(QuestId::TREE_GNOME_VILLAGE)],
+            ),
+            task("Perform the 4 emotes from the Stronghold of Security", []),
+            task(
+                "Enter the Tolna dungeon after completing A Soul's Bane",
+                [quest_req(QuestId::A_SOULS_BANE)],
+            ),
+            task(
+                "Teleport to the digsite using a Digsite pendant",
+                [quest_req(QuestId::THE_DIG_SITE)],
+            ),
+            task(
+                "Cast the teleport to Varrock spell",
+                [skill_req(Skill::Magic, Level(25))],
+            ),
+            task(
+                "Get a Slayer task from Vannaka",
+                [combat_level_req(Level(40))],
+            ),
+            task("Make 20 mahogany planks in one go", []),
+            task(
+                "Pick a white tree fruit",
+                [
+                    skill_req(Skill::Farming, Level(25)),
+                    quest_req(QuestId::GARDEN_OF_TRANQUILLITY),
+                ],
+            ),
+            task(
+                "Use the balloon to travel from Varrock",
+                [
+                    skill_req(Skill::Firemaking, Level(40)),
+                    quest_req(QuestId::ENLIGHTENED_JOURNEY),
+                ],
+            ),
+            task(
+                "Complete a lap of the Varrock Agility course",
+                [skill_req(Skill::Agility, Level(30))],
+            ),
+        ],
+    }
+}
+
+fn varrock_hard() -> AchievementDiary {
+    AchievementDiary {
+        region: DiaryRegion::Varrock,
+        tier: DiaryTier::Hard,
+        tasks: vec![
+            task(
+                "Trade furs with the Fancy Dress Seller for a spottier cape and equip it",
+                [skill_req(Skill::Hunter, Level(66))],
+            ),
+            task(
+                "Speak to Orlando Smith when you have achieved 153 Kudos",
+                [kudos_req(153)],
+            ),
+            task(
+                "Make a waka canoe near Edgeville",
+                [skill_req(Skill::Woodcutting, Level(57))],
+            ),
+            task(
+                "Teleport to Paddewwa",
+                [
+                    skill_req(Skill::Magic, Level(54)),
+                    quest_req(QuestId::DESERT_TREASURE_I),
+                ],
+            ),
+            task("Teleport to Barbarian Village with a skull sceptre", []),
+            task(
+                "Chop some yew logs in Varrock and burn them at the top of the Varrock church",
+                [
+                    skill_req(Skill::Woodcutting, Level(60)),
+                    skill_req(Skill::Firemaking, Level(60)),
+                ],
+            ),
+            task(
+                "Have the Varrock estate agent decorate your house with Fancy Stone",
+                [skill_req(Skill::Construction, Level(50))],
+            ),
+            task(
+                "Collect at least 2 yew roots from the tree patch in Varrock Palace",
+                [
+                    skill_req(Skill::Farming, Level(68)),
+                    skill_req(Skill::Woodcutting, Level(60)),
+                ],
+            ),
+            task(
+                "Pray at the altar in Varrock Palace with Smite active",
+                [skill_req_unboostable(Skill::Prayer, Level(52))],
+            ),
+            task(
+                "Squeeze through the obstacle pipe in Edgeville Dungeon",
+                [skill_req(Skill::Agility, Level(51))],
+            ),
+        ],
+    }
+}
+
+fn varrock_elite() -> AchievementDiary {
+    AchievementDiary {
+        region: DiaryRegion::Varrock,
+        tier: DiaryTier::Elite,
+        tasks: vec![
+            task(
+                "Create a super combat potion in Varrock west bank",
+                [
+                    skill_req(Skill::Herblore, Level(90)),
+                    quest_req(QuestId::DRUIDIC_RITUAL),
+                ],
+            ),
+            task(
+                "Use Lunar magic to make 20 mahogany planks in the Varrock Lumber Yard",
+                [
+                    skill_req(Skill::Magic, Level(86)),
+                    quest_req(QuestId::DREAM_MENTOR),
+                ],
+            ),
+            task(
+                "Bake a summer pie in the Cooking Guild",
+                [skill_req(Skill::Cooking, Level(95))],
+            ),
+            task(
+                "Smith and fletch ten rune darts within Varrock",
+                [
+                    skill_req(Skill::Smithing, Level(89)),
+                    skill_req(Skill::Fletching, Level(81)),
+                    quest_req(QuestId::THE_TOURIST_TRAP),
+                ],
+            ),
+            task(
+                "Craft 100 or more earth runes simultaneously from Essence",
+                [skill_req(Skill::Runecraft, Level(78))],
+            ),
+        ],
+    }
+}
+
+fn western_provinces_easy() -> AchievementDiary {
+    AchievementDiary {
+        region: DiaryRegion::WesternProvinces,
+        tier: DiaryTier::Easy,
+        tasks: vec![
+            task(
+                "Catch a copper longtail",
+                [skill_req(Skill::Hunter, Level(9))],
+            ),
+            task(
+                "Complete a novice game of Pest Control",
+                [combat_level_req(Level(40))],
+            ),
+            task(
+                "Mine some iron ore near Piscatoris",
+                [skill_req(Skill::Mining, Level(15))],
+            ),
+            task("Complete a lap of the Gnome Agility course", []),
+            task("Score a goal in a Gnomeball match", []),
+            task(
+                "Claim any Chompy bird hat from Rantz",
+                [
+                    skill_req(Skill::Ranged, Level(30)),
+                    quest_req(QuestId::BIG_CHOMPY_BIRD_HUNTING),
+                ],
+            ),
+            task("Teleport to Pest Control using the minigame teleports", []),
+            task("Collect a swamp toad at the Gnome Stronghold", []),
+            task(
+                "Have Brimstail teleport you to the Essence mine",
+                [quest_req(QuestId::RUNE_MYSTERIES)],
+            ),
+            task(
+                "Fletch an oak shortbow in the Gnome Stronghold",
+                [skill_req(Skill::Fletching, Level(20))],
+            ),
+            task("Kill a terrorbird in the terrorbird enclosure", []),
+        ],
+    }
+}
+
+fn western_provinces_medium() -> AchievementDiary {
+    AchievementDiary {
+        region: DiaryRegion::WesternProvinces,
+        tier: DiaryTier::Medium,
+        tasks: vec![
+            task(
+                "Take the agility shortcut from the Grand Tree to Otto's Grotto",
+                [
+                    skill_req(Skill::Agility, Level(37)),
+                    quest_req(QuestId::TREE_GNOME_VILLAGE),
+                    quest_req(QuestId::THE_GRAND_TREE),
+                ],
+            ),
+            task(
+                "Travel to the Gnome Stronghold by spirit tree",
+                [quest_req(QuestId::TREE_GNOME_VILLAGE)],
+            ),
+            task(
+                "Trap a spined larupia",
+                [skill_req(Skill::Hunter, Level(31))],
+            ),
+            task(
+                "Fish some bass on Ape Atoll",
+                [
+                    skill_req(Skill::Fishing, Level(46)),
+                    quest_req(QuestId::MONKEY_MADNESS_I),
+                ],
+            ),
+            task(
+                "Chop and burn some teak logs on Ape Atoll",
+                [
+                    skill_req(Skill::Woodcutting, Level(35)),
+                    skill_req(Skill::Firemaking, Level(35)),
+                    quest_req(QuestId::MONKEY_MADNESS_I),
+                ],
+            ),
+            task(
+                "Complete an intermediate game of Pest Control",
+                [combat_level_req(Level(70))],
+            ),
+            task(
+                "Travel to the Feldip Hills by gnome glider",
+                [quest_req(QuestId::ONE_SMALL_FAVOUR)],
+            ),
+            task(
+                "Claim a Chompy bird hat from Rantz after registering at least 125 kills",
+                [
+                    skill_req(Skill::Ranged, Level(30)),
+                    quest_req(QuestId::BIG_CHOMPY_BIRD_HUNTING),
+                ],
+            ),
+            task(
+                "Travel from Eagles' Peak to the Feldip Hills by Eagle",
+                [quest_req(QuestId::EAGLES_PEAK)],
+            ),
+            task(
+                "Make a chocolate bomb at the Grand Tree",
+                [skill_req(Skill::Cooking, Level(42))],
+            ),
+            task(
+                "Complete a delivery for the Gnome Restaurant",
+                [skill_req(Skill::Cooking, Level(29))],
+            ),
+            task(
+                "Turn your small crystal seed into a crystal saw",
+                [quest_req(QuestId::THE_EYES_OF_GLOUPHRIE)],
+            ),
+            task(
+                "Mine some gold ore underneath the Grand Tree",
+                [
+                    skill_req(Skill::Mining, Level(40)),
+                    quest_req(QuestId::THE_GRAND_TREE),
+                ],
+            ),
+        ],
+    }
+}
+
+fn western_provinces_hard() -> AchievementDiary {
+    AchievementDiary {
+        region: DiaryRegion::WesternProvinces,
+        tier: DiaryTier::Hard,
+        tasks: vec![
+            task(
+                "Kill an elf with a crystal bow",
+                [
+                    skill_req(Skill::Ranged, Level(70)),
+                    skill_req(Skill::Agility, Level(50)),
+                    quest_req(QuestId::ROVING_ELVES),
+                ],
+            ),
+            task(
+                "Catch and cook a monkfish in Piscatoris",
+                [
+                    skill_req(Skill::Fishing, Level(62)),
+                    skill_req(Skill::Cooking, Level(62)),
+                    quest_req(QuestId::SWAN_SONG),
+                ],
+            ),
+            task(
+                "Complete a veteran game of Pest Control",
+                [combat_level_req(Level(100))],
+            ),
+            task(
+                "Catch a dashing kebbit",
+                [skill_req(Skill::Hunter, Level(69))],
+            ),
+            task(
+                "Complete a lap of the Ape Atoll agility course",
+                [
+                    skill_req(Skill::Agility, Level(48)),
+                    quest_req(QuestId::MONKEY_MADNESS_I),
+                ],
+            ),
+            task(
+                "Chop and burn some mahogany logs on Ape Atoll",
+                [
+                    skill_req(Skill::Woodcutting, Level(50)),
+                    skill_req(Skill::Firemaking, Level(50)),
+                    quest_req(QuestId::MONKEY_MADNESS_I),
+                ],
+            ),
+            task(
+                "Mine some adamantite ore in Tirannwn",
+                [
+                    skill_req(Skill::Mining, Level(70)),
+                    quest_req(QuestId::REGICIDE),
+                ],
+            ),
+            task(
+                "Check the health of your palm tree in Lletya",
+                [
+                    skill_req(Skill::Farming, Level(68)),
+                    quest_req(QuestId::MOURNINGS_END_PART_I),
+                ],
+            ),
+            task(
+                "Claim a Chompy bird hat from Rantz after registering at least 300 kills",
+                [
+                    skill_req(Skill::Ranged, Level(30)),
+                    quest_req(QuestId::BIG_CHOMPY_BIRD_HUNTING),
+                ],
+            ),
+            task(
+                "Build an Isafdar painting in your POH quest hall",
+                [
+                    skill_req(Skill::Construction, Level(65)),
+                    quest_req(QuestId::ROVING_ELVES),
+                ],
+            ),
+            task("Kill Zulrah", [quest_req(QuestId::REGICIDE)]),
+            task(
+                "Teleport to Ape Atoll",
+                [
+                    skill_req(Skill::Magic, Level(64)),
+                    quest_req(QuestId::RECIPE_FOR_DISASTER_FREEING_KING_AWOWOGEI),
+                ],
+            ),
+            task(
+                "Pickpocket a gnome",
+                [skill_req(Skill::Thieving, Level(75))],
+            ),
+        ],
+    }
+}
+
+fn western_provinces_elite() -> AchievementDiary {
+    AchievementDiary {
+        region: DiaryRegion::WesternProvinces,
+        tier: DiaryTier::Elite,
+        tasks: vec![
+            task(
+                "Fletch a magic longbow in Tirannwn",
+                [
+                    skill_req(Skill::Fletching, Level(85)),
+                    quest_req(QuestId::MOURNINGS_END_PART_I),
+                ],
+            ),
+            task(
+                "Kill the Thermonuclear smoke devil",
+                [skill_req(Skill::Slayer, Level(93))],
+            ),
+            task(
+                "Have Prissy Scilla protect your magic tree",
+                [skill_req(Skill::Farming, Level(75))],
+            ),
+            task(
+                "Use the Elven overpass advanced cliffside shortcut",
+                [
+                    skill_req(Skill::Agility, Level(85)),
+                    quest_req(QuestId::UNDERGROUND_PASS),
+                ],
+            ),
+            task(
+                "Equip any complete void set",
+                [
+                    skill_req(Skill::Attack, Level(42)),
+                    skill_req(Skill::Strength, Level(42)),
+                    skill_req(Skill::Defence, Level(42)),
+                    skill_req(Skill::Hitpoints, Level(42)),
+                    skill_req(Skill::Ranged, Level(42)),
+                    skill_req(Skill::Magic, Level(42)),
+                    skill_req_unboostable(Skill::Prayer, Level(22)),
+                ],
+            ),
+            task(
+                "Claim a Chompy bird hat from Rantz after registering at least 1000 kills",
+                [
+                    skill_req(Skill::Ranged, Level(30)),
+                    quest_req(QuestId::BIG_CHOMPY_BIRD_HUNTING),
+                ],
+            ),
+            task(
+                "Pickpocket an elf",
+                [
+                    skill_req(Skill::Thieving, Level(85)),
+                    quest_req(QuestId::MOURNINGS_END_PART_I),
+                ],
+            ),
+        ],
+    }
+}
+
+fn wilderness_easy() -> AchievementDiary {
+    AchievementDiary {
+        region: DiaryRegion::Wilderness,
+        tier: DiaryTier::Easy,
+        tasks: vec![
+            task(
+                "Cast Low Alchemy at the Fountain of Rune",
+                [skill_req(Skill::Magic, Level(21))],
+            ),
+            task(
+                "Enter the Wilderness from the Ardougne or Edgeville lever",
+                [],
+            ),
+            task("Pray at the Chaos Altar in western Wilderness", []),
+            task("Enter the Chaos Runecrafting temple", []),
+            task("Kill a mammoth in the Wilderness", []),
+            task(
+                "Kill an earth warrior in the Wilderness beneath Edgeville",
+                [skill_req(Skill::Agility, Level(15))],
+            ),
+            task("Restore some prayer points at the demonic ruins", []),
+            task("Enter the King Black Dragon Lair", []),
+            task("Collect 5 red spider's eggs from the Wilderness", []),
+            task(
+                "Mine some iron ore in the Wilderness",
+                [skill_req(Skill::Mining, Level(15))],
+            ),
+            task(
+                "Have the Mage of Zamorak teleport you to the Abyss",
+                [miniquest_req(MiniquestId::ENTER_THE_ABYSS)],
+            ),
+            task("Equip any team cape in the Wilderness", []),
+        ],
+    }
+}
+
+fn wilderness_medium() -> AchievementDiary {
+    AchievementDiary {
+        region: DiaryRegion::Wilderness,
+        tier: DiaryTier::Medium,
+        tasks: vec![
+            task(
+                "Mine some mithril ore in the Wilderness",
+                [skill_req(Skill::Mining, Level(55))],
+            ),
+            task(
+                "Chop some yew logs from a fallen Ent",
+                [skill_req(Skill::Woodcutting, Level(61))],
+            ),
+            task(
+                "Enter the Wilderness God Wars Dungeon",
+                [QuestRequirement::Any(vec![
+                    skill_req(Skill::Agility, Level(60)),
+                    skill_req(Skill::Strength, Level(60)),
+                ])],
+            ),
+            task(
+                "Complete a lap of the Wilderness Agility course",
+                [skill_req(Skill::Agility, Level(52))],
+            ),
+            task("Kill a green dragon", []),
+            task("Kill an ankou in the Wilderness", []),
+            task("Charge an earth orb", [skill_req(Skill::Magic, Level(60))]),
+            task(
+                "Kill a bloodveld in the Wilderness God Wars Dungeon",
+                [skill_req(Skill::Slayer, Level(50))],
+            ),
+            task("Talk to the Emblem Trader in Edgeville", []),
+            task(
+                "Smith a gold helmet in the Resource Area",
+                [
+                    skill_req(Skill::Smithing, Level(50)),
+                    quest_req(QuestId::BETWEEN_A_ROCK),
+                ],
+            ),
+            task("Open the muddy chest in the Lava Maze", []),
+        ],
+    }
+}
+
+fn wilderness_hard() -> AchievementDiary {
+    AchievementDiary {
+        region: DiaryRegion::Wilderness,
+        tier: DiaryTier::Hard,
+        tasks: vec![
+            task(
+                "Cast one of the three god spells against another player in the Wilderness",
+                [
+                    skill_req(Skill::Magic, Level(60)),
+                    miniquest_req(MiniquestId::MAGE_ARENA_I),
+                ],
+            ),
+            task("Charge an air orb", [skill_req(Skill::Magic, Level(66))]),
+            task(
+                "Catch a black salamander in the Wilderness",
+                [skill_req(Skill::Hunter, Level(67))],
+            ),
+            task(
+                "Smith an adamant scimitar in the Resource Area",
+                [skill_req(Skill::Smithing, Level(75))],
+            ),
+            task(
+                "Kill a lava dragon and bury the bones on Lava Dragon Isle",
+                [],
+            ),
+            task("Kill the Chaos Elemental", []),
+            task(
+                "Kill the Crazy Archaeologist, Chaos Fanatic and Scorpia",
+                [],
+            ),
+            task(
+                "Take the agility shortcut from Trollheim into the Wilderness",
+                [
+                    skill_req(Skill::Agility, Level(64)),
+                    quest_req(QuestId::DEATH_PLATEAU),
+                ],
+            ),
+            task(
+                "Kill a spiritual warrior in the Wilderness God Wars Dungeon",
+                [skill_req(Skill::Slayer, Level(68))],
+            ),
+            task(
+                "Fish some raw lava eel in the Wilderness",
+                [skill_req(Skill::Fishing, Level(53))],
+            ),
+        ],
+    }
+}
+
+fn wilderness_elite() -> AchievementDiary {
+    AchievementDiary {
+        region: DiaryRegion::Wilderness,
+        tier: DiaryTier::Elite,
+        tasks: vec![
+            task("Kill Callisto, Venenatis and Vet'ion", []),
+            task(
+                "Teleport to Ghorrock",
+                [
+                    skill_req(Skill::Magic, Level(96)),
+                    quest_req(QuestId::DESERT_TREASURE_I),
+                ],
+            ),
+            task(
+                "Fish and cook a dark crab in the Resource Area",
+                [
+                    skill_req(Skill::Fishing, Level(85)),
+                    skill_req(Skill::Cooking, Level(90)),
+                ],
+            ),
+            task(
+                "Smith a rune scimitar from scratch in the Resource Area",
+                [
+                    skill_req(Skill::Mining, Level(85)),
+                    skill_req(Skill::Smithing, Level(90)),
+                ],
+            ),
+            task(
+                "Steal from the Rogues' chest",
+                [skill_req(Skill::Thieving, Level(84))],
+            ),
+            task(
+                "Slay a spiritual mage inside the Wilderness God Wars Dungeon",
+                [skill_req(Skill::Slayer, Level(83))],
+            ),
+            task(
+                "Cut and burn some magic logs in the Resource Area",
+                [
+                    skill_req(Skill::Woodcutting, Level(75)),
+                    skill_req(Skill::Firemaking, Level(75)),
+                ],
+            ),
+        ],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::{PlayerState, UnmetRequirement};
+
+    use super::*;
+
+    #[test]
+    fn test_all_diaries_are_defined() {
+        let task_counts = [
+            (DiaryRegion::Ardougne, [10, 12, 12, 8]),
+            (DiaryRegion::Desert, [11, 12, 10, 6]),
+            (DiaryRegion::Falador, [11, 14, 11, 6]),
+            (DiaryRegion::Fremennik, [10, 9, 8, 6]),
+            (DiaryRegion::Kandarin, [11, 14, 11, 7]),
+            (DiaryRegion::Karamja, [10, 19, 10, 5]),
+            (DiaryRegion::KourendAndKebos, [12, 13, 10, 8]),
+            (DiaryRegion::LumbridgeAndDraynor, [12, 12, 11, 6]),
+            (DiaryRegion::Morytania, [11, 10, 10, 6]),
+            (DiaryRegion::Varrock, [14, 13, 10, 5]),
+            (DiaryRegion::WesternProvinces, [11, 13, 13, 7]),
+            (DiaryRegion::Wilderness, [12, 11, 10, 7]),
+        ];
+        assert_eq!(AchievementDiary::all().count(), task_counts.len() * 4);
+        for (region, counts) in task_counts {
+            for (tier, count) in DiaryTier::iter().zip(counts) {
+                assert_eq!(
+                    AchievementDiary::get(region, tier).tasks.len(),
+                    count,
+                    "{region} {tier}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_combined_requirements() {
+        let diary = AchievementDiary::get(DiaryRegion::Falador, DiaryTier::Easy);
+        assert_eq!(
+            diary.requirements(),
+            vec![
+                skill_req(Skill::Smithing, Level(13)),
+                skill_req(Skill::Mining, Level(10)),
+                skill_req(Skill::Agility, Level(5)),
+                skill_req(Skill::Construction, Level(16)),
+                quest_req(QuestId::RUNE_MYSTERIES),
+                quest_req(QuestId::THE_KNIGHTS_SWORD),
+                quest_req(QuestId::DORICS_QUEST),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_merged_requirements_keep_the_stricter_boostability() {
+        let diary = AchievementDiary {
+            region: DiaryRegion::Kandarin,
+            tier: DiaryTier::Hard,
+            tasks: vec![
+                task(
+                    "Unboostable",
+                    [skill_req_unboostable(Skill::Prayer, Level(70))],
+                ),
+                task("Boostable", [skill_req(Skill::Prayer, Level(72))]),
+            ],
+        };
+        assert_eq!(
+            diary.requirements(),
+            vec![skill_req_unboostable(Skill::Prayer, Level(72))]
+        );
+    }
+
+    #[test]
+    fn test_diary_requirements() {
+        let diary = AchievementDiary::get(DiaryRegion::Ardougne, DiaryTier::Medium);
+        let mut player = PlayerState::new();
+        player.set_level(Skill::Agility, Level(35));
+
+        let unmet = diary.unmet_requirements(&player);
+        assert!(unmet.contains(&UnmetRequirement::Skill {
+            skill: Skill::Agility,
+            required: Level(39),
+            current: Level(35),
+        }));
+        assert!(unmet.contains(&UnmetRequirement::Quest(QuestId::PLAGUE_CITY)));
+        assert_eq!(
+            unmet
+                .iter()
+                .filter(|unmet| matches!(
+                    unmet,
+                    UnmetRequirement::Skill {
+                        skill: Skill::Agility,
+                        ..
+                    }
+                ))
+                .count(),
+            1
+        );
+
+        for skill in Skill::iter() {
+            player.set_level(skill, Level(60));
+        }
+        for quest in [
+            QuestId::FAIRYTALE_II,
+            QuestId::PLAGUE_CITY,
+            QuestId::ENLIGHTENED_JOURNEY,
+            QuestId::THE_HAND_IN_THE_SAND,
+            QuestId::SEA_SLUG,
+            QuestId::WATCHTOWER,
+            QuestId::TOWER_OF_LIFE,
+            QuestId::UNDERGROUND_PASS,
+        ] {
+            player.completed_quests.insert(quest);
+        }
+        assert_eq!(diary.unmet_requirements(&player), Vec::new());
+    }
+}
@@ -0,0 +1,96 @@
+use std::sync::LazyLock;
+
+use strum::IntoEnumIterator;
+
+use crate::{Boost, Level, Skill};
+
+impl Boost {
+    /// Returns all known boosts.
+    pub fn all() -> &'static [Boost] {
+        &BOOSTS
+    }
+
+    /// Returns all known boosts for the given skill.
+    pub fn for_skill(skill: Skill) -> impl Iterator<Item = &'static Boost> {
+        Self::all().iter().filter(move |boost| boost.skill == skill)
+    }
+
+    /// Returns the boost that is guaranteed to add the most levels to the given
+    /// skill at the given base level.
+    pub fn best(skill: Skill, level: Level) -> Option<&'static Boost> {
+        Self::for_skill(skill)
+            .filter(|boost| !boost.random)
+            .max_by_key(|boost| boost.amount(level))
+    }
+}
+
+static BOOSTS: LazyLock<Vec<Boost>> = LazyLock::new(|| {
+    let mut boosts = vec![
+        // Potions
+        Boost::new("Attack potion", Skill::Attack, 3).with_percent(10),
+        Boost::new("Super attack", Skill::Attack, 5).with_percent(15),
+        Boost::new("Strength potion", Skill::Strength, 3).with_percent(10),
+        Boost::new("Super strength", Skill::Strength, 5).with_percent(15),
+        Boost::new("Defence potion", Skill::Defence, 3).with_percent(10),
+        Boost::new("Super defence", Skill::Defence, 5).with_percent(15),
+        Boost::new("Ranging potion", Skill::Ranged, 4).with_percent(10),
+        Boost::new("Magic potion", Skill::Magic, 4),
+        Boost::new("Agility potion", Skill::Agility, 3),
+        Boost::new("Fishing potion", Skill::Fishing, 3),
+        Boost::new("Hunter potion", Skill::Hunter, 3),
+        // Pies
+        Boost::new("Garden pie", Skill::Farming, 3),
+        Boost::new("Fish pie", Skill::Fishing, 3),
+        Boost::new("Botanical pie", Skill::Herblore, 4),
+        Boost::new("Mushroom pie", Skill::Crafting, 4),
+        Boost::new("Admiral pie", Skill::Fishing, 5),
+        Boost::new("Dragonfruit pie", Skill::Fletching, 4),
+        Boost::new("Wild pie", Skill::Slayer, 5),
+        Boost::new("Wild pie", Skill::Ranged, 4),
+        Boost::new("Summer pie", Skill::Agility, 5),
+        // Drinks
+        Boost::new("Dwarven stout", Skill::Mining, 1),
+        Boost::new("Dwarven stout", Skill::Smithing, 1),
+        Boost::new("Wizard's mind bomb", Skill::Magic, 2),
+        Boost::new("Chef's delight", Skill::Cooking, 1).with_percent(5),
+        Boost::new("Greenman's ale", Skill::Herblore, 1),
+        Boost::new("Cider", Skill::Farming, 1),
+        // Special attacks and tools
+        Boost::new("Dragon pickaxe", Skill::Mining, 3),
+        Boost::new("Dragon axe", Skill::Woodcutting, 3),
+        Boost::new("Dragon harpoon", Skill::Fishing, 3),
+    ];
+
+    // Spicy stews boost or lower a random amount of up to 5 levels, depending
+    // on the spices used.
+    boosts.extend(
+        Skill::iter()
+            .filter(|skill| !matches!(skill, Skill::Hitpoints | Skill::Prayer | Skill::Sailing))
+            .map(|skill| Boost::new("Spicy stew", skill, 5).random()),
+    );
+
+    boosts
+});
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_best_boost() {
+        let super_attack = Boost::best(Skill::Attack, Level(80)).unwrap();
+        assert_eq!(super_attack.name, "Super attack");
+        assert_eq!(super_attack.apply(Level(80)), Level(97));
+
+        assert_eq!(
+            Boost::best(Skill::Slayer, Level(50))
+                .unwrap()
+                .amount(Level(50)),
+            5
+        );
+        assert_eq!(Boost::best(Skill::Prayer, Level(50)), None);
+        assert_eq!(Boost::best(Skill::Construction, Level(50)), None);
+    }
+}
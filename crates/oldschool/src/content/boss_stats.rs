@@ -0,0 +1,124 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::LazyLock;
+
+use crate::{Boss, BossStats};
+
+/// The bundled boss stats dataset.
+///
+/// Bosses without a single fightable form, such as raids and reward chests, and skilling bosses
+/// are not included.
+const BOSS_STATS_CSV: &str = include_str!("../../data/boss_stats.csv");
+
+impl Boss {
+    /// Returns the combat stats of this boss, if they are known.
+    pub fn stats(&self) -> Option<&'static BossStats> {
+        BOSS_STATS.get(self)
+    }
+}
+
+static BOSS_STATS: LazyLock<HashMap<Boss, BossStats>> = LazyLock::new(|| {
+    data_rows(BOSS_STATS_CSV)
+        .map(|(line, row)| {
+            let stats = BossStats::from_str(row)
+                .unwrap_or_else(|err| panic!("invalid boss stats on line {line}: {err}"));
+
+            (stats.boss, stats)
+        })
+        .collect()
+});
+
+/// Returns the non-empty, non-comment rows of the given dataset, along with their line numbers.
+fn data_rows(text: &str) -> impl Iterator<Item = (usize, &str)> {
+    text.lines()
+        .enumerate()
+        .map(|(index, row)| (index + 1, row.trim()))
+        .filter(|(_, row)| !row.is_empty() && !row.starts_with('#'))
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use strum::IntoEnumIterator;
+
+    use crate::{AttackStyle, Level, ParseBossStatsError};
+
+    use super::*;
+
+    #[test]
+    fn test_boss_stats() {
+        assert_eq!(BOSS_STATS.len(), data_rows(BOSS_STATS_CSV).count());
+
+        let vorkath = Boss::Vorkath.stats().unwrap();
+        assert_eq!(vorkath.hitpoints, 750);
+        assert_eq!(vorkath.attack, Level(560));
+        assert_eq!(vorkath.defence_bonuses.against(AttackStyle::Magic), 240);
+        assert!(vorkath.is_weak_to(AttackStyle::Stab));
+        assert!(!vorkath.is_weak_to(AttackStyle::Magic));
+
+        assert_eq!(Boss::ChambersOfXeric.stats(), None);
+    }
+
+    #[test]
+    fn test_every_fightable_boss_has_stats() {
+        let excluded = [
+            // Raids
+            Boss::ChambersOfXeric,
+            Boss::ChambersOfXericChallengeMode,
+            Boss::TheatreOfBlood,
+            Boss::TheatreOfBloodHardMode,
+            Boss::TombsOfAmascut,
+            Boss::TombsOfAmascutExpertMode,
+            // Reward chests
+            Boss::BarrowsChests,
+            Boss::LunarChests,
+            // Skilling bosses
+            Boss::Tempoross,
+            Boss::Wintertodt,
+            Boss::Zalcano,
+        ];
+
+        for boss in Boss::iter() {
+            assert_eq!(boss.stats().is_some(), !excluded.contains(&boss), "{boss}");
+        }
+    }
+
+    #[test]
+    fn test_boss_weaknesses() {
+        use AttackStyle::*;
+
+        let weaknesses = |boss: Boss| boss.stats().unwrap().weaknesses();
+
+        assert_eq!(
+            weaknesses(Boss::GeneralGraardor),
+            vec![Stab, Slash, Crush, Ranged]
+        );
+        assert_eq!(
+            weaknesses(Boss::ChaosElemental),
+            AttackStyle::iter().collect::<Vec<_>>()
+        );
+        assert_eq!(weaknesses(Boss::Kreearra), vec![Magic, Ranged]);
+        assert_eq!(weaknesses(Boss::TzKalZuk), vec![Ranged]);
+        assert_eq!(weaknesses(Boss::Zulrah), vec![Magic]);
+
+        for stats in BOSS_STATS.values() {
+            assert!(!stats.weaknesses().is_empty(), "{}", stats.boss);
+        }
+    }
+
+    #[test]
+    fn test_boss_stats_parsing_errors() {
+        assert!(matches!(
+            BossStats::from_str("Vorkath,750"),
+            Err(ParseBossStatsError::ColumnCount {
+                expected: 15,
+                found: 2
+            })
+        ));
+        assert!(matches!(
+            BossStats::from_str("Vorkath,750,560,308,214,150,308,26,108,108,240,26,32,5,Fire"),
+            Err(ParseBossStatsError::UnknownAttackStyle(style)) if style == "Fire"
+        ));
+    }
+}
@@ -0,0 +1,385 @@
+use std::str::FromStr;
+use std::sync::LazyLock;
+
+use thiserror::Error;
+
+use crate::{
+    Boss, CombatAchievement, CombatAchievementId, CombatAchievementTier, CombatAchievementType,
+    QuestReward, Unlock, UnlockKind,
+};
+
+#[derive(Debug, Error)]
+#[error("combat achievement not found: {0:?}")]
+pub struct CombatAchievementNotFoundError(pub String);
+
+impl CombatAchievementId {
+    /// Returns the task with this id.
+    pub fn task(&self) -> &'static CombatAchievement {
+        CombatAchievement::all()
+            .iter()
+            .find(|task| task.id() == *self)
+            .expect("ids are only created from catalogued tasks")
+    }
+}
+
+impl FromStr for CombatAchievementId {
+    type Err = CombatAchievementNotFoundError;
+
+    /// Looks up a catalogued task by its exact name.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        CombatAchievement::all()
+            .iter()
+            .find(|task| task.name == name)
+            .map(CombatAchievement::id)
+            .ok_or_else(|| CombatAchievementNotFoundError(name.to_string()))
+    }
+}
+
+impl CombatAchievement {
+    /// Returns all known Combat Achievement tasks.
+    ///
+    /// Only the tasks of some bosses are catalogued so far.
+    pub fn all() -> &'static [CombatAchievement] {
+        &COMBAT_ACHIEVEMENTS
+    }
+
+    /// Returns whether the tasks for the given boss are catalogued.
+    pub fn is_catalogued(boss: Boss) -> bool {
+        Self::all().iter().any(|task| task.boss == boss)
+    }
+
+    /// Returns all Combat Achievement tasks for the given boss, or `None` if
+    /// its tasks are not catalogued.
+    pub fn for_boss(boss: Boss) -> Option<impl Iterator<Item = &'static CombatAchievement>> {
+        Self::is_catalogued(boss).then(|| Self::all().iter().filter(move |task| task.boss == boss))
+    }
+
+    /// Returns all known Combat Achievement tasks in the given tier.
+    pub fn in_tier(
+        tier: CombatAchievementTier,
+    ) -> impl Iterator<Item = &'static CombatAchievement> {
+        Self::all().iter().filter(move |task| task.tier == tier)
+    }
+}
+
+impl CombatAchievementTier {
+    /// Returns the total number of points needed to unlock this tier's rewards.
+    pub const fn points_required(&self) -> u32 {
+        match self {
+            Self::Easy => 38,
+            Self::Medium => 148,
+            Self::Hard => 394,
+            Self::Elite => 1_038,
+            Self::Master => 1_566,
+            Self::Grandmaster => 2_221,
+        }
+    }
+
+    /// Returns the rewards unlocked upon reaching this tier.
+    pub fn rewards(&self) -> Vec<QuestReward> {
+        let hilt = |tier: u8| QuestReward::Item {
+            name: format!("Ghommal's hilt {tier}"),
+            quantity: 1,
+        };
+        let unlock = |kind, name: &str| {
+            QuestReward::Unlock(Unlock {
+                kind,
+                name: name.to_string(),
+            })
+        };
+
+        match self {
+            Self::Easy => vec![hilt(1)],
+            Self::Medium => vec![
+                hilt(2),
+                unlock(UnlockKind::Feature, "Daily Mor Ul Rek teleports"),
+            ],
+            Self::Hard => vec![
+                hilt(3),
+                unlock(UnlockKind::Equipment, "Tztok slayer helmet"),
+            ],
+            Self::Elite => vec![
+                hilt(4),
+                unlock(UnlockKind::Equipment, "Vampyric slayer helmet"),
+                unlock(UnlockKind::Feature, "Free instances"),
+            ],
+            Self::Master => vec![
+                hilt(5),
+                unlock(UnlockKind::Equipment, "Tzkal slayer helmet"),
+                unlock(UnlockKind::Equipment, "Ghommal's avernic defender 5"),
+            ],
+            Self::Grandmaster => vec![
+                hilt(6),
+                unlock(UnlockKind::Equipment, "Ghommal's avernic defender 6"),
+            ],
+        }
+    }
+}
+
+static COMBAT_ACHIEVEMENTS: LazyLock<Vec<CombatAchievement>> = LazyLock::new(|| {
+    use CombatAchievementTier::*;
+    use CombatAchievementType::*;
+
+    vec![
+        // Barrows
+        CombatAchievement::new("Barrows Novice", Boss::BarrowsChests, Easy, KillCount),
+        CombatAchievement::new(
+            "Defence? What Defence?",
+            Boss::BarrowsChests,
+            Easy,
+            Restriction,
+        ),
+        CombatAchievement::new("Barrows Champion", Boss::BarrowsChests, Medium, KillCount),
+        CombatAchievement::new("Can't Touch Me", Boss::BarrowsChests, Medium, Mechanical),
+        CombatAchievement::new(
+            "Faithless Crypt Run",
+            Boss::BarrowsChests,
+            Hard,
+            Restriction,
+        ),
+        // Wintertodt
+        CombatAchievement::new("Handyman", Boss::Wintertodt, Easy, Mechanical),
+        CombatAchievement::new("Mummy!", Boss::Wintertodt, Easy, Mechanical),
+        CombatAchievement::new("Cosy", Boss::Wintertodt, Easy, Restriction),
+        // Tempoross
+        CombatAchievement::new("Master of Buckets", Boss::Tempoross, Easy, Mechanical),
+        // Giant Mole
+        CombatAchievement::new("Giant Mole Adept", Boss::GiantMole, Medium, KillCount),
+        CombatAchievement::new(
+            "Avoiding Those Little Arms",
+            Boss::GiantMole,
+            Medium,
+            Mechanical,
+        ),
+        // Sarachnis
+        CombatAchievement::new("Sarachnis Novice", Boss::Sarachnis, Easy, KillCount),
+        CombatAchievement::new("Sarachnis Adept", Boss::Sarachnis, Medium, KillCount),
+        // King Black Dragon
+        CombatAchievement::new(
+            "King Black Dragon Adept",
+            Boss::KingBlackDragon,
+            Medium,
+            KillCount,
+        ),
+        // Vorkath
+        CombatAchievement::new("Vorkath Veteran", Boss::Vorkath, Hard, KillCount),
+        CombatAchievement::new("Dodging the Dragon", Boss::Vorkath, Elite, Mechanical),
+        CombatAchievement::new("Vorkath Speed-Chaser", Boss::Vorkath, Elite, Speed),
+        CombatAchievement::new("Vorkath Speed-Runner", Boss::Vorkath, Master, Speed),
+        // Zulrah
+        CombatAchievement::new("Zulrah Veteran", Boss::Zulrah, Hard, KillCount),
+        CombatAchievement::new("Perfect Zulrah", Boss::Zulrah, Elite, Perfection),
+        CombatAchievement::new("Zulrah Speed-Chaser", Boss::Zulrah, Elite, Speed),
+        CombatAchievement::new("Zulrah Speed-Runner", Boss::Zulrah, Master, Speed),
+        // TzTok-Jad
+        CombatAchievement::new("Fight Caves Veteran", Boss::TzTokJad, Hard, KillCount),
+        CombatAchievement::new("Denying the Healers", Boss::TzTokJad, Elite, Mechanical),
+        CombatAchievement::new("Fight Caves Speed-Chaser", Boss::TzTokJad, Master, Speed),
+        // TzKal-Zuk
+        CombatAchievement::new(
+            "Inferno Grandmaster",
+            Boss::TzKalZuk,
+            Grandmaster,
+            KillCount,
+        ),
+        CombatAchievement::new("Nibblers, Begone!", Boss::TzKalZuk, Grandmaster, Mechanical),
+        // The Gauntlet
+        CombatAchievement::new("Gauntlet Veteran", Boss::TheGauntlet, Hard, KillCount),
+        CombatAchievement::new("Egniol Diet", Boss::TheGauntlet, Elite, Restriction),
+        CombatAchievement::new(
+            "Perfect Crystalline Hunllef",
+            Boss::TheGauntlet,
+            Elite,
+            Perfection,
+        ),
+        // The Corrupted Gauntlet
+        CombatAchievement::new(
+            "Corrupted Gauntlet Master",
+            Boss::TheCorruptedGauntlet,
+            Master,
+            KillCount,
+        ),
+        CombatAchievement::new(
+            "Perfect Corrupted Hunllef",
+            Boss::TheCorruptedGauntlet,
+            Master,
+            Perfection,
+        ),
+        CombatAchievement::new(
+            "Wolf Puncher II",
+            Boss::TheCorruptedGauntlet,
+            Grandmaster,
+            Restriction,
+        ),
+        // Chambers of Xeric
+        CombatAchievement::new(
+            "Chambers of Xeric Veteran",
+            Boss::ChambersOfXeric,
+            Elite,
+            KillCount,
+        ),
+        CombatAchievement::new(
+            "Perfect Olm (Solo)",
+            Boss::ChambersOfXeric,
+            Master,
+            Perfection,
+        ),
+        CombatAchievement::new(
+            "Chambers of Xeric: CM Grandmaster",
+            Boss::ChambersOfXericChallengeMode,
+            Grandmaster,
+            KillCount,
+        ),
+        // Theatre of Blood
+        CombatAchievement::new(
+            "Theatre of Blood Veteran",
+            Boss::TheatreOfBlood,
+            Elite,
+            KillCount,
+        ),
+        CombatAchievement::new(
+            "Perfect Theatre",
+            Boss::TheatreOfBlood,
+            Grandmaster,
+            Perfection,
+        ),
+        CombatAchievement::new(
+            "Theatre: HM Grandmaster",
+            Boss::TheatreOfBloodHardMode,
+            Grandmaster,
+            KillCount,
+        ),
+        // Tombs of Amascut
+        CombatAchievement::new(
+            "Tombs of Amascut Veteran",
+            Boss::TombsOfAmascut,
+            Elite,
+            KillCount,
+        ),
+        CombatAchievement::new(
+            "Tombs of Amascut: Expert Mode Grandmaster",
+            Boss::TombsOfAmascutExpertMode,
+            Grandmaster,
+            KillCount,
+        ),
+        // Nex
+        CombatAchievement::new("Nex Master", Boss::Nex, Master, KillCount),
+        // Corporeal Beast
+        CombatAchievement::new(
+            "Corporeal Beast Master",
+            Boss::CorporealBeast,
+            Master,
+            KillCount,
+        ),
+    ]
+});
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use pretty_assertions::assert_eq;
+
+    use strum::IntoEnumIterator;
+
+    use crate::CombatAchievementProgress;
+
+    use super::*;
+
+    #[test]
+    fn test_combat_achievement_progress() {
+        let mut progress = CombatAchievementProgress::new();
+        assert_eq!(progress.points(), 0);
+
+        progress.completed.insert("Perfect Zulrah".parse().unwrap());
+        progress.completed.insert("Zulrah Veteran".parse().unwrap());
+        assert_eq!(progress.points(), 7);
+        assert_eq!(
+            progress
+                .completed_for(Boss::Zulrah)
+                .unwrap()
+                .map(|task| task.name)
+                .collect::<Vec<_>>(),
+            vec!["Zulrah Veteran", "Perfect Zulrah"]
+        );
+        assert_eq!(
+            progress
+                .remaining_for(Boss::Zulrah)
+                .unwrap()
+                .map(|task| task.tier)
+                .collect::<Vec<_>>(),
+            vec![CombatAchievementTier::Elite, CombatAchievementTier::Master]
+        );
+
+        let grandmaster =
+            CombatAchievement::in_tier(CombatAchievementTier::Grandmaster).collect::<Vec<_>>();
+        for task in &grandmaster {
+            progress.completed.insert(task.id());
+        }
+        assert_eq!(
+            progress.points(),
+            7 + grandmaster.len() as u32 * CombatAchievementTier::Grandmaster.points_per_task()
+        );
+        assert!(progress.remaining_for(Boss::Araxxor).is_none());
+    }
+
+    #[test]
+    fn test_combat_achievement_ids() {
+        let id = CombatAchievementId::from_str("Perfect Zulrah").unwrap();
+        assert_eq!(id.as_str(), "Perfect Zulrah");
+        assert_eq!(id.task().boss, Boss::Zulrah);
+        assert!(matches!(
+            CombatAchievementId::from_str("Perfect Zulra"),
+            Err(CombatAchievementNotFoundError(name)) if name == "Perfect Zulra"
+        ));
+
+        let names = CombatAchievement::all()
+            .iter()
+            .map(|task| task.name)
+            .collect::<HashSet<_>>();
+        assert_eq!(names.len(), CombatAchievement::all().len());
+    }
+
+    #[test]
+    fn test_catalogued_bosses() {
+        let catalogued = [
+            Boss::BarrowsChests,
+            Boss::ChambersOfXeric,
+            Boss::ChambersOfXericChallengeMode,
+            Boss::CorporealBeast,
+            Boss::GiantMole,
+            Boss::KingBlackDragon,
+            Boss::Nex,
+            Boss::Sarachnis,
+            Boss::Tempoross,
+            Boss::TheCorruptedGauntlet,
+            Boss::TheGauntlet,
+            Boss::TheatreOfBlood,
+            Boss::TheatreOfBloodHardMode,
+            Boss::TombsOfAmascut,
+            Boss::TombsOfAmascutExpertMode,
+            Boss::TzKalZuk,
+            Boss::TzTokJad,
+            Boss::Vorkath,
+            Boss::Wintertodt,
+            Boss::Zulrah,
+        ];
+        for boss in Boss::iter() {
+            assert_eq!(
+                CombatAchievement::for_boss(boss).is_some(),
+                catalogued.contains(&boss),
+                "{boss}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_tier_thresholds_are_increasing() {
+        let thresholds = CombatAchievementTier::iter()
+            .map(|tier| tier.points_required())
+            .collect::<Vec<_>>();
+
+        assert!(thresholds.is_sorted());
+        assert_eq!(CombatAchievementTier::Elite.rewards().len(), 3);
+    }
+}
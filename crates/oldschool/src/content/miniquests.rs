@@ -0,0 +1,465 @@
+use std::collections::HashMap;
+use std::sync::{Arc, LazyLock};
+
+use indexmap::IndexSet;
+use thiserror::Error;
+
+use super::quests::{
+    item_reward, miniquest_req, quest_req, skill_req, skill_req_unboostable, unlock_reward,
+    xp_reward,
+};
+use crate::{Level, Miniquest, MiniquestId, QuestId, QuestReward, Skill, UnlockKind, Xp};
+
+impl MiniquestId {
+    /// <https://oldschool.runescape.wiki/w/Alfred_Grimhand%27s_Barcrawl>
+    pub const ALFRED_GRIMHANDS_BARCRAWL: Self = Self::new_static("alfred_grimhands_barcrawl");
+
+    /// <https://oldschool.runescape.wiki/w/Architectural_Alliance>
+    pub const ARCHITECTURAL_ALLIANCE: Self = Self::new_static("architectural_alliance");
+
+    /// <https://oldschool.runescape.wiki/w/Barbarian_Training>
+    pub const BARBARIAN_TRAINING: Self = Self::new_static("barbarian_training");
+
+    /// <https://oldschool.runescape.wiki/w/Bear_Your_Soul>
+    pub const BEAR_YOUR_SOUL: Self = Self::new_static("bear_your_soul");
+
+    /// <https://oldschool.runescape.wiki/w/Curse_of_the_Empty_Lord>
+    pub const CURSE_OF_THE_EMPTY_LORD: Self = Self::new_static("curse_of_the_empty_lord");
+
+    /// <https://oldschool.runescape.wiki/w/Daddy%27s_Home>
+    pub const DADDYS_HOME: Self = Self::new_static("daddys_home");
+
+    /// <https://oldschool.runescape.wiki/w/The_Enchanted_Key>
+    pub const THE_ENCHANTED_KEY: Self = Self::new_static("the_enchanted_key");
+
+    /// <https://oldschool.runescape.wiki/w/Enter_the_Abyss>
+    pub const ENTER_THE_ABYSS: Self = Self::new_static("enter_the_abyss");
+
+    /// <https://oldschool.runescape.wiki/w/Family_Pest>
+    pub const FAMILY_PEST: Self = Self::new_static("family_pest");
+
+    /// <https://oldschool.runescape.wiki/w/The_Frozen_Door>
+    pub const THE_FROZEN_DOOR: Self = Self::new_static("the_frozen_door");
+
+    /// <https://oldschool.runescape.wiki/w/The_General%27s_Shadow>
+    pub const THE_GENERALS_SHADOW: Self = Self::new_static("the_generals_shadow");
+
+    /// <https://oldschool.runescape.wiki/w/His_Faithful_Servants>
+    pub const HIS_FAITHFUL_SERVANTS: Self = Self::new_static("his_faithful_servants");
+
+    /// <https://oldschool.runescape.wiki/w/Hopespear%27s_Will>
+    pub const HOPESPEARS_WILL: Self = Self::new_static("hopespears_will");
+
+    /// <https://oldschool.runescape.wiki/w/In_Search_of_Knowledge>
+    pub const IN_SEARCH_OF_KNOWLEDGE: Self = Self::new_static("in_search_of_knowledge");
+
+    /// <https://oldschool.runescape.wiki/w/Into_the_Tombs>
+    pub const INTO_THE_TOMBS: Self = Self::new_static("into_the_tombs");
+
+    /// <https://oldschool.runescape.wiki/w/Lair_of_Tarn_Razorlor>
+    pub const LAIR_OF_TARN_RAZORLOR: Self = Self::new_static("lair_of_tarn_razorlor");
+
+    /// <https://oldschool.runescape.wiki/w/Mage_Arena_I>
+    pub const MAGE_ARENA_I: Self = Self::new_static("mage_arena_i");
+
+    /// <https://oldschool.runescape.wiki/w/Mage_Arena_II>
+    pub const MAGE_ARENA_II: Self = Self::new_static("mage_arena_ii");
+
+    /// <https://oldschool.runescape.wiki/w/Skippy_and_the_Mogres>
+    pub const SKIPPY_AND_THE_MOGRES: Self = Self::new_static("skippy_and_the_mogres");
+
+    /// <https://oldschool.runescape.wiki/w/Vale_Totems>
+    pub const VALE_TOTEMS: Self = Self::new_static("vale_totems");
+}
+
+#[derive(Debug, Error)]
+#[error("miniquest not found: {0:?}")]
+pub struct MiniquestNotFoundError(pub MiniquestId);
+
+impl MiniquestId {
+    pub fn all() -> &'static [MiniquestId] {
+        &ALL_MINIQUEST_IDS
+    }
+
+    pub fn miniquest(&self) -> Result<Arc<Miniquest>, MiniquestNotFoundError> {
+        MINIQUESTS_BY_ID
+            .get(self)
+            .cloned()
+            .ok_or_else(|| MiniquestNotFoundError(self.clone()))
+    }
+}
+
+impl Miniquest {
+    /// Returns all miniquests.
+    pub fn all() -> impl Iterator<Item = Arc<Miniquest>> {
+        MiniquestId::all()
+            .iter()
+            .map(|id| MINIQUESTS_BY_ID[id].clone())
+    }
+}
+
+static ALL_MINIQUEST_IDS: LazyLock<Vec<MiniquestId>> = LazyLock::new(|| {
+    vec![
+        MiniquestId::ALFRED_GRIMHANDS_BARCRAWL,
+        MiniquestId::ARCHITECTURAL_ALLIANCE,
+        MiniquestId::BARBARIAN_TRAINING,
+        MiniquestId::BEAR_YOUR_SOUL,
+        MiniquestId::CURSE_OF_THE_EMPTY_LORD,
+        MiniquestId::DADDYS_HOME,
+        MiniquestId::THE_ENCHANTED_KEY,
+        MiniquestId::ENTER_THE_ABYSS,
+        MiniquestId::FAMILY_PEST,
+        MiniquestId::THE_FROZEN_DOOR,
+        MiniquestId::THE_GENERALS_SHADOW,
+        MiniquestId::HIS_FAITHFUL_SERVANTS,
+        MiniquestId::HOPESPEARS_WILL,
+        MiniquestId::IN_SEARCH_OF_KNOWLEDGE,
+        MiniquestId::INTO_THE_TOMBS,
+        MiniquestId::LAIR_OF_TARN_RAZORLOR,
+        MiniquestId::MAGE_ARENA_I,
+        MiniquestId::MAGE_ARENA_II,
+        MiniquestId::SKIPPY_AND_THE_MOGRES,
+        MiniquestId::VALE_TOTEMS,
+    ]
+});
+
+static MINIQUESTS_BY_ID: LazyLock<HashMap<MiniquestId, Arc<Miniquest>>> =
+    LazyLock::new(make_miniquests_by_id);
+
+fn make_miniquests_by_id() -> HashMap<MiniquestId, Arc<Miniquest>> {
+    let miniquests = [
+        alfred_grimhands_barcrawl(),
+        architectural_alliance(),
+        barbarian_training(),
+        bear_your_soul(),
+        curse_of_the_empty_lord(),
+        daddys_home(),
+        the_enchanted_key(),
+        enter_the_abyss(),
+        family_pest(),
+        the_frozen_door(),
+        the_generals_shadow(),
+        his_faithful_servants(),
+        hopespears_will(),
+        in_search_of_knowledge(),
+        into_the_tombs(),
+        lair_of_tarn_razorlor(),
+        mage_arena_i(),
+        mage_arena_ii(),
+        skippy_and_the_mogres(),
+        vale_totems(),
+    ];
+
+    miniquests
+        .into_iter()
+        .map(|miniquest| (miniquest.id.clone(), Arc::new(miniquest)))
+        .collect()
+}
+
+fn alfred_grimhands_barcrawl() -> Miniquest {
+    Miniquest {
+        id: MiniquestId::ALFRED_GRIMHANDS_BARCRAWL,
+        name: "Alfred Grimhand's Barcrawl".to_string(),
+        requirements: IndexSet::new(),
+        rewards: vec![unlock_reward(
+            UnlockKind::Area,
+            "Barbarian Outpost Agility Course",
+        )],
+    }
+}
+
+fn architectural_alliance() -> Miniquest {
+    Miniquest {
+        id: MiniquestId::ARCHITECTURAL_ALLIANCE,
+        name: "Architectural Alliance".to_string(),
+        requirements: IndexSet::new(),
+        rewards: vec![unlock_reward(UnlockKind::Feature, "Xeric's Heart teleport")],
+    }
+}
+
+fn barbarian_training() -> Miniquest {
+    Miniquest {
+        id: MiniquestId::BARBARIAN_TRAINING,
+        name: "Barbarian Training".to_string(),
+        requirements: IndexSet::from_iter([
+            quest_req(QuestId::DRUIDIC_RITUAL),
+            quest_req(QuestId::TAI_BWO_WANNAI_TRIO),
+            skill_req(Skill::Fishing, Level(55)),
+            skill_req(Skill::Firemaking, Level(35)),
+            skill_req(Skill::Agility, Level(15)),
+            skill_req(Skill::Strength, Level(35)),
+            skill_req(Skill::Crafting, Level(11)),
+            skill_req(Skill::Herblore, Level(4)),
+            skill_req(Skill::Smithing, Level(5)),
+        ]),
+        rewards: vec![
+            unlock_reward(UnlockKind::Feature, "Barbarian fishing"),
+            unlock_reward(UnlockKind::Feature, "Barbarian firemaking"),
+            unlock_reward(UnlockKind::Feature, "Barbarian mixes"),
+            unlock_reward(UnlockKind::Feature, "Barbarian smithing"),
+        ],
+    }
+}
+
+fn bear_your_soul() -> Miniquest {
+    Miniquest {
+        id: MiniquestId::BEAR_YOUR_SOUL,
+        name: "Bear Your Soul".to_string(),
+        requirements: IndexSet::new(),
+        rewards: vec![item_reward("Soul bearer", 1)],
+    }
+}
+
+fn curse_of_the_empty_lord() -> Miniquest {
+    Miniquest {
+        id: MiniquestId::CURSE_OF_THE_EMPTY_LORD,
+        name: "Curse of the Empty Lord".to_string(),
+        requirements: IndexSet::from_iter([
+            quest_req(QuestId::THE_RESTLESS_GHOST),
+            skill_req_unboostable(Skill::Prayer, Level(31)),
+        ]),
+        rewards: vec![item_reward("Empty lord's robes", 1)],
+    }
+}
+
+fn daddys_home() -> Miniquest {
+    Miniquest {
+        id: MiniquestId::DADDYS_HOME,
+        name: "Daddy's Home".to_string(),
+        requirements: IndexSet::new(),
+        rewards: vec![xp_reward(Skill::Construction, Xp::new(400))],
+    }
+}
+
+fn the_enchanted_key() -> Miniquest {
+    Miniquest {
+        id: MiniquestId::THE_ENCHANTED_KEY,
+        name: "The Enchanted Key".to_string(),
+        requirements: IndexSet::from_iter([quest_req(QuestId::MAKING_HISTORY)]),
+        rewards: Vec::new(),
+    }
+}
+
+fn enter_the_abyss() -> Miniquest {
+    Miniquest {
+        id: MiniquestId::ENTER_THE_ABYSS,
+        name: "Enter the Abyss".to_string(),
+        requirements: IndexSet::from_iter([quest_req(QuestId::RUNE_MYSTERIES)]),
+        rewards: vec![
+            item_reward("Small pouch", 1),
+            unlock_reward(UnlockKind::Area, "The Abyss"),
+        ],
+    }
+}
+
+fn family_pest() -> Miniquest {
+    Miniquest {
+        id: MiniquestId::FAMILY_PEST,
+        name: "Family Pest".to_string(),
+        requirements: IndexSet::from_iter([quest_req(QuestId::FAMILY_CREST)]),
+        rewards: vec![unlock_reward(
+            UnlockKind::Feature,
+            "Swapping steel gauntlet enchantments",
+        )],
+    }
+}
+
+/// The kill count required in each God Wars Dungeon stronghold is not included.
+fn the_frozen_door() -> Miniquest {
+    Miniquest {
+        id: MiniquestId::THE_FROZEN_DOOR,
+        name: "The Frozen Door".to_string(),
+        requirements: IndexSet::from_iter([
+            quest_req(QuestId::DESERT_TREASURE_I),
+            skill_req(Skill::Agility, Level(70)),
+            skill_req(Skill::Strength, Level(70)),
+            skill_req(Skill::Hitpoints, Level(70)),
+            skill_req(Skill::Ranged, Level(70)),
+        ]),
+        rewards: vec![unlock_reward(UnlockKind::Area, "Ancient Prison")],
+    }
+}
+
+fn the_generals_shadow() -> Miniquest {
+    Miniquest {
+        id: MiniquestId::THE_GENERALS_SHADOW,
+        name: "The General's Shadow".to_string(),
+        requirements: IndexSet::from_iter([
+            miniquest_req(MiniquestId::CURSE_OF_THE_EMPTY_LORD),
+            quest_req(QuestId::FIGHT_ARENA),
+        ]),
+        rewards: vec![unlock_reward(UnlockKind::Equipment, "Serenity")],
+    }
+}
+
+fn his_faithful_servants() -> Miniquest {
+    Miniquest {
+        id: MiniquestId::HIS_FAITHFUL_SERVANTS,
+        name: "His Faithful Servants".to_string(),
+        requirements: IndexSet::from_iter([quest_req(QuestId::PRIEST_IN_PERIL)]),
+        rewards: vec![unlock_reward(UnlockKind::Feature, "Barrows")],
+    }
+}
+
+fn hopespears_will() -> Miniquest {
+    Miniquest {
+        id: MiniquestId::HOPESPEARS_WILL,
+        name: "Hopespear's Will".to_string(),
+        requirements: IndexSet::from_iter([
+            quest_req(QuestId::LAND_OF_THE_GOBLINS),
+            quest_req(QuestId::DESERT_TREASURE_I),
+            skill_req_unboostable(Skill::Prayer, Level(50)),
+        ]),
+        rewards: vec![xp_reward(Skill::Prayer, Xp::new(38_750))],
+    }
+}
+
+fn in_search_of_knowledge() -> Miniquest {
+    Miniquest {
+        id: MiniquestId::IN_SEARCH_OF_KNOWLEDGE,
+        name: "In Search of Knowledge".to_string(),
+        requirements: IndexSet::new(),
+        rewards: Vec::new(),
+    }
+}
+
+fn into_the_tombs() -> Miniquest {
+    Miniquest {
+        id: MiniquestId::INTO_THE_TOMBS,
+        name: "Into the Tombs".to_string(),
+        requirements: IndexSet::from_iter([quest_req(QuestId::BENEATH_CURSED_SANDS)]),
+        rewards: vec![unlock_reward(UnlockKind::Area, "Tombs of Amascut")],
+    }
+}
+
+fn lair_of_tarn_razorlor() -> Miniquest {
+    Miniquest {
+        id: MiniquestId::LAIR_OF_TARN_RAZORLOR,
+        name: "Lair of Tarn Razorlor".to_string(),
+        requirements: IndexSet::from_iter([
+            quest_req(QuestId::HAUNTED_MINE),
+            skill_req(Skill::Slayer, Level(40)),
+        ]),
+        rewards: vec![
+            item_reward("Tarn's diary", 1),
+            unlock_reward(UnlockKind::Feature, "Salve amulet enchantment"),
+        ],
+    }
+}
+
+fn mage_arena_i() -> Miniquest {
+    Miniquest {
+        id: MiniquestId::MAGE_ARENA_I,
+        name: "Mage Arena I".to_string(),
+        requirements: IndexSet::from_iter([skill_req(Skill::Magic, Level(60))]),
+        rewards: vec![QuestReward::Choice(vec![
+            vec![
+                item_reward("Saradomin cape", 1),
+                unlock_reward(UnlockKind::Spell, "Saradomin Strike"),
+            ],
+            vec![
+                item_reward("Guthix cape", 1),
+                unlock_reward(UnlockKind::Spell, "Claws of Guthix"),
+            ],
+            vec![
+                item_reward("Zamorak cape", 1),
+                unlock_reward(UnlockKind::Spell, "Flames of Zamorak"),
+            ],
+        ])],
+    }
+}
+
+fn mage_arena_ii() -> Miniquest {
+    Miniquest {
+        id: MiniquestId::MAGE_ARENA_II,
+        name: "Mage Arena II".to_string(),
+        requirements: IndexSet::from_iter([
+            miniquest_req(MiniquestId::MAGE_ARENA_I),
+            skill_req(Skill::Magic, Level(75)),
+        ]),
+        rewards: vec![QuestReward::Choice(vec![
+            vec![item_reward("Imbued saradomin cape", 1)],
+            vec![item_reward("Imbued guthix cape", 1)],
+            vec![item_reward("Imbued zamorak cape", 1)],
+        ])],
+    }
+}
+
+fn skippy_and_the_mogres() -> Miniquest {
+    Miniquest {
+        id: MiniquestId::SKIPPY_AND_THE_MOGRES,
+        name: "Skippy and the Mogres".to_string(),
+        requirements: IndexSet::from_iter([skill_req(Skill::Cooking, Level(20))]),
+        rewards: vec![unlock_reward(UnlockKind::Feature, "Mogres")],
+    }
+}
+
+fn vale_totems() -> Miniquest {
+    Miniquest {
+        id: MiniquestId::VALE_TOTEMS,
+        name: "Vale Totems".to_string(),
+        requirements: IndexSet::from_iter([skill_req(Skill::Fletching, Level(20))]),
+        rewards: vec![unlock_reward(UnlockKind::Feature, "Vale Totems")],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::{PlayerState, QuestRequirement, UnmetRequirement};
+
+    use super::*;
+
+    #[test]
+    fn test_all_miniquests_are_defined() {
+        for id in MiniquestId::all() {
+            assert_eq!(&id.miniquest().unwrap().id, id);
+        }
+        assert_eq!(Miniquest::all().count(), MiniquestId::all().len());
+        assert_eq!(MiniquestId::all().len(), 20);
+
+        let error = MiniquestId::new_static("non_existent_miniquest")
+            .miniquest()
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            r#"miniquest not found: MiniquestId("non_existent_miniquest")"#
+        );
+    }
+
+    #[test]
+    fn test_miniquest_check() {
+        let mut player = PlayerState::new();
+        let mage_arena_ii = MiniquestId::MAGE_ARENA_II.miniquest().unwrap();
+
+        assert_eq!(
+            mage_arena_ii.check(&player).unmet,
+            vec![
+                UnmetRequirement::Miniquest(MiniquestId::MAGE_ARENA_I),
+                UnmetRequirement::Skill {
+                    skill: Skill::Magic,
+                    required: Level(75),
+                    current: Level(1),
+                },
+            ]
+        );
+
+        player.set_level(Skill::Magic, Level(75));
+        player.complete_miniquest(&MiniquestId::MAGE_ARENA_I.miniquest().unwrap());
+        assert!(mage_arena_ii.check(&player).can_start());
+    }
+
+    #[test]
+    fn test_resolve_miniquest_requirements() {
+        let resolved = QuestId::SECRETS_OF_THE_NORTH
+            .resolve_requirements()
+            .unwrap();
+
+        assert!(resolved.quests.contains(&QuestId::FIGHT_ARENA));
+        assert!(resolved.quests.contains(&QuestId::THE_RESTLESS_GHOST));
+        assert!(resolved.other.contains(&QuestRequirement::Miniquest(
+            MiniquestId::CURSE_OF_THE_EMPTY_LORD
+        )));
+    }
+}
@@ -2,9 +2,13 @@ use std::collections::HashMap;
 use std::sync::{Arc, LazyLock};
 
 use indexmap::IndexSet;
+use strum::IntoEnumIterator;
 use thiserror::Error;
 
-use crate::{Level, Quest, QuestId, QuestRequirement, QuestReward, Skill, Xp};
+use crate::{
+    LampXp, Level, MiniquestId, Quest, QuestDifficulty, QuestId, QuestLength, QuestRequirement,
+    QuestReward, QuestSeries, ReleaseDate, Skill, Unlock, UnlockKind, Xp, XpLamp,
+};
 
 impl QuestId {
     // Free-to-play quests
@@ -598,6 +602,38 @@ impl QuestId {
     }
 }
 
+impl Quest {
+    /// Returns all quests.
+    pub fn all() -> impl Iterator<Item = Arc<Quest>> {
+        QuestId::all().iter().map(|id| QUESTS_BY_ID[id].clone())
+    }
+
+    /// Returns all free-to-play quests.
+    pub fn free_to_play() -> impl Iterator<Item = Arc<Quest>> {
+        Self::all().filter(|quest| !quest.members)
+    }
+
+    /// Returns all members quests.
+    pub fn members_only() -> impl Iterator<Item = Arc<Quest>> {
+        Self::all().filter(|quest| quest.members)
+    }
+
+    /// Returns all quests with the given difficulty.
+    pub fn with_difficulty(difficulty: QuestDifficulty) -> impl Iterator<Item = Arc<Quest>> {
+        Self::all().filter(move |quest| quest.difficulty == difficulty)
+    }
+
+    /// Returns all quests in the given series, in the order they were released.
+    pub fn in_series(series: QuestSeries) -> Vec<Arc<Quest>> {
+        let mut quests = Self::all()
+            .filter(|quest| quest.series == Some(series))
+            .collect::<Vec<_>>();
+        quests.sort_by_key(|quest| quest.release_date);
+
+        quests
+    }
+}
+
 static ALL_QUEST_IDS: LazyLock<Vec<QuestId>> = LazyLock::new(|| {
     vec![
         QuestId::BELOW_ICE_MOUNTAIN,
@@ -791,22 +827,107 @@ static ALL_QUEST_IDS: LazyLock<Vec<QuestId>> = LazyLock::new(|| {
 
 static QUESTS_BY_ID: LazyLock<HashMap<QuestId, Arc<Quest>>> = LazyLock::new(make_quests_by_id);
 
-fn quest_req(id: QuestId) -> QuestRequirement {
+pub(super) fn quest_req(id: QuestId) -> QuestRequirement {
     QuestRequirement::Quest(id)
 }
 
-fn skill_req(skill: Skill, level: Level) -> QuestRequirement {
-    QuestRequirement::Skill { skill, level }
+pub(super) fn skill_req(skill: Skill, level: Level) -> QuestRequirement {
+    QuestRequirement::Skill {
+        skill,
+        level,
+        boostable: true,
+    }
+}
+
+/// A skill requirement that must be met without a temporary boost.
+///
+/// Prayer cannot be boosted, so Prayer requirements are always unboostable.
+pub(super) fn skill_req_unboostable(skill: Skill, level: Level) -> QuestRequirement {
+    QuestRequirement::Skill {
+        skill,
+        level,
+        boostable: false,
+    }
 }
 
-fn quest_point_req(quest_points: u16) -> QuestRequirement {
+pub(super) fn quest_point_req(quest_points: u16) -> QuestRequirement {
     QuestRequirement::QuestPoints(quest_points)
 }
 
-fn xp_reward(skill: Skill, xp: Xp) -> QuestReward {
+pub(super) fn miniquest_req(id: MiniquestId) -> QuestRequirement {
+    QuestRequirement::Miniquest(id)
+}
+
+pub(super) fn combat_level_req(level: Level) -> QuestRequirement {
+    QuestRequirement::CombatLevel(level)
+}
+
+pub(super) fn kudos_req(kudos: u16) -> QuestRequirement {
+    QuestRequirement::Kudos(kudos)
+}
+
+pub(super) fn item_req(name: &str, quantity: u32) -> QuestRequirement {
+    QuestRequirement::Item {
+        name: name.to_string(),
+        quantity,
+    }
+}
+
+/// Entry to Zanaris, which requires Lost City and a Dramen or Lunar staff.
+fn zanaris_req() -> QuestRequirement {
+    QuestRequirement::All(vec![
+        quest_req(QuestId::LOST_CITY),
+        QuestRequirement::Any(vec![
+            item_req("Dramen staff", 1),
+            item_req("Lunar staff", 1),
+        ]),
+    ])
+}
+
+pub(super) fn xp_reward(skill: Skill, xp: Xp) -> QuestReward {
     QuestReward::Xp { skill, xp }
 }
 
+fn lamp_reward(
+    xp: Xp,
+    skills: impl IntoIterator<Item = Skill>,
+    min_level: Option<Level>,
+    count: u8,
+) -> QuestReward {
+    QuestReward::Lamp {
+        lamp: XpLamp {
+            xp: LampXp::Fixed(xp),
+            skills: skills.into_iter().collect(),
+            min_level,
+        },
+        count,
+    }
+}
+
+pub(super) fn item_reward(name: &str, quantity: u32) -> QuestReward {
+    QuestReward::Item {
+        name: name.to_string(),
+        quantity,
+    }
+}
+
+pub(super) fn unlock_reward(kind: UnlockKind, name: &str) -> QuestReward {
+    QuestReward::Unlock(Unlock {
+        kind,
+        name: name.to_string(),
+    })
+}
+
+/// The skills that lamps for "any combat skill" can be used on.
+const COMBAT_SKILLS_EXCEPT_PRAYER: [Skill; 6] = [
+    Skill::Attack,
+    Skill::Strength,
+    Skill::Defence,
+    Skill::Hitpoints,
+    Skill::Ranged,
+    Skill::Magic,
+];
+
 fn make_quests_by_id() -> HashMap<QuestId, Arc<Quest>> {
     let mut quests = HashMap::new();
 
@@ -1017,6 +1138,11 @@ fn below_ice_mountain() -> Quest {
         id: QuestId::BELOW_ICE_MOUNTAIN,
         name: "Below Ice Mountain".to_string(),
         quest_points: 1,
+        difficulty: QuestDifficulty::Novice,
+        length: QuestLength::Short,
+        members: false,
+        series: None,
+        release_date: ReleaseDate::new(2021, 2, 10),
         requirements: IndexSet::from_iter([quest_point_req(16)]),
         rewards: Vec::new(),
     }
@@ -1028,6 +1154,11 @@ fn black_knights_fortress() -> Quest {
         id: QuestId::BLACK_KNIGHTS_FORTRESS,
         name: "Black Knights' Fortress".to_string(),
         quest_points: 3,
+        difficulty: QuestDifficulty::Novice,
+        length: QuestLength::Short,
+        members: false,
+        series: None,
+        release_date: ReleaseDate::new(2001, 1, 4),
         requirements: IndexSet::from_iter([quest_point_req(12)]),
         rewards: Vec::new(),
     }
@@ -1039,8 +1170,13 @@ fn cooks_assistant() -> Quest {
         id: QuestId::COOKS_ASSISTANT,
         name: "Cook's Assistant".to_string(),
         quest_points: 1,
+        difficulty: QuestDifficulty::Novice,
+        length: QuestLength::Short,
+        members: false,
+        series: None,
+        release_date: ReleaseDate::new(2001, 1, 4),
         requirements: IndexSet::new(),
-        rewards: vec![xp_reward(Skill::Cooking, Xp(300.))],
+        rewards: vec![xp_reward(Skill::Cooking, Xp::new(300))],
     }
 }
 
@@ -1050,6 +1186,11 @@ fn the_corsair_curse() -> Quest {
         id: QuestId::THE_CORSAIR_CURSE,
         name: "The Corsair Curse".to_string(),
         quest_points: 2,
+        difficulty: QuestDifficulty::Intermediate,
+        length: QuestLength::Medium,
+        members: false,
+        series: None,
+        release_date: ReleaseDate::new(2018, 3, 8),
         requirements: IndexSet::new(),
         rewards: Vec::new(),
     }
@@ -1061,6 +1202,11 @@ fn demon_slayer() -> Quest {
         id: QuestId::DEMON_SLAYER,
         name: "Demon Slayer".to_string(),
         quest_points: 3,
+        difficulty: QuestDifficulty::Novice,
+        length: QuestLength::Medium,
+        members: false,
+        series: None,
+        release_date: ReleaseDate::new(2001, 1, 4),
         requirements: IndexSet::new(),
         rewards: Vec::new(),
     }
@@ -1072,8 +1218,13 @@ fn dorics_quest() -> Quest {
         id: QuestId::DORICS_QUEST,
         name: "Doric's Quest".to_string(),
         quest_points: 1,
+        difficulty: QuestDifficulty::Novice,
+        length: QuestLength::VeryShort,
+        members: false,
+        series: None,
+        release_date: ReleaseDate::new(2001, 1, 4),
         requirements: IndexSet::new(),
-        rewards: vec![xp_reward(Skill::Mining, Xp(1_300.))],
+        rewards: vec![xp_reward(Skill::Mining, Xp::new(1_300))],
     }
 }
 
@@ -1083,10 +1234,17 @@ fn dragon_slayer_i() -> Quest {
         id: QuestId::DRAGON_SLAYER_I,
         name: "Dragon Slayer I".to_string(),
         quest_points: 2,
+        difficulty: QuestDifficulty::Experienced,
+        length: QuestLength::Long,
+        members: false,
+        series: Some(QuestSeries::Dragonkin),
+        release_date: ReleaseDate::new(2001, 1, 4),
         requirements: IndexSet::from_iter([quest_point_req(32)]),
         rewards: vec![
-            xp_reward(Skill::Strength, Xp(18_650.)),
-            xp_reward(Skill::Defence, Xp(18_650.)),
+            xp_reward(Skill::Strength, Xp::new(18_650)),
+            xp_reward(Skill::Defence, Xp::new(18_650)),
+            unlock_reward(UnlockKind::Equipment, "Rune platebody"),
+            unlock_reward(UnlockKind::Equipment, "Green d'hide body"),
         ],
     }
 }
@@ -1097,6 +1255,11 @@ fn ernest_the_chicken() -> Quest {
         id: QuestId::ERNEST_THE_CHICKEN,
         name: "Ernest the Chicken".to_string(),
         quest_points: 4,
+        difficulty: QuestDifficulty::Novice,
+        length: QuestLength::Short,
+        members: false,
+        series: None,
+        release_date: ReleaseDate::new(2001, 1, 4),
         requirements: IndexSet::new(),
         rewards: Vec::new(),
     }
@@ -1108,8 +1271,13 @@ fn goblin_diplomacy() -> Quest {
         id: QuestId::GOBLIN_DIPLOMACY,
         name: "Goblin Diplomacy".to_string(),
         quest_points: 5,
+        difficulty: QuestDifficulty::Novice,
+        length: QuestLength::Short,
+        members: false,
+        series: None,
+        release_date: ReleaseDate::new(2001, 1, 4),
         requirements: IndexSet::new(),
-        rewards: vec![xp_reward(Skill::Crafting, Xp(200.))],
+        rewards: vec![xp_reward(Skill::Crafting, Xp::new(200))],
     }
 }
 
@@ -1119,8 +1287,13 @@ fn imp_catcher() -> Quest {
         id: QuestId::IMP_CATCHER,
         name: "Imp Catcher".to_string(),
         quest_points: 1,
+        difficulty: QuestDifficulty::Novice,
+        length: QuestLength::Short,
+        members: false,
+        series: None,
+        release_date: ReleaseDate::new(2001, 1, 4),
         requirements: IndexSet::new(),
-        rewards: vec![xp_reward(Skill::Magic, Xp(875.))],
+        rewards: vec![xp_reward(Skill::Magic, Xp::new(875))],
     }
 }
 
@@ -1130,8 +1303,13 @@ fn the_knights_sword() -> Quest {
         id: QuestId::THE_KNIGHTS_SWORD,
         name: "The Knight's Sword".to_string(),
         quest_points: 1,
+        difficulty: QuestDifficulty::Intermediate,
+        length: QuestLength::Medium,
+        members: false,
+        series: None,
+        release_date: ReleaseDate::new(2001, 1, 4),
         requirements: IndexSet::from_iter([skill_req(Skill::Mining, Level(10))]),
-        rewards: vec![xp_reward(Skill::Smithing, Xp(12_725.))],
+        rewards: vec![xp_reward(Skill::Smithing, Xp::new(12_725))],
     }
 }
 
@@ -1141,8 +1319,13 @@ fn misthalin_mystery() -> Quest {
         id: QuestId::MISTHALIN_MYSTERY,
         name: "Misthalin Mystery".to_string(),
         quest_points: 1,
+        difficulty: QuestDifficulty::Novice,
+        length: QuestLength::Short,
+        members: false,
+        series: None,
+        release_date: ReleaseDate::new(2015, 5, 21),
         requirements: IndexSet::new(),
-        rewards: vec![xp_reward(Skill::Crafting, Xp(600.))],
+        rewards: vec![xp_reward(Skill::Crafting, Xp::new(600))],
     }
 }
 
@@ -1152,6 +1335,11 @@ fn pirates_treasure() -> Quest {
         id: QuestId::PIRATES_TREASURE,
         name: "Pirate's Treasure".to_string(),
         quest_points: 2,
+        difficulty: QuestDifficulty::Novice,
+        length: QuestLength::Short,
+        members: false,
+        series: Some(QuestSeries::Pirate),
+        release_date: ReleaseDate::new(2001, 1, 4),
         requirements: IndexSet::new(),
         rewards: Vec::new(),
     }
@@ -1163,6 +1351,11 @@ fn prince_ali_rescue() -> Quest {
         id: QuestId::PRINCE_ALI_RESCUE,
         name: "Prince Ali Rescue".to_string(),
         quest_points: 3,
+        difficulty: QuestDifficulty::Novice,
+        length: QuestLength::Medium,
+        members: false,
+        series: None,
+        release_date: ReleaseDate::new(2001, 1, 4),
         requirements: IndexSet::new(),
         rewards: Vec::new(),
     }
@@ -1174,8 +1367,13 @@ fn the_restless_ghost() -> Quest {
         id: QuestId::THE_RESTLESS_GHOST,
         name: "The Restless Ghost".to_string(),
         quest_points: 1,
+        difficulty: QuestDifficulty::Novice,
+        length: QuestLength::Short,
+        members: false,
+        series: None,
+        release_date: ReleaseDate::new(2001, 1, 4),
         requirements: IndexSet::new(),
-        rewards: vec![xp_reward(Skill::Prayer, Xp(1_125.))],
+        rewards: vec![xp_reward(Skill::Prayer, Xp::new(1_125))],
     }
 }
 
@@ -1185,6 +1383,11 @@ fn romeo_and_juliet() -> Quest {
         id: QuestId::ROMEO_AND_JULIET,
         name: "Romeo & Juliet".to_string(),
         quest_points: 5,
+        difficulty: QuestDifficulty::Novice,
+        length: QuestLength::Short,
+        members: false,
+        series: None,
+        release_date: ReleaseDate::new(2001, 1, 4),
         requirements: IndexSet::new(),
         rewards: Vec::new(),
     }
@@ -1196,8 +1399,16 @@ fn rune_mysteries() -> Quest {
         id: QuestId::RUNE_MYSTERIES,
         name: "Rune Mysteries".to_string(),
         quest_points: 1,
+        difficulty: QuestDifficulty::Novice,
+        length: QuestLength::Short,
+        members: false,
+        series: None,
+        release_date: ReleaseDate::new(2004, 3, 29),
         requirements: IndexSet::new(),
-        rewards: Vec::new(),
+        rewards: vec![
+            item_reward("Air talisman", 1),
+            unlock_reward(UnlockKind::Skill, "Runecraft"),
+        ],
     }
 }
 
@@ -1207,8 +1418,13 @@ fn sheep_shearer() -> Quest {
         id: QuestId::SHEEP_SHEARER,
         name: "Sheep Shearer".to_string(),
         quest_points: 1,
+        difficulty: QuestDifficulty::Novice,
+        length: QuestLength::Short,
+        members: false,
+        series: None,
+        release_date: ReleaseDate::new(2001, 1, 4),
         requirements: IndexSet::new(),
-        rewards: vec![xp_reward(Skill::Crafting, Xp(150.))],
+        rewards: vec![xp_reward(Skill::Crafting, Xp::new(150))],
     }
 }
 
@@ -1218,6 +1434,11 @@ fn shield_of_arrav() -> Quest {
         id: QuestId::SHIELD_OF_ARRAV,
         name: "Shield of Arrav".to_string(),
         quest_points: 1,
+        difficulty: QuestDifficulty::Novice,
+        length: QuestLength::Short,
+        members: false,
+        series: None,
+        release_date: ReleaseDate::new(2001, 1, 4),
         requirements: IndexSet::new(),
         rewards: Vec::new(),
     }
@@ -1229,8 +1450,13 @@ fn vampyre_slayer() -> Quest {
         id: QuestId::VAMPYRE_SLAYER,
         name: "Vampyre Slayer".to_string(),
         quest_points: 3,
+        difficulty: QuestDifficulty::Novice,
+        length: QuestLength::Short,
+        members: false,
+        series: None,
+        release_date: ReleaseDate::new(2001, 1, 4),
         requirements: IndexSet::new(),
-        rewards: vec![xp_reward(Skill::Attack, Xp(4_825.))],
+        rewards: vec![xp_reward(Skill::Attack, Xp::new(4_825))],
     }
 }
 
@@ -1240,8 +1466,13 @@ fn witchs_potion() -> Quest {
         id: QuestId::WITCHS_POTION,
         name: "Witch's Potion".to_string(),
         quest_points: 1,
+        difficulty: QuestDifficulty::Novice,
+        length: QuestLength::Short,
+        members: false,
+        series: None,
+        release_date: ReleaseDate::new(2001, 1, 4),
         requirements: IndexSet::new(),
-        rewards: vec![xp_reward(Skill::Magic, Xp(325.))],
+        rewards: vec![xp_reward(Skill::Magic, Xp::new(325))],
     }
 }
 
@@ -1251,10 +1482,13 @@ fn x_marks_the_spot() -> Quest {
         id: QuestId::X_MARKS_THE_SPOT,
         name: "X Marks the Spot".to_string(),
         quest_points: 1,
+        difficulty: QuestDifficulty::Novice,
+        length: QuestLength::VeryShort,
+        members: false,
+        series: None,
+        release_date: ReleaseDate::new(2018, 9, 13),
         requirements: IndexSet::new(),
-        rewards: vec![
-            // TODO: Figure out how to model 300 XP lamp.
-        ],
+        rewards: vec![lamp_reward(Xp::new(300), Skill::iter(), None, 1)],
     }
 }
 
@@ -1264,6 +1498,11 @@ fn animal_magnetism() -> Quest {
         id: QuestId::ANIMAL_MAGNETISM,
         name: "Animal Magnetism".to_string(),
         quest_points: 1,
+        difficulty: QuestDifficulty::Intermediate,
+        length: QuestLength::Medium,
+        members: true,
+        series: None,
+        release_date: ReleaseDate::new(2005, 4, 11),
         requirements: IndexSet::from_iter([
             quest_req(QuestId::THE_RESTLESS_GHOST),
             quest_req(QuestId::ERNEST_THE_CHICKEN),
@@ -1274,10 +1513,11 @@ fn animal_magnetism() -> Quest {
             skill_req(Skill::Woodcutting, Level(35)),
         ]),
         rewards: vec![
-            xp_reward(Skill::Crafting, Xp(1_000.)),
-            xp_reward(Skill::Fletching, Xp(1_000.)),
-            xp_reward(Skill::Slayer, Xp(1_000.)),
-            xp_reward(Skill::Woodcutting, Xp(2_500.)),
+            xp_reward(Skill::Crafting, Xp::new(1_000)),
+            xp_reward(Skill::Fletching, Xp::new(1_000)),
+            xp_reward(Skill::Slayer, Xp::new(1_000)),
+            xp_reward(Skill::Woodcutting, Xp::new(2_500)),
+            unlock_reward(UnlockKind::Equipment, "Ava's attractor"),
         ],
     }
 }
@@ -1288,16 +1528,21 @@ fn another_slice_of_ham() -> Quest {
         id: QuestId::ANOTHER_SLICE_OF_HAM,
         name: "Another Slice of H.A.M.".to_string(),
         quest_points: 1,
+        difficulty: QuestDifficulty::Intermediate,
+        length: QuestLength::Medium,
+        members: true,
+        series: Some(QuestSeries::Dorgeshuun),
+        release_date: ReleaseDate::new(2007, 3, 13),
         requirements: IndexSet::from_iter([
             quest_req(QuestId::DEATH_TO_THE_DORGESHUUN),
             quest_req(QuestId::THE_GIANT_DWARF),
             quest_req(QuestId::THE_DIG_SITE),
             skill_req(Skill::Attack, Level(15)),
-            skill_req(Skill::Prayer, Level(25)),
+            skill_req_unboostable(Skill::Prayer, Level(25)),
         ]),
         rewards: vec![
-            xp_reward(Skill::Mining, Xp(3_000.)),
-            xp_reward(Skill::Prayer, Xp(3_000.)),
+            xp_reward(Skill::Mining, Xp::new(3_000)),
+            xp_reward(Skill::Prayer, Xp::new(3_000)),
         ],
     }
 }
@@ -1308,13 +1553,18 @@ fn the_ascent_of_arceuus() -> Quest {
         id: QuestId::THE_ASCENT_OF_ARCEUUS,
         name: "The Ascent of Arceuus".to_string(),
         quest_points: 1,
+        difficulty: QuestDifficulty::Intermediate,
+        length: QuestLength::Short,
+        members: true,
+        series: Some(QuestSeries::GreatKourend),
+        release_date: ReleaseDate::new(2019, 5, 16),
         requirements: IndexSet::from_iter([
             quest_req(QuestId::CLIENT_OF_KOUREND),
             skill_req(Skill::Hunter, Level(12)),
         ]),
         rewards: vec![
-            xp_reward(Skill::Hunter, Xp(1_500.)),
-            xp_reward(Skill::Runecraft, Xp(500.)),
+            xp_reward(Skill::Hunter, Xp::new(1_500)),
+            xp_reward(Skill::Runecraft, Xp::new(500)),
         ],
     }
 }
@@ -1325,6 +1575,11 @@ fn at_first_light() -> Quest {
         id: QuestId::AT_FIRST_LIGHT,
         name: "At First Light".to_string(),
         quest_points: 1,
+        difficulty: QuestDifficulty::Intermediate,
+        length: QuestLength::Short,
+        members: true,
+        series: None,
+        release_date: ReleaseDate::new(2024, 3, 20),
         requirements: IndexSet::from_iter([
             quest_req(QuestId::CHILDREN_OF_THE_SUN),
             quest_req(QuestId::EAGLES_PEAK),
@@ -1333,9 +1588,9 @@ fn at_first_light() -> Quest {
             skill_req(Skill::Construction, Level(27)),
         ]),
         rewards: vec![
-            xp_reward(Skill::Hunter, Xp(4_500.)),
-            xp_reward(Skill::Construction, Xp(800.)),
-            xp_reward(Skill::Herblore, Xp(500.)),
+            xp_reward(Skill::Hunter, Xp::new(4_500)),
+            xp_reward(Skill::Construction, Xp::new(800)),
+            xp_reward(Skill::Herblore, Xp::new(500)),
         ],
     }
 }
@@ -1346,13 +1601,18 @@ fn beneath_cursed_sands() -> Quest {
         id: QuestId::BENEATH_CURSED_SANDS,
         name: "Beneath Cursed Sands".to_string(),
         quest_points: 2,
+        difficulty: QuestDifficulty::Master,
+        length: QuestLength::Medium,
+        members: true,
+        series: Some(QuestSeries::Desert),
+        release_date: ReleaseDate::new(2022, 4, 27),
         requirements: IndexSet::from_iter([
             quest_req(QuestId::CONTACT),
             skill_req(Skill::Agility, Level(62)),
             skill_req(Skill::Crafting, Level(55)),
             skill_req(Skill::Firemaking, Level(55)),
         ]),
-        rewards: vec![xp_reward(Skill::Agility, Xp(50_000.))],
+        rewards: vec![xp_reward(Skill::Agility, Xp::new(50_000))],
     }
 }
 
@@ -1362,6 +1622,11 @@ fn between_a_rock() -> Quest {
         id: QuestId::BETWEEN_A_ROCK,
         name: "Between a Rock...".to_string(),
         quest_points: 2,
+        difficulty: QuestDifficulty::Experienced,
+        length: QuestLength::Medium,
+        members: true,
+        series: Some(QuestSeries::RedAxe),
+        release_date: ReleaseDate::new(2005, 11, 8),
         requirements: IndexSet::from_iter([
             quest_req(QuestId::DWARF_CANNON),
             quest_req(QuestId::FISHING_CONTEST),
@@ -1370,9 +1635,9 @@ fn between_a_rock() -> Quest {
             skill_req(Skill::Smithing, Level(50)),
         ]),
         rewards: vec![
-            xp_reward(Skill::Defence, Xp(5_000.)),
-            xp_reward(Skill::Mining, Xp(5_000.)),
-            xp_reward(Skill::Smithing, Xp(5_000.)),
+            xp_reward(Skill::Defence, Xp::new(5_000)),
+            xp_reward(Skill::Mining, Xp::new(5_000)),
+            xp_reward(Skill::Smithing, Xp::new(5_000)),
         ],
     }
 }
@@ -1383,15 +1648,20 @@ fn big_chompy_bird_hunting() -> Quest {
         id: QuestId::BIG_CHOMPY_BIRD_HUNTING,
         name: "Big Chompy Bird Hunting".to_string(),
         quest_points: 2,
+        difficulty: QuestDifficulty::Intermediate,
+        length: QuestLength::Medium,
+        members: true,
+        series: None,
+        release_date: ReleaseDate::new(2004, 8, 10),
         requirements: IndexSet::from_iter([
             skill_req(Skill::Fletching, Level(5)),
             skill_req(Skill::Cooking, Level(30)),
             skill_req(Skill::Ranged, Level(30)),
         ]),
         rewards: vec![
-            xp_reward(Skill::Fletching, Xp(262.)),
-            xp_reward(Skill::Cooking, Xp(1_470.)),
-            xp_reward(Skill::Ranged, Xp(735.)),
+            xp_reward(Skill::Fletching, Xp::new(262)),
+            xp_reward(Skill::Cooking, Xp::new(1_470)),
+            xp_reward(Skill::Ranged, Xp::new(735)),
         ],
     }
 }
@@ -1402,8 +1672,13 @@ fn biohazard() -> Quest {
         id: QuestId::BIOHAZARD,
         name: "Biohazard".to_string(),
         quest_points: 3,
+        difficulty: QuestDifficulty::Novice,
+        length: QuestLength::Medium,
+        members: true,
+        series: Some(QuestSeries::Elf),
+        release_date: ReleaseDate::new(2002, 5, 21),
         requirements: IndexSet::from_iter([quest_req(QuestId::PLAGUE_CITY)]),
-        rewards: vec![xp_reward(Skill::Thieving, Xp(1_250.))],
+        rewards: vec![xp_reward(Skill::Thieving, Xp::new(1_250))],
     }
 }
 
@@ -1413,10 +1688,12 @@ fn bone_voyage() -> Quest {
         id: QuestId::BONE_VOYAGE,
         name: "Bone Voyage".to_string(),
         quest_points: 1,
-        requirements: IndexSet::from_iter([
-            // TODO: Figure out how to model kudos requirement.
-            quest_req(QuestId::THE_DIG_SITE),
-        ]),
+        difficulty: QuestDifficulty::Intermediate,
+        length: QuestLength::Medium,
+        members: true,
+        series: None,
+        release_date: ReleaseDate::new(2016, 4, 21),
+        requirements: IndexSet::from_iter([quest_req(QuestId::THE_DIG_SITE), kudos_req(100)]),
         rewards: Vec::new(),
     }
 }
@@ -1427,6 +1704,11 @@ fn cabin_fever() -> Quest {
         id: QuestId::CABIN_FEVER,
         name: "Cabin Fever".to_string(),
         quest_points: 2,
+        difficulty: QuestDifficulty::Experienced,
+        length: QuestLength::Medium,
+        members: true,
+        series: Some(QuestSeries::Pirate),
+        release_date: ReleaseDate::new(2005, 9, 26),
         requirements: IndexSet::from_iter([
             quest_req(QuestId::PIRATES_TREASURE),
             quest_req(QuestId::RUM_DEAL),
@@ -1436,9 +1718,9 @@ fn cabin_fever() -> Quest {
             skill_req(Skill::Ranged, Level(40)),
         ]),
         rewards: vec![
-            xp_reward(Skill::Crafting, Xp(7_000.)),
-            xp_reward(Skill::Smithing, Xp(7_000.)),
-            xp_reward(Skill::Agility, Xp(7_000.)),
+            xp_reward(Skill::Crafting, Xp::new(7_000)),
+            xp_reward(Skill::Smithing, Xp::new(7_000)),
+            xp_reward(Skill::Agility, Xp::new(7_000)),
         ],
     }
 }
@@ -1449,6 +1731,11 @@ fn children_of_the_sun() -> Quest {
         id: QuestId::CHILDREN_OF_THE_SUN,
         name: "Children of the Sun".to_string(),
         quest_points: 1,
+        difficulty: QuestDifficulty::Novice,
+        length: QuestLength::Short,
+        members: true,
+        series: Some(QuestSeries::Varlamore),
+        release_date: ReleaseDate::new(2023, 11, 15),
         requirements: IndexSet::new(),
         rewards: Vec::new(),
     }
@@ -1460,10 +1747,13 @@ fn client_of_kourend() -> Quest {
         id: QuestId::CLIENT_OF_KOUREND,
         name: "Client of Kourend".to_string(),
         quest_points: 1,
+        difficulty: QuestDifficulty::Novice,
+        length: QuestLength::Short,
+        members: true,
+        series: Some(QuestSeries::GreatKourend),
+        release_date: ReleaseDate::new(2016, 1, 28),
         requirements: IndexSet::from_iter([quest_req(QuestId::X_MARKS_THE_SPOT)]),
-        rewards: vec![
-            // TODO: Figure out how to model 2x 500 XP lamps.
-        ],
+        rewards: vec![lamp_reward(Xp::new(500), Skill::iter(), None, 2)],
     }
 }
 
@@ -1473,6 +1763,11 @@ fn clock_tower() -> Quest {
         id: QuestId::CLOCK_TOWER,
         name: "Clock Tower".to_string(),
         quest_points: 1,
+        difficulty: QuestDifficulty::Novice,
+        length: QuestLength::Short,
+        members: true,
+        series: None,
+        release_date: ReleaseDate::new(2002, 1, 21),
         requirements: IndexSet::new(),
         rewards: Vec::new(),
     }
@@ -1484,6 +1779,11 @@ fn cold_war() -> Quest {
         id: QuestId::COLD_WAR,
         name: "Cold War".to_string(),
         quest_points: 1,
+        difficulty: QuestDifficulty::Intermediate,
+        length: QuestLength::Medium,
+        members: true,
+        series: None,
+        release_date: ReleaseDate::new(2006, 2, 15),
         requirements: IndexSet::from_iter([
             skill_req(Skill::Hunter, Level(10)),
             skill_req(Skill::Agility, Level(30)),
@@ -1492,9 +1792,9 @@ fn cold_war() -> Quest {
             skill_req(Skill::Thieving, Level(15)),
         ]),
         rewards: vec![
-            xp_reward(Skill::Agility, Xp(5_000.)),
-            xp_reward(Skill::Crafting, Xp(2_000.)),
-            xp_reward(Skill::Construction, Xp(1_500.)),
+            xp_reward(Skill::Agility, Xp::new(5_000)),
+            xp_reward(Skill::Crafting, Xp::new(2_000)),
+            xp_reward(Skill::Construction, Xp::new(1_500)),
         ],
     }
 }
@@ -1505,11 +1805,16 @@ fn contact() -> Quest {
         id: QuestId::CONTACT,
         name: "Contact!".to_string(),
         quest_points: 1,
+        difficulty: QuestDifficulty::Master,
+        length: QuestLength::Medium,
+        members: true,
+        series: Some(QuestSeries::Desert),
+        release_date: ReleaseDate::new(2006, 3, 6),
         requirements: IndexSet::from_iter([
             quest_req(QuestId::PRINCE_ALI_RESCUE),
             quest_req(QuestId::ICTHLARINS_LITTLE_HELPER),
         ]),
-        rewards: vec![xp_reward(Skill::Thieving, Xp(7_000.))],
+        rewards: vec![xp_reward(Skill::Thieving, Xp::new(7_000))],
     }
 }
 
@@ -1519,13 +1824,18 @@ fn creature_of_fenkenstrain() -> Quest {
         id: QuestId::CREATURE_OF_FENKENSTRAIN,
         name: "Creature of Fenkenstrain".to_string(),
         quest_points: 2,
+        difficulty: QuestDifficulty::Intermediate,
+        length: QuestLength::Medium,
+        members: true,
+        series: None,
+        release_date: ReleaseDate::new(2004, 12, 14),
         requirements: IndexSet::from_iter([
             quest_req(QuestId::PRIEST_IN_PERIL),
             quest_req(QuestId::THE_RESTLESS_GHOST),
             skill_req(Skill::Crafting, Level(20)),
             skill_req(Skill::Thieving, Level(25)),
         ]),
-        rewards: vec![xp_reward(Skill::Thieving, Xp(1_000.))],
+        rewards: vec![xp_reward(Skill::Thieving, Xp::new(1_000))],
     }
 }
 
@@ -1535,14 +1845,19 @@ fn current_affairs() -> Quest {
         id: QuestId::CURRENT_AFFAIRS,
         name: "Current Affairs".to_string(),
         quest_points: 1,
+        difficulty: QuestDifficulty::Novice,
+        length: QuestLength::Short,
+        members: true,
+        series: None,
+        release_date: ReleaseDate::new(2025, 11, 19),
         requirements: IndexSet::from_iter([
             quest_req(QuestId::PANDEMONIUM),
             skill_req(Skill::Sailing, Level(22)),
             skill_req(Skill::Fishing, Level(10)),
         ]),
         rewards: vec![
-            xp_reward(Skill::Sailing, Xp(1_400.)),
-            xp_reward(Skill::Fishing, Xp(1_000.)),
+            xp_reward(Skill::Sailing, Xp::new(1_400)),
+            xp_reward(Skill::Fishing, Xp::new(1_000)),
         ],
     }
 }
@@ -1553,6 +1868,11 @@ fn the_curse_of_arrav() -> Quest {
         id: QuestId::THE_CURSE_OF_ARRAV,
         name: "The Curse of Arrav".to_string(),
         quest_points: 2,
+        difficulty: QuestDifficulty::Master,
+        length: QuestLength::Long,
+        members: true,
+        series: Some(QuestSeries::Mahjarrat),
+        release_date: ReleaseDate::new(2024, 7, 17),
         requirements: IndexSet::from_iter([
             quest_req(QuestId::DEFENDER_OF_VARROCK),
             quest_req(QuestId::TROLL_ROMANCE),
@@ -1564,9 +1884,9 @@ fn the_curse_of_arrav() -> Quest {
             skill_req(Skill::Slayer, Level(37)),
         ]),
         rewards: vec![
-            xp_reward(Skill::Mining, Xp(40_000.)),
-            xp_reward(Skill::Thieving, Xp(40_000.)),
-            xp_reward(Skill::Agility, Xp(40_000.)),
+            xp_reward(Skill::Mining, Xp::new(40_000)),
+            xp_reward(Skill::Thieving, Xp::new(40_000)),
+            xp_reward(Skill::Agility, Xp::new(40_000)),
         ],
     }
 }
@@ -1577,6 +1897,11 @@ fn darkness_of_hallowvale() -> Quest {
         id: QuestId::DARKNESS_OF_HALLOWVALE,
         name: "Darkness of Hallowvale".to_string(),
         quest_points: 2,
+        difficulty: QuestDifficulty::Intermediate,
+        length: QuestLength::Long,
+        members: true,
+        series: Some(QuestSeries::Myreque),
+        release_date: ReleaseDate::new(2007, 3, 5),
         requirements: IndexSet::from_iter([
             quest_req(QuestId::IN_AID_OF_THE_MYREQUE),
             skill_req(Skill::Construction, Level(5)),
@@ -1588,9 +1913,9 @@ fn darkness_of_hallowvale() -> Quest {
             skill_req(Skill::Strength, Level(40)),
         ]),
         rewards: vec![
-            xp_reward(Skill::Agility, Xp(7_000.)),
-            xp_reward(Skill::Thieving, Xp(6_000.)),
-            xp_reward(Skill::Construction, Xp(2_000.)),
+            xp_reward(Skill::Agility, Xp::new(7_000)),
+            xp_reward(Skill::Thieving, Xp::new(6_000)),
+            xp_reward(Skill::Construction, Xp::new(2_000)),
         ],
     }
 }
@@ -1601,15 +1926,20 @@ fn death_on_the_isle() -> Quest {
         id: QuestId::DEATH_ON_THE_ISLE,
         name: "Death on the Isle".to_string(),
         quest_points: 2,
+        difficulty: QuestDifficulty::Intermediate,
+        length: QuestLength::Medium,
+        members: true,
+        series: None,
+        release_date: ReleaseDate::new(2024, 8, 28),
         requirements: IndexSet::from_iter([
             quest_req(QuestId::CHILDREN_OF_THE_SUN),
             skill_req(Skill::Thieving, Level(34)),
             skill_req(Skill::Agility, Level(32)),
         ]),
         rewards: vec![
-            xp_reward(Skill::Thieving, Xp(10_000.)),
-            xp_reward(Skill::Agility, Xp(7_500.)),
-            xp_reward(Skill::Crafting, Xp(5_000.)),
+            xp_reward(Skill::Thieving, Xp::new(10_000)),
+            xp_reward(Skill::Agility, Xp::new(7_500)),
+            xp_reward(Skill::Crafting, Xp::new(5_000)),
         ],
     }
 }
@@ -1620,8 +1950,13 @@ fn death_plateau() -> Quest {
         id: QuestId::DEATH_PLATEAU,
         name: "Death Plateau".to_string(),
         quest_points: 1,
+        difficulty: QuestDifficulty::Novice,
+        length: QuestLength::Short,
+        members: true,
+        series: Some(QuestSeries::Troll),
+        release_date: ReleaseDate::new(2003, 4, 28),
         requirements: IndexSet::new(),
-        rewards: vec![xp_reward(Skill::Attack, Xp(3_000.))],
+        rewards: vec![xp_reward(Skill::Attack, Xp::new(3_000))],
     }
 }
 
@@ -1631,14 +1966,19 @@ fn death_to_the_dorgeshuun() -> Quest {
         id: QuestId::DEATH_TO_THE_DORGESHUUN,
         name: "Death to the Dorgeshuun".to_string(),
         quest_points: 1,
+        difficulty: QuestDifficulty::Intermediate,
+        length: QuestLength::Medium,
+        members: true,
+        series: Some(QuestSeries::Dorgeshuun),
+        release_date: ReleaseDate::new(2005, 6, 13),
         requirements: IndexSet::from_iter([
             quest_req(QuestId::THE_LOST_TRIBE),
             skill_req(Skill::Agility, Level(23)),
             skill_req(Skill::Thieving, Level(23)),
         ]),
         rewards: vec![
-            xp_reward(Skill::Thieving, Xp(2_000.)),
-            xp_reward(Skill::Ranged, Xp(2_000.)),
+            xp_reward(Skill::Thieving, Xp::new(2_000)),
+            xp_reward(Skill::Ranged, Xp::new(2_000)),
         ],
     }
 }
@@ -1648,6 +1988,11 @@ fn defender_of_varrock() -> Quest {
         id: QuestId::DEFENDER_OF_VARROCK,
         name: "Defender of Varrock".to_string(),
         quest_points: 2,
+        difficulty: QuestDifficulty::Experienced,
+        length: QuestLength::Long,
+        members: true,
+        series: Some(QuestSeries::Mahjarrat),
+        release_date: ReleaseDate::new(2023, 11, 29),
         requirements: IndexSet::from_iter([
             quest_req(QuestId::SHIELD_OF_ARRAV),
             quest_req(QuestId::TEMPLE_OF_IKOV),
@@ -1661,8 +2006,8 @@ fn defender_of_varrock() -> Quest {
             skill_req(Skill::Hunter, Level(52)),
         ]),
         rewards: vec![
-            xp_reward(Skill::Smithing, Xp(15_000.)),
-            xp_reward(Skill::Hunter, Xp(15_000.)),
+            xp_reward(Skill::Smithing, Xp::new(15_000)),
+            xp_reward(Skill::Hunter, Xp::new(15_000)),
         ],
     }
 }
@@ -1673,11 +2018,16 @@ fn the_depths_of_despair() -> Quest {
         id: QuestId::THE_DEPTHS_OF_DESPAIR,
         name: "The Depths of Despair".to_string(),
         quest_points: 1,
+        difficulty: QuestDifficulty::Novice,
+        length: QuestLength::Short,
+        members: true,
+        series: Some(QuestSeries::GreatKourend),
+        release_date: ReleaseDate::new(2019, 1, 24),
         requirements: IndexSet::from_iter([
             quest_req(QuestId::CLIENT_OF_KOUREND),
             skill_req(Skill::Agility, Level(18)),
         ]),
-        rewards: vec![xp_reward(Skill::Agility, Xp(1_500.))],
+        rewards: vec![xp_reward(Skill::Agility, Xp::new(1_500))],
     }
 }
 
@@ -1687,6 +2037,11 @@ fn desert_treasure_i() -> Quest {
         id: QuestId::DESERT_TREASURE_I,
         name: "Desert Treasure I".to_string(),
         quest_points: 3,
+        difficulty: QuestDifficulty::Master,
+        length: QuestLength::Long,
+        members: true,
+        series: Some(QuestSeries::Mahjarrat),
+        release_date: ReleaseDate::new(2005, 3, 7),
         requirements: IndexSet::from_iter([
             quest_req(QuestId::THE_DIG_SITE),
             quest_req(QuestId::TEMPLE_OF_IKOV),
@@ -1699,7 +2054,10 @@ fn desert_treasure_i() -> Quest {
             skill_req(Skill::Firemaking, Level(50)),
             skill_req(Skill::Slayer, Level(10)),
         ]),
-        rewards: vec![xp_reward(Skill::Magic, Xp(20_006.9))],
+        rewards: vec![
+            xp_reward(Skill::Magic, Xp::from_tenths(200_069)),
+            unlock_reward(UnlockKind::Spellbook, "Ancient Magicks"),
+        ],
     }
 }
 
@@ -1709,6 +2067,11 @@ fn desert_treasure_ii() -> Quest {
         id: QuestId::DESERT_TREASURE_II,
         name: "Desert Treasure II - The Fallen Empire".to_string(),
         quest_points: 5,
+        difficulty: QuestDifficulty::Grandmaster,
+        length: QuestLength::VeryLong,
+        members: true,
+        series: Some(QuestSeries::Mahjarrat),
+        release_date: ReleaseDate::new(2023, 7, 26),
         requirements: IndexSet::from_iter([
             quest_req(QuestId::DESERT_TREASURE_I),
             quest_req(QuestId::SECRETS_OF_THE_NORTH),
@@ -1716,7 +2079,7 @@ fn desert_treasure_ii() -> Quest {
             quest_req(QuestId::TEMPLE_OF_THE_EYE),
             quest_req(QuestId::THE_GARDEN_OF_DEATH),
             quest_req(QuestId::BELOW_ICE_MOUNTAIN),
-            // TODO: Figure out how to model His Faithful Servants.
+            miniquest_req(MiniquestId::HIS_FAITHFUL_SERVANTS),
             skill_req(Skill::Firemaking, Level(75)),
             skill_req(Skill::Magic, Level(75)),
             skill_req(Skill::Thieving, Level(70)),
@@ -1724,9 +2087,12 @@ fn desert_treasure_ii() -> Quest {
             skill_req(Skill::Runecraft, Level(60)),
             skill_req(Skill::Construction, Level(60)),
         ]),
-        rewards: vec![
-            // TODO: Figure out how to model combat XP lamps.
-        ],
+        rewards: vec![lamp_reward(
+            Xp::new(35_000),
+            COMBAT_SKILLS_EXCEPT_PRAYER,
+            None,
+            4,
+        )],
     }
 }
 
@@ -1736,19 +2102,24 @@ fn devious_minds() -> Quest {
         id: QuestId::DEVIOUS_MINDS,
         name: "Devious Minds".to_string(),
         quest_points: 1,
+        difficulty: QuestDifficulty::Experienced,
+        length: QuestLength::Short,
+        members: true,
+        series: None,
+        release_date: ReleaseDate::new(2006, 5, 2),
         requirements: IndexSet::from_iter([
             quest_req(QuestId::WANTED),
             quest_req(QuestId::TROLL_STRONGHOLD),
             quest_req(QuestId::DORICS_QUEST),
-            // TODO: Figure out how to model Enter the Abyss requirement.
+            miniquest_req(MiniquestId::ENTER_THE_ABYSS),
             skill_req(Skill::Smithing, Level(65)),
             skill_req(Skill::Runecraft, Level(50)),
             skill_req(Skill::Fletching, Level(50)),
         ]),
         rewards: vec![
-            xp_reward(Skill::Fletching, Xp(5_000.)),
-            xp_reward(Skill::Runecraft, Xp(5_000.)),
-            xp_reward(Skill::Smithing, Xp(6_500.)),
+            xp_reward(Skill::Fletching, Xp::new(5_000)),
+            xp_reward(Skill::Runecraft, Xp::new(5_000)),
+            xp_reward(Skill::Smithing, Xp::new(6_500)),
         ],
     }
 }
@@ -1759,14 +2130,19 @@ fn the_dig_site() -> Quest {
         id: QuestId::THE_DIG_SITE,
         name: "The Dig Site".to_string(),
         quest_points: 2,
+        difficulty: QuestDifficulty::Intermediate,
+        length: QuestLength::Long,
+        members: true,
+        series: None,
+        release_date: ReleaseDate::new(2003, 7, 22),
         requirements: IndexSet::from_iter([
             skill_req(Skill::Agility, Level(10)),
             skill_req(Skill::Herblore, Level(10)),
             skill_req(Skill::Thieving, Level(25)),
         ]),
         rewards: vec![
-            xp_reward(Skill::Mining, Xp(15_300.)),
-            xp_reward(Skill::Herblore, Xp(2_000.)),
+            xp_reward(Skill::Mining, Xp::new(15_300)),
+            xp_reward(Skill::Herblore, Xp::new(2_000)),
         ],
     }
 }
@@ -1777,6 +2153,11 @@ fn dragon_slayer_ii() -> Quest {
         id: QuestId::DRAGON_SLAYER_II,
         name: "Dragon Slayer II".to_string(),
         quest_points: 5,
+        difficulty: QuestDifficulty::Grandmaster,
+        length: QuestLength::VeryLong,
+        members: true,
+        series: Some(QuestSeries::Dragonkin),
+        release_date: ReleaseDate::new(2019, 1, 4),
         requirements: IndexSet::from_iter([
             quest_point_req(200),
             quest_req(QuestId::LEGENDS_QUEST),
@@ -1796,10 +2177,11 @@ fn dragon_slayer_ii() -> Quest {
             skill_req(Skill::Hitpoints, Level(50)),
         ]),
         rewards: vec![
-            xp_reward(Skill::Smithing, Xp(80_000.)),
-            xp_reward(Skill::Mining, Xp(60_000.)),
-            xp_reward(Skill::Agility, Xp(50_000.)),
-            xp_reward(Skill::Thieving, Xp(50_000.)),
+            xp_reward(Skill::Smithing, Xp::new(80_000)),
+            xp_reward(Skill::Mining, Xp::new(60_000)),
+            xp_reward(Skill::Agility, Xp::new(50_000)),
+            xp_reward(Skill::Thieving, Xp::new(50_000)),
+            unlock_reward(UnlockKind::Area, "Myths' Guild"),
         ],
     }
 }
@@ -1810,14 +2192,20 @@ fn dream_mentor() -> Quest {
         id: QuestId::DREAM_MENTOR,
         name: "Dream Mentor".to_string(),
         quest_points: 2,
+        difficulty: QuestDifficulty::Master,
+        length: QuestLength::Medium,
+        members: true,
+        series: Some(QuestSeries::Fremennik),
+        release_date: ReleaseDate::new(2006, 8, 8),
         requirements: IndexSet::from_iter([
             quest_req(QuestId::LUNAR_DIPLOMACY),
             quest_req(QuestId::EADGARS_RUSE),
+            combat_level_req(Level(85)),
         ]),
         rewards: vec![
-            xp_reward(Skill::Hitpoints, Xp(15_000.)),
-            xp_reward(Skill::Magic, Xp(10_000.)),
-            // TODO: Figure out how to model dreamy lamp.
+            xp_reward(Skill::Hitpoints, Xp::new(15_000)),
+            xp_reward(Skill::Magic, Xp::new(10_000)),
+            lamp_reward(Xp::new(15_000), Skill::iter(), None, 1),
         ],
     }
 }
@@ -1828,8 +2216,16 @@ fn druidic_ritual() -> Quest {
         id: QuestId::DRUIDIC_RITUAL,
         name: "Druidic Ritual".to_string(),
         quest_points: 4,
+        difficulty: QuestDifficulty::Novice,
+        length: QuestLength::Short,
+        members: true,
+        series: None,
+        release_date: ReleaseDate::new(2001, 4, 2),
         requirements: IndexSet::new(),
-        rewards: vec![xp_reward(Skill::Herblore, Xp(250.))],
+        rewards: vec![
+            xp_reward(Skill::Herblore, Xp::new(250)),
+            unlock_reward(UnlockKind::Skill, "Herblore"),
+        ],
     }
 }
 
@@ -1839,8 +2235,13 @@ fn dwarf_cannon() -> Quest {
         id: QuestId::DWARF_CANNON,
         name: "Dwarf Cannon".to_string(),
         quest_points: 1,
+        difficulty: QuestDifficulty::Novice,
+        length: QuestLength::Short,
+        members: true,
+        series: None,
+        release_date: ReleaseDate::new(2002, 1, 21),
         requirements: IndexSet::new(),
-        rewards: vec![xp_reward(Skill::Crafting, Xp(750.))],
+        rewards: vec![xp_reward(Skill::Crafting, Xp::new(750))],
     }
 }
 
@@ -1850,12 +2251,17 @@ fn eadgars_ruse() -> Quest {
         id: QuestId::EADGARS_RUSE,
         name: "Eadgar's Ruse".to_string(),
         quest_points: 1,
+        difficulty: QuestDifficulty::Experienced,
+        length: QuestLength::Medium,
+        members: true,
+        series: Some(QuestSeries::Troll),
+        release_date: ReleaseDate::new(2003, 6, 16),
         requirements: IndexSet::from_iter([
             quest_req(QuestId::DRUIDIC_RITUAL),
             quest_req(QuestId::TROLL_STRONGHOLD),
             skill_req(Skill::Herblore, Level(31)),
         ]),
-        rewards: vec![xp_reward(Skill::Herblore, Xp(11_000.))],
+        rewards: vec![xp_reward(Skill::Herblore, Xp::new(11_000))],
     }
 }
 
@@ -1865,8 +2271,13 @@ fn eagles_peak() -> Quest {
         id: QuestId::EAGLES_PEAK,
         name: "Eagles' Peak".to_string(),
         quest_points: 2,
+        difficulty: QuestDifficulty::Novice,
+        length: QuestLength::Medium,
+        members: true,
+        series: None,
+        release_date: ReleaseDate::new(2007, 5, 30),
         requirements: IndexSet::from_iter([skill_req(Skill::Hunter, Level(27))]),
-        rewards: vec![xp_reward(Skill::Hunter, Xp(2_500.))],
+        rewards: vec![xp_reward(Skill::Hunter, Xp::new(2_500))],
     }
 }
 
@@ -1876,14 +2287,19 @@ fn elemental_workshop_i() -> Quest {
         id: QuestId::ELEMENTAL_WORKSHOP_I,
         name: "Elemental Workshop I".to_string(),
         quest_points: 1,
+        difficulty: QuestDifficulty::Novice,
+        length: QuestLength::Short,
+        members: true,
+        series: Some(QuestSeries::ElementalWorkshop),
+        release_date: ReleaseDate::new(2004, 5, 10),
         requirements: IndexSet::from_iter([
             skill_req(Skill::Mining, Level(20)),
             skill_req(Skill::Smithing, Level(20)),
             skill_req(Skill::Crafting, Level(20)),
         ]),
         rewards: vec![
-            xp_reward(Skill::Crafting, Xp(5_000.)),
-            xp_reward(Skill::Smithing, Xp(5_000.)),
+            xp_reward(Skill::Crafting, Xp::new(5_000)),
+            xp_reward(Skill::Smithing, Xp::new(5_000)),
         ],
     }
 }
@@ -1894,14 +2310,19 @@ fn elemental_workshop_ii() -> Quest {
         id: QuestId::ELEMENTAL_WORKSHOP_II,
         name: "Elemental Workshop II".to_string(),
         quest_points: 1,
+        difficulty: QuestDifficulty::Intermediate,
+        length: QuestLength::Short,
+        members: true,
+        series: Some(QuestSeries::ElementalWorkshop),
+        release_date: ReleaseDate::new(2005, 12, 12),
         requirements: IndexSet::from_iter([
             quest_req(QuestId::ELEMENTAL_WORKSHOP_I),
             skill_req(Skill::Magic, Level(20)),
             skill_req(Skill::Smithing, Level(30)),
         ]),
         rewards: vec![
-            xp_reward(Skill::Smithing, Xp(7_500.)),
-            xp_reward(Skill::Crafting, Xp(7_500.)),
+            xp_reward(Skill::Smithing, Xp::new(7_500)),
+            xp_reward(Skill::Crafting, Xp::new(7_500)),
         ],
     }
 }
@@ -1912,17 +2333,22 @@ fn enakhras_lament() -> Quest {
         id: QuestId::ENAKHRAS_LAMENT,
         name: "Enakhra's Lament".to_string(),
         quest_points: 2,
+        difficulty: QuestDifficulty::Experienced,
+        length: QuestLength::Medium,
+        members: true,
+        series: Some(QuestSeries::Mahjarrat),
+        release_date: ReleaseDate::new(2006, 4, 25),
         requirements: IndexSet::from_iter([
             skill_req(Skill::Crafting, Level(50)),
             skill_req(Skill::Firemaking, Level(45)),
-            skill_req(Skill::Prayer, Level(43)),
+            skill_req_unboostable(Skill::Prayer, Level(43)),
             skill_req(Skill::Magic, Level(39)),
         ]),
         rewards: vec![
-            xp_reward(Skill::Crafting, Xp(7_000.)),
-            xp_reward(Skill::Mining, Xp(7_000.)),
-            xp_reward(Skill::Firemaking, Xp(7_000.)),
-            xp_reward(Skill::Magic, Xp(7_000.)),
+            xp_reward(Skill::Crafting, Xp::new(7_000)),
+            xp_reward(Skill::Mining, Xp::new(7_000)),
+            xp_reward(Skill::Firemaking, Xp::new(7_000)),
+            xp_reward(Skill::Magic, Xp::new(7_000)),
         ],
     }
 }
@@ -1933,6 +2359,11 @@ fn enlightened_journey() -> Quest {
         id: QuestId::ENLIGHTENED_JOURNEY,
         name: "Enlightened Journey".to_string(),
         quest_points: 1,
+        difficulty: QuestDifficulty::Intermediate,
+        length: QuestLength::Medium,
+        members: true,
+        series: None,
+        release_date: ReleaseDate::new(2006, 2, 28),
         requirements: IndexSet::from_iter([
             quest_point_req(20),
             skill_req(Skill::Firemaking, Level(20)),
@@ -1940,10 +2371,10 @@ fn enlightened_journey() -> Quest {
             skill_req(Skill::Crafting, Level(36)),
         ]),
         rewards: vec![
-            xp_reward(Skill::Crafting, Xp(2_000.)),
-            xp_reward(Skill::Farming, Xp(3_000.)),
-            xp_reward(Skill::Woodcutting, Xp(1_500.)),
-            xp_reward(Skill::Firemaking, Xp(4_000.)),
+            xp_reward(Skill::Crafting, Xp::new(2_000)),
+            xp_reward(Skill::Farming, Xp::new(3_000)),
+            xp_reward(Skill::Woodcutting, Xp::new(1_500)),
+            xp_reward(Skill::Firemaking, Xp::new(4_000)),
         ],
     }
 }
@@ -1954,12 +2385,17 @@ fn ethically_acquired_antiquities() -> Quest {
         id: QuestId::ETHICALLY_ACQUIRED_ANTIQUITIES,
         name: "Ethically Acquired Antiquities".to_string(),
         quest_points: 1,
+        difficulty: QuestDifficulty::Novice,
+        length: QuestLength::Short,
+        members: true,
+        series: None,
+        release_date: ReleaseDate::new(2024, 5, 15),
         requirements: IndexSet::from_iter([
             skill_req(Skill::Thieving, Level(25)),
             quest_req(QuestId::CHILDREN_OF_THE_SUN),
             quest_req(QuestId::SHIELD_OF_ARRAV),
         ]),
-        rewards: vec![xp_reward(Skill::Thieving, Xp(6_000.))],
+        rewards: vec![xp_reward(Skill::Thieving, Xp::new(6_000))],
     }
 }
 
@@ -1969,16 +2405,21 @@ fn the_eyes_of_glouphrie() -> Quest {
         id: QuestId::THE_EYES_OF_GLOUPHRIE,
         name: "The Eyes of Glouphrie".to_string(),
         quest_points: 2,
+        difficulty: QuestDifficulty::Intermediate,
+        length: QuestLength::Medium,
+        members: true,
+        series: Some(QuestSeries::Gnome),
+        release_date: ReleaseDate::new(2005, 8, 30),
         requirements: IndexSet::from_iter([
             quest_req(QuestId::THE_GRAND_TREE),
             skill_req(Skill::Construction, Level(5)),
             skill_req(Skill::Magic, Level(46)),
         ]),
         rewards: vec![
-            xp_reward(Skill::Magic, Xp(12_000.)),
-            xp_reward(Skill::Woodcutting, Xp(2_500.)),
-            xp_reward(Skill::Runecraft, Xp(6_000.)),
-            xp_reward(Skill::Construction, Xp(250.)),
+            xp_reward(Skill::Magic, Xp::new(12_000)),
+            xp_reward(Skill::Woodcutting, Xp::new(2_500)),
+            xp_reward(Skill::Runecraft, Xp::new(6_000)),
+            xp_reward(Skill::Construction, Xp::new(250)),
         ],
     }
 }
@@ -1989,14 +2430,16 @@ fn fairytale_i() -> Quest {
         id: QuestId::FAIRYTALE_I,
         name: "Fairytale I - Growing Pains".to_string(),
         quest_points: 2,
-        requirements: IndexSet::from_iter([
-            quest_req(QuestId::LOST_CITY),
-            quest_req(QuestId::NATURE_SPIRIT),
-        ]),
+        difficulty: QuestDifficulty::Intermediate,
+        length: QuestLength::Medium,
+        members: true,
+        series: Some(QuestSeries::FairyTale),
+        release_date: ReleaseDate::new(2005, 8, 8),
+        requirements: IndexSet::from_iter([zanaris_req(), quest_req(QuestId::NATURE_SPIRIT)]),
         rewards: vec![
-            xp_reward(Skill::Farming, Xp(3_500.)),
-            xp_reward(Skill::Attack, Xp(2_000.)),
-            xp_reward(Skill::Magic, Xp(1_000.)),
+            xp_reward(Skill::Farming, Xp::new(3_500)),
+            xp_reward(Skill::Attack, Xp::new(2_000)),
+            xp_reward(Skill::Magic, Xp::new(1_000)),
         ],
     }
 }
@@ -2007,15 +2450,22 @@ fn fairytale_ii() -> Quest {
         id: QuestId::FAIRYTALE_II,
         name: "Fairytale II - Cure a Queen".to_string(),
         quest_points: 2,
+        difficulty: QuestDifficulty::Experienced,
+        length: QuestLength::Medium,
+        members: true,
+        series: Some(QuestSeries::FairyTale),
+        release_date: ReleaseDate::new(2006, 7, 5),
         requirements: IndexSet::from_iter([
             quest_req(QuestId::FAIRYTALE_I),
+            zanaris_req(),
             skill_req(Skill::Thieving, Level(40)),
             skill_req(Skill::Farming, Level(49)),
             skill_req(Skill::Herblore, Level(57)),
         ]),
         rewards: vec![
-            xp_reward(Skill::Herblore, Xp(3500.)),
-            xp_reward(Skill::Thieving, Xp(2500.)),
+            xp_reward(Skill::Herblore, Xp::new(3500)),
+            xp_reward(Skill::Thieving, Xp::new(2500)),
+            unlock_reward(UnlockKind::Feature, "Fairy rings"),
         ],
     }
 }
@@ -2026,6 +2476,11 @@ fn family_crest() -> Quest {
         id: QuestId::FAMILY_CREST,
         name: "Family Crest".to_string(),
         quest_points: 1,
+        difficulty: QuestDifficulty::Experienced,
+        length: QuestLength::Medium,
+        members: true,
+        series: None,
+        release_date: ReleaseDate::new(2002, 2, 18),
         requirements: IndexSet::from_iter([
             skill_req(Skill::Mining, Level(40)),
             skill_req(Skill::Smithing, Level(40)),
@@ -2042,8 +2497,13 @@ fn the_feud() -> Quest {
         id: QuestId::THE_FEUD,
         name: "The Feud".to_string(),
         quest_points: 1,
+        difficulty: QuestDifficulty::Intermediate,
+        length: QuestLength::Medium,
+        members: true,
+        series: Some(QuestSeries::Desert),
+        release_date: ReleaseDate::new(2005, 6, 28),
         requirements: IndexSet::from_iter([skill_req(Skill::Thieving, Level(30))]),
-        rewards: vec![xp_reward(Skill::Thieving, Xp(15_000.))],
+        rewards: vec![xp_reward(Skill::Thieving, Xp::new(15_000))],
     }
 }
 
@@ -2053,10 +2513,15 @@ fn fight_arena() -> Quest {
         id: QuestId::FIGHT_ARENA,
         name: "Fight Arena".to_string(),
         quest_points: 2,
+        difficulty: QuestDifficulty::Experienced,
+        length: QuestLength::Medium,
+        members: true,
+        series: None,
+        release_date: ReleaseDate::new(2002, 3, 18),
         requirements: IndexSet::new(),
         rewards: vec![
-            xp_reward(Skill::Attack, Xp(12_175.)),
-            xp_reward(Skill::Thieving, Xp(2_175.)),
+            xp_reward(Skill::Attack, Xp::new(12_175)),
+            xp_reward(Skill::Thieving, Xp::new(2_175)),
         ],
     }
 }
@@ -2067,6 +2532,11 @@ fn the_final_dawn() -> Quest {
         id: QuestId::THE_FINAL_DAWN,
         name: "The Final Dawn".to_string(),
         quest_points: 3,
+        difficulty: QuestDifficulty::Master,
+        length: QuestLength::Long,
+        members: true,
+        series: Some(QuestSeries::Varlamore),
+        release_date: ReleaseDate::new(2025, 7, 23),
         requirements: IndexSet::from_iter([
             quest_req(QuestId::THE_HEART_OF_DARKNESS),
             quest_req(QuestId::PERILOUS_MOONS),
@@ -2075,10 +2545,10 @@ fn the_final_dawn() -> Quest {
             skill_req(Skill::Fletching, Level(52)),
         ]),
         rewards: vec![
-            xp_reward(Skill::Thieving, Xp(55_000.)),
-            xp_reward(Skill::Runecraft, Xp(25_000.)),
-            xp_reward(Skill::Fletching, Xp(25_000.)),
-            // TODO: Figure out how to model 55,000 combat lamp.
+            xp_reward(Skill::Thieving, Xp::new(55_000)),
+            xp_reward(Skill::Runecraft, Xp::new(25_000)),
+            xp_reward(Skill::Fletching, Xp::new(25_000)),
+            lamp_reward(Xp::new(55_000), COMBAT_SKILLS_EXCEPT_PRAYER, None, 1),
         ],
     }
 }
@@ -2089,8 +2559,13 @@ pub fn fishing_contest() -> Quest {
         id: QuestId::FISHING_CONTEST,
         name: "Fishing Contest".to_string(),
         quest_points: 1,
+        difficulty: QuestDifficulty::Novice,
+        length: QuestLength::Short,
+        members: true,
+        series: None,
+        release_date: ReleaseDate::new(2002, 1, 21),
         requirements: IndexSet::from_iter([skill_req(Skill::Fishing, Level(10))]),
-        rewards: vec![xp_reward(Skill::Fishing, Xp(2_437.))],
+        rewards: vec![xp_reward(Skill::Fishing, Xp::new(2_437))],
     }
 }
 
@@ -2100,6 +2575,11 @@ pub fn forgettable_tale() -> Quest {
         id: QuestId::FORGETTABLE_TALE,
         name: "Forgettable Tale...".to_string(),
         quest_points: 2,
+        difficulty: QuestDifficulty::Intermediate,
+        length: QuestLength::Medium,
+        members: true,
+        series: Some(QuestSeries::RedAxe),
+        release_date: ReleaseDate::new(2005, 3, 29),
         requirements: IndexSet::from_iter([
             quest_req(QuestId::THE_GIANT_DWARF),
             quest_req(QuestId::FISHING_CONTEST),
@@ -2107,8 +2587,8 @@ pub fn forgettable_tale() -> Quest {
             skill_req(Skill::Farming, Level(17)),
         ]),
         rewards: vec![
-            xp_reward(Skill::Cooking, Xp(5_000.)),
-            xp_reward(Skill::Farming, Xp(5_000.)),
+            xp_reward(Skill::Cooking, Xp::new(5_000)),
+            xp_reward(Skill::Farming, Xp::new(5_000)),
         ],
     }
 }
@@ -2119,10 +2599,15 @@ pub fn the_forsaken_tower() -> Quest {
         id: QuestId::THE_FORSAKEN_TOWER,
         name: "The Forsaken Tower".to_string(),
         quest_points: 1,
+        difficulty: QuestDifficulty::Intermediate,
+        length: QuestLength::Medium,
+        members: true,
+        series: Some(QuestSeries::GreatKourend),
+        release_date: ReleaseDate::new(2019, 4, 4),
         requirements: IndexSet::from_iter([quest_req(QuestId::CLIENT_OF_KOUREND)]),
         rewards: vec![
-            xp_reward(Skill::Mining, Xp(500.)),
-            xp_reward(Skill::Smithing, Xp(500.)),
+            xp_reward(Skill::Mining, Xp::new(500)),
+            xp_reward(Skill::Smithing, Xp::new(500)),
         ],
     }
 }
@@ -2133,6 +2618,11 @@ pub fn the_fremennik_exiles() -> Quest {
         id: QuestId::THE_FREMENNIK_EXILES,
         name: "The Fremennik Exiles".to_string(),
         quest_points: 2,
+        difficulty: QuestDifficulty::Master,
+        length: QuestLength::Long,
+        members: true,
+        series: Some(QuestSeries::Fremennik),
+        release_date: ReleaseDate::new(2019, 8, 29),
         requirements: IndexSet::from_iter([
             quest_req(QuestId::THE_FREMENNIK_ISLES),
             quest_req(QuestId::LUNAR_DIPLOMACY),
@@ -2145,9 +2635,9 @@ pub fn the_fremennik_exiles() -> Quest {
             skill_req(Skill::Runecraft, Level(55)),
         ]),
         rewards: vec![
-            xp_reward(Skill::Slayer, Xp(50_000.)),
-            xp_reward(Skill::Crafting, Xp(50_000.)),
-            xp_reward(Skill::Runecraft, Xp(30_000.)),
+            xp_reward(Skill::Slayer, Xp::new(50_000)),
+            xp_reward(Skill::Crafting, Xp::new(50_000)),
+            xp_reward(Skill::Runecraft, Xp::new(30_000)),
         ],
     }
 }
@@ -2158,15 +2648,20 @@ pub fn the_fremennik_isles() -> Quest {
         id: QuestId::THE_FREMENNIK_ISLES,
         name: "The Fremennik Isles".to_string(),
         quest_points: 1,
+        difficulty: QuestDifficulty::Experienced,
+        length: QuestLength::Long,
+        members: true,
+        series: Some(QuestSeries::Fremennik),
+        release_date: ReleaseDate::new(2006, 11, 21),
         requirements: IndexSet::from_iter([
             quest_req(QuestId::THE_FREMENNIK_TRIALS),
             skill_req(Skill::Construction, Level(20)),
         ]),
         rewards: vec![
-            xp_reward(Skill::Woodcutting, Xp(10_000.)),
-            xp_reward(Skill::Crafting, Xp(5_000.)),
-            xp_reward(Skill::Construction, Xp(5_000.)),
-            // TODO: Figure out how to model "Two lots of Combat level 10,000 Combat experience".
+            xp_reward(Skill::Woodcutting, Xp::new(10_000)),
+            xp_reward(Skill::Crafting, Xp::new(5_000)),
+            xp_reward(Skill::Construction, Xp::new(5_000)),
+            lamp_reward(Xp::new(10_000), COMBAT_SKILLS_EXCEPT_PRAYER, None, 2),
         ],
     }
 }
@@ -2177,22 +2672,27 @@ pub fn the_fremennik_trials() -> Quest {
         id: QuestId::THE_FREMENNIK_TRIALS,
         name: "The Fremennik Trials".to_string(),
         quest_points: 3,
+        difficulty: QuestDifficulty::Intermediate,
+        length: QuestLength::Long,
+        members: true,
+        series: Some(QuestSeries::Fremennik),
+        release_date: ReleaseDate::new(2003, 11, 24),
         requirements: IndexSet::from_iter([
             skill_req(Skill::Fletching, Level(25)),
             skill_req(Skill::Woodcutting, Level(40)),
             skill_req(Skill::Crafting, Level(40)),
         ]),
         rewards: vec![
-            xp_reward(Skill::Agility, Xp(2_812.4)),
-            xp_reward(Skill::Attack, Xp(2_812.4)),
-            xp_reward(Skill::Crafting, Xp(2_812.4)),
-            xp_reward(Skill::Defence, Xp(2_812.4)),
-            xp_reward(Skill::Fishing, Xp(2_812.4)),
-            xp_reward(Skill::Fletching, Xp(2_812.4)),
-            xp_reward(Skill::Hitpoints, Xp(2_812.4)),
-            xp_reward(Skill::Strength, Xp(2_812.4)),
-            xp_reward(Skill::Thieving, Xp(2_812.4)),
-            xp_reward(Skill::Woodcutting, Xp(2_812.4)),
+            xp_reward(Skill::Agility, Xp::from_tenths(28_124)),
+            xp_reward(Skill::Attack, Xp::from_tenths(28_124)),
+            xp_reward(Skill::Crafting, Xp::from_tenths(28_124)),
+            xp_reward(Skill::Defence, Xp::from_tenths(28_124)),
+            xp_reward(Skill::Fishing, Xp::from_tenths(28_124)),
+            xp_reward(Skill::Fletching, Xp::from_tenths(28_124)),
+            xp_reward(Skill::Hitpoints, Xp::from_tenths(28_124)),
+            xp_reward(Skill::Strength, Xp::from_tenths(28_124)),
+            xp_reward(Skill::Thieving, Xp::from_tenths(28_124)),
+            xp_reward(Skill::Woodcutting, Xp::from_tenths(28_124)),
         ],
     }
 }
@@ -2203,8 +2703,13 @@ fn the_garden_of_death() -> Quest {
         id: QuestId::THE_GARDEN_OF_DEATH,
         name: "The Garden of Death".to_string(),
         quest_points: 1,
+        difficulty: QuestDifficulty::Novice,
+        length: QuestLength::Medium,
+        members: true,
+        series: None,
+        release_date: ReleaseDate::new(2021, 6, 23),
         requirements: IndexSet::from_iter([skill_req(Skill::Farming, Level(20))]),
-        rewards: vec![xp_reward(Skill::Farming, Xp(10_000.))],
+        rewards: vec![xp_reward(Skill::Farming, Xp::new(10_000))],
     }
 }
 
@@ -2214,11 +2719,16 @@ fn garden_of_tranquillity() -> Quest {
         id: QuestId::GARDEN_OF_TRANQUILLITY,
         name: "Garden of Tranquillity".to_string(),
         quest_points: 2,
+        difficulty: QuestDifficulty::Intermediate,
+        length: QuestLength::Long,
+        members: true,
+        series: None,
+        release_date: ReleaseDate::new(2005, 2, 22),
         requirements: IndexSet::from_iter([
             quest_req(QuestId::CREATURE_OF_FENKENSTRAIN),
             skill_req(Skill::Farming, Level(25)),
         ]),
-        rewards: vec![xp_reward(Skill::Farming, Xp(5_000.))],
+        rewards: vec![xp_reward(Skill::Farming, Xp::new(5_000))],
     }
 }
 
@@ -2228,8 +2738,13 @@ fn gertrudes_cat() -> Quest {
         id: QuestId::GERTRUDES_CAT,
         name: "Gertrude's Cat".to_string(),
         quest_points: 1,
+        difficulty: QuestDifficulty::Novice,
+        length: QuestLength::Short,
+        members: true,
+        series: None,
+        release_date: ReleaseDate::new(2003, 5, 5),
         requirements: IndexSet::new(),
-        rewards: vec![xp_reward(Skill::Cooking, Xp(1_525.))],
+        rewards: vec![xp_reward(Skill::Cooking, Xp::new(1_525))],
     }
 }
 
@@ -2239,13 +2754,18 @@ fn getting_ahead() -> Quest {
         id: QuestId::GETTING_AHEAD,
         name: "Getting Ahead".to_string(),
         quest_points: 1,
+        difficulty: QuestDifficulty::Intermediate,
+        length: QuestLength::Short,
+        members: true,
+        series: Some(QuestSeries::GreatKourend),
+        release_date: ReleaseDate::new(2020, 9, 17),
         requirements: IndexSet::from_iter([
             skill_req(Skill::Crafting, Level(30)),
             skill_req(Skill::Construction, Level(26)),
         ]),
         rewards: vec![
-            xp_reward(Skill::Crafting, Xp(4_000.)),
-            xp_reward(Skill::Construction, Xp(3_200.)),
+            xp_reward(Skill::Crafting, Xp::new(4_000)),
+            xp_reward(Skill::Construction, Xp::new(3_200)),
         ],
     }
 }
@@ -2256,13 +2776,18 @@ fn ghosts_ahoy() -> Quest {
         id: QuestId::GHOSTS_AHOY,
         name: "Ghosts Ahoy".to_string(),
         quest_points: 2,
+        difficulty: QuestDifficulty::Intermediate,
+        length: QuestLength::Medium,
+        members: true,
+        series: None,
+        release_date: ReleaseDate::new(2003, 9, 2),
         requirements: IndexSet::from_iter([
             quest_req(QuestId::PRIEST_IN_PERIL),
             quest_req(QuestId::THE_RESTLESS_GHOST),
             skill_req(Skill::Agility, Level(25)),
             skill_req(Skill::Cooking, Level(20)),
         ]),
-        rewards: vec![xp_reward(Skill::Prayer, Xp(2_400.))],
+        rewards: vec![xp_reward(Skill::Prayer, Xp::new(2_400))],
     }
 }
 
@@ -2272,6 +2797,11 @@ fn the_giant_dwarf() -> Quest {
         id: QuestId::THE_GIANT_DWARF,
         name: "The Giant Dwarf".to_string(),
         quest_points: 2,
+        difficulty: QuestDifficulty::Intermediate,
+        length: QuestLength::Medium,
+        members: true,
+        series: Some(QuestSeries::RedAxe),
+        release_date: ReleaseDate::new(2004, 12, 7),
         requirements: IndexSet::from_iter([
             skill_req(Skill::Crafting, Level(12)),
             skill_req(Skill::Firemaking, Level(16)),
@@ -2279,12 +2809,12 @@ fn the_giant_dwarf() -> Quest {
             skill_req(Skill::Thieving, Level(14)),
         ]),
         rewards: vec![
-            xp_reward(Skill::Mining, Xp(2_500.)),
-            xp_reward(Skill::Smithing, Xp(2_500.)),
-            xp_reward(Skill::Crafting, Xp(2_500.)),
-            xp_reward(Skill::Magic, Xp(1_500.)),
-            xp_reward(Skill::Thieving, Xp(1_500.)),
-            xp_reward(Skill::Firemaking, Xp(1_500.)),
+            xp_reward(Skill::Mining, Xp::new(2_500)),
+            xp_reward(Skill::Smithing, Xp::new(2_500)),
+            xp_reward(Skill::Crafting, Xp::new(2_500)),
+            xp_reward(Skill::Magic, Xp::new(1_500)),
+            xp_reward(Skill::Thieving, Xp::new(1_500)),
+            xp_reward(Skill::Firemaking, Xp::new(1_500)),
         ],
     }
 }
@@ -2295,13 +2825,18 @@ fn the_golem() -> Quest {
         id: QuestId::THE_GOLEM,
         name: "The Golem".to_string(),
         quest_points: 1,
+        difficulty: QuestDifficulty::Intermediate,
+        length: QuestLength::Medium,
+        members: true,
+        series: Some(QuestSeries::Desert),
+        release_date: ReleaseDate::new(2005, 1, 11),
         requirements: IndexSet::from_iter([
             skill_req(Skill::Crafting, Level(20)),
             skill_req(Skill::Thieving, Level(25)),
         ]),
         rewards: vec![
-            xp_reward(Skill::Crafting, Xp(1_000.)),
-            xp_reward(Skill::Thieving, Xp(1_000.)),
+            xp_reward(Skill::Crafting, Xp::new(1_000)),
+            xp_reward(Skill::Thieving, Xp::new(1_000)),
         ],
     }
 }
@@ -2312,11 +2847,16 @@ fn the_grand_tree() -> Quest {
         id: QuestId::THE_GRAND_TREE,
         name: "The Grand Tree".to_string(),
         quest_points: 5,
+        difficulty: QuestDifficulty::Experienced,
+        length: QuestLength::Medium,
+        members: true,
+        series: Some(QuestSeries::Gnome),
+        release_date: ReleaseDate::new(2002, 3, 4),
         requirements: IndexSet::from_iter([skill_req(Skill::Agility, Level(25))]),
         rewards: vec![
-            xp_reward(Skill::Attack, Xp(18_400.)),
-            xp_reward(Skill::Agility, Xp(7_900.)),
-            xp_reward(Skill::Magic, Xp(2_150.)),
+            xp_reward(Skill::Attack, Xp::new(18_400)),
+            xp_reward(Skill::Agility, Xp::new(7_900)),
+            xp_reward(Skill::Magic, Xp::new(2_150)),
         ],
     }
 }
@@ -2327,19 +2867,24 @@ fn the_great_brain_robbery() -> Quest {
         id: QuestId::THE_GREAT_BRAIN_ROBBERY,
         name: "The Great Brain Robbery".to_string(),
         quest_points: 2,
+        difficulty: QuestDifficulty::Experienced,
+        length: QuestLength::Medium,
+        members: true,
+        series: Some(QuestSeries::Pirate),
+        release_date: ReleaseDate::new(2006, 8, 22),
         requirements: IndexSet::from_iter([
             quest_req(QuestId::CREATURE_OF_FENKENSTRAIN),
             quest_req(QuestId::CABIN_FEVER),
             quest_req(QuestId::RECIPE_FOR_DISASTER_FREEING_PIRATE_PETE),
             skill_req(Skill::Crafting, Level(16)),
             skill_req(Skill::Construction, Level(30)),
-            skill_req(Skill::Prayer, Level(50)),
+            skill_req_unboostable(Skill::Prayer, Level(50)),
         ]),
         rewards: vec![
-            xp_reward(Skill::Prayer, Xp(6_000.)),
-            xp_reward(Skill::Crafting, Xp(3_000.)),
-            xp_reward(Skill::Construction, Xp(2_000.)),
-            // TODO: Figure out how to model "Blessed lamp".
+            xp_reward(Skill::Prayer, Xp::new(6_000)),
+            xp_reward(Skill::Crafting, Xp::new(3_000)),
+            xp_reward(Skill::Construction, Xp::new(2_000)),
+            lamp_reward(Xp::new(5_000), [Skill::Prayer], None, 1),
         ],
     }
 }
@@ -2350,6 +2895,11 @@ fn grim_tales() -> Quest {
         id: QuestId::GRIM_TALES,
         name: "Grim Tales".to_string(),
         quest_points: 1,
+        difficulty: QuestDifficulty::Master,
+        length: QuestLength::Medium,
+        members: true,
+        series: None,
+        release_date: ReleaseDate::new(2007, 7, 31),
         requirements: IndexSet::from_iter([
             quest_req(QuestId::WITCHS_HOUSE),
             skill_req(Skill::Farming, Level(45)),
@@ -2359,12 +2909,12 @@ fn grim_tales() -> Quest {
             skill_req(Skill::Woodcutting, Level(71)),
         ]),
         rewards: vec![
-            xp_reward(Skill::Woodcutting, Xp(60_000.)),
-            xp_reward(Skill::Agility, Xp(25_000.)),
-            xp_reward(Skill::Thieving, Xp(25_000.)),
-            xp_reward(Skill::Herblore, Xp(15_000.)),
-            xp_reward(Skill::Farming, Xp(10_000.)),
-            xp_reward(Skill::Hitpoints, Xp(5_000.)),
+            xp_reward(Skill::Woodcutting, Xp::new(60_000)),
+            xp_reward(Skill::Agility, Xp::new(25_000)),
+            xp_reward(Skill::Thieving, Xp::new(25_000)),
+            xp_reward(Skill::Herblore, Xp::new(15_000)),
+            xp_reward(Skill::Farming, Xp::new(10_000)),
+            xp_reward(Skill::Hitpoints, Xp::new(5_000)),
         ],
     }
 }
@@ -2375,13 +2925,18 @@ fn the_hand_in_the_sand() -> Quest {
         id: QuestId::THE_HAND_IN_THE_SAND,
         name: "The Hand in the Sand".to_string(),
         quest_points: 1,
+        difficulty: QuestDifficulty::Intermediate,
+        length: QuestLength::Medium,
+        members: true,
+        series: None,
+        release_date: ReleaseDate::new(2005, 5, 16),
         requirements: IndexSet::from_iter([
             skill_req(Skill::Thieving, Level(17)),
             skill_req(Skill::Crafting, Level(49)),
         ]),
         rewards: vec![
-            xp_reward(Skill::Thieving, Xp(1_000.)),
-            xp_reward(Skill::Crafting, Xp(9_000.)),
+            xp_reward(Skill::Thieving, Xp::new(1_000)),
+            xp_reward(Skill::Crafting, Xp::new(9_000)),
         ],
     }
 }
@@ -2392,11 +2947,16 @@ fn haunted_mine() -> Quest {
         id: QuestId::HAUNTED_MINE,
         name: "Haunted Mine".to_string(),
         quest_points: 2,
+        difficulty: QuestDifficulty::Experienced,
+        length: QuestLength::Medium,
+        members: true,
+        series: None,
+        release_date: ReleaseDate::new(2004, 11, 9),
         requirements: IndexSet::from_iter([
             quest_req(QuestId::PRIEST_IN_PERIL),
             skill_req(Skill::Crafting, Level(35)),
         ]),
-        rewards: vec![xp_reward(Skill::Strength, Xp(22_000.))],
+        rewards: vec![xp_reward(Skill::Strength, Xp::new(22_000))],
     }
 }
 
@@ -2406,8 +2966,13 @@ fn hazeel_cult() -> Quest {
         id: QuestId::HAZEEL_CULT,
         name: "Hazeel Cult".to_string(),
         quest_points: 1,
+        difficulty: QuestDifficulty::Novice,
+        length: QuestLength::Medium,
+        members: true,
+        series: None,
+        release_date: ReleaseDate::new(2002, 2, 4),
         requirements: IndexSet::new(),
-        rewards: vec![xp_reward(Skill::Thieving, Xp(1_500.))],
+        rewards: vec![xp_reward(Skill::Thieving, Xp::new(1_500))],
     }
 }
 
@@ -2417,6 +2982,11 @@ fn the_heart_of_darkness() -> Quest {
         id: QuestId::THE_HEART_OF_DARKNESS,
         name: "The Heart of Darkness".to_string(),
         quest_points: 2,
+        difficulty: QuestDifficulty::Experienced,
+        length: QuestLength::Medium,
+        members: true,
+        series: Some(QuestSeries::Varlamore),
+        release_date: ReleaseDate::new(2024, 6, 5),
         requirements: IndexSet::from_iter([
             quest_req(QuestId::TWILIGHTS_PROMISE),
             skill_req(Skill::Mining, Level(55)),
@@ -2425,10 +2995,10 @@ fn the_heart_of_darkness() -> Quest {
             skill_req(Skill::Agility, Level(46)),
         ]),
         rewards: vec![
-            xp_reward(Skill::Mining, Xp(8_000.)),
-            xp_reward(Skill::Thieving, Xp(8_000.)),
-            xp_reward(Skill::Slayer, Xp(8_000.)),
-            xp_reward(Skill::Agility, Xp(8_000.)),
+            xp_reward(Skill::Mining, Xp::new(8_000)),
+            xp_reward(Skill::Thieving, Xp::new(8_000)),
+            xp_reward(Skill::Slayer, Xp::new(8_000)),
+            xp_reward(Skill::Agility, Xp::new(8_000)),
         ],
     }
 }
@@ -2439,6 +3009,11 @@ fn heroes_quest() -> Quest {
         id: QuestId::HEROES_QUEST,
         name: "Heroes' Quest".to_string(),
         quest_points: 1,
+        difficulty: QuestDifficulty::Experienced,
+        length: QuestLength::Long,
+        members: true,
+        series: None,
+        release_date: ReleaseDate::new(2002, 1, 7),
         requirements: IndexSet::from_iter([
             quest_point_req(55),
             quest_req(QuestId::SHIELD_OF_ARRAV),
@@ -2451,18 +3026,19 @@ fn heroes_quest() -> Quest {
             skill_req(Skill::Mining, Level(50)),
         ]),
         rewards: vec![
-            xp_reward(Skill::Attack, Xp(3_075.)),
-            xp_reward(Skill::Defence, Xp(3_075.)),
-            xp_reward(Skill::Strength, Xp(3_075.)),
-            xp_reward(Skill::Hitpoints, Xp(3_075.)),
-            xp_reward(Skill::Ranged, Xp(2_075.)),
-            xp_reward(Skill::Fishing, Xp(2_725.)),
-            xp_reward(Skill::Cooking, Xp(2_825.)),
-            xp_reward(Skill::Woodcutting, Xp(1_575.)),
-            xp_reward(Skill::Firemaking, Xp(1_575.)),
-            xp_reward(Skill::Smithing, Xp(2_275.)),
-            xp_reward(Skill::Mining, Xp(2_575.)),
-            xp_reward(Skill::Herblore, Xp(1_325.)),
+            xp_reward(Skill::Attack, Xp::new(3_075)),
+            xp_reward(Skill::Defence, Xp::new(3_075)),
+            xp_reward(Skill::Strength, Xp::new(3_075)),
+            xp_reward(Skill::Hitpoints, Xp::new(3_075)),
+            xp_reward(Skill::Ranged, Xp::new(2_075)),
+            xp_reward(Skill::Fishing, Xp::new(2_725)),
+            xp_reward(Skill::Cooking, Xp::new(2_825)),
+            xp_reward(Skill::Woodcutting, Xp::new(1_575)),
+            xp_reward(Skill::Firemaking, Xp::new(1_575)),
+            xp_reward(Skill::Smithing, Xp::new(2_275)),
+            xp_reward(Skill::Mining, Xp::new(2_575)),
+            xp_reward(Skill::Herblore, Xp::new(1_325)),
+            unlock_reward(UnlockKind::Area, "Heroes' Guild"),
         ],
     }
 }
@@ -2473,13 +3049,18 @@ fn holy_grail() -> Quest {
         id: QuestId::HOLY_GRAIL,
         name: "Holy Grail".to_string(),
         quest_points: 2,
+        difficulty: QuestDifficulty::Intermediate,
+        length: QuestLength::Long,
+        members: true,
+        series: Some(QuestSeries::Camelot),
+        release_date: ReleaseDate::new(2001, 12, 18),
         requirements: IndexSet::from_iter([
             quest_req(QuestId::MERLINS_CRYSTAL),
             skill_req(Skill::Attack, Level(20)),
         ]),
         rewards: vec![
-            xp_reward(Skill::Prayer, Xp(11_000.)),
-            xp_reward(Skill::Defence, Xp(15_300.)),
+            xp_reward(Skill::Prayer, Xp::new(11_000)),
+            xp_reward(Skill::Defence, Xp::new(15_300)),
         ],
     }
 }
@@ -2490,14 +3071,24 @@ fn horror_from_the_deep() -> Quest {
         id: QuestId::HORROR_FROM_THE_DEEP,
         name: "Horror from the Deep".to_string(),
         quest_points: 2,
+        difficulty: QuestDifficulty::Experienced,
+        length: QuestLength::Medium,
+        members: true,
+        series: Some(QuestSeries::Fremennik),
+        release_date: ReleaseDate::new(2004, 2, 16),
         requirements: IndexSet::from_iter([
             skill_req(Skill::Agility, Level(35)),
-            // TODO: Figure out how to model Alfred Grimhand's Barcrawl.
+            miniquest_req(MiniquestId::ALFRED_GRIMHANDS_BARCRAWL),
         ]),
         rewards: vec![
-            xp_reward(Skill::Magic, Xp(4_662.5)),
-            xp_reward(Skill::Strength, Xp(4_662.5)),
-            xp_reward(Skill::Ranged, Xp(4_662.5)),
+            xp_reward(Skill::Magic, Xp::from_tenths(46_625)),
+            xp_reward(Skill::Strength, Xp::from_tenths(46_625)),
+            xp_reward(Skill::Ranged, Xp::from_tenths(46_625)),
+            QuestReward::Choice(vec![
+                vec![item_reward("Damaged book (Saradomin)", 1)],
+                vec![item_reward("Damaged book (Zamorak)", 1)],
+                vec![item_reward("Damaged book (Guthix)", 1)],
+            ]),
         ],
     }
 }
@@ -2508,11 +3099,19 @@ fn icthlarins_little_helper() -> Quest {
         id: QuestId::ICTHLARINS_LITTLE_HELPER,
         name: "Icthlarin's Little Helper".to_string(),
         quest_points: 2,
-        requirements: IndexSet::from_iter([quest_req(QuestId::GERTRUDES_CAT)]),
+        difficulty: QuestDifficulty::Intermediate,
+        length: QuestLength::Medium,
+        members: true,
+        series: Some(QuestSeries::Desert),
+        release_date: ReleaseDate::new(2005, 11, 22),
+        requirements: IndexSet::from_iter([
+            quest_req(QuestId::GERTRUDES_CAT),
+            QuestRequirement::Any(vec![item_req("Pet kitten", 1), item_req("Pet cat", 1)]),
+        ]),
         rewards: vec![
-            xp_reward(Skill::Thieving, Xp(4_500.)),
-            xp_reward(Skill::Agility, Xp(4_000.)),
-            xp_reward(Skill::Woodcutting, Xp(4_000.)),
+            xp_reward(Skill::Thieving, Xp::new(4_500)),
+            xp_reward(Skill::Agility, Xp::new(4_000)),
+            xp_reward(Skill::Woodcutting, Xp::new(4_000)),
         ],
     }
 }
@@ -2523,6 +3122,11 @@ fn in_aid_of_the_myreque() -> Quest {
         id: QuestId::IN_AID_OF_THE_MYREQUE,
         name: "In Aid of the Myreque".to_string(),
         quest_points: 2,
+        difficulty: QuestDifficulty::Intermediate,
+        length: QuestLength::Long,
+        members: true,
+        series: Some(QuestSeries::Myreque),
+        release_date: ReleaseDate::new(2006, 7, 25),
         requirements: IndexSet::from_iter([
             quest_req(QuestId::IN_SEARCH_OF_THE_MYREQUE),
             skill_req(Skill::Agility, Level(25)),
@@ -2531,10 +3135,10 @@ fn in_aid_of_the_myreque() -> Quest {
             skill_req(Skill::Magic, Level(7)),
         ]),
         rewards: vec![
-            xp_reward(Skill::Attack, Xp(2_000.)),
-            xp_reward(Skill::Strength, Xp(2_000.)),
-            xp_reward(Skill::Crafting, Xp(2_000.)),
-            xp_reward(Skill::Defence, Xp(2_000.)),
+            xp_reward(Skill::Attack, Xp::new(2_000)),
+            xp_reward(Skill::Strength, Xp::new(2_000)),
+            xp_reward(Skill::Crafting, Xp::new(2_000)),
+            xp_reward(Skill::Defence, Xp::new(2_000)),
         ],
     }
 }
@@ -2545,16 +3149,21 @@ fn in_search_of_the_myreque() -> Quest {
         id: QuestId::IN_SEARCH_OF_THE_MYREQUE,
         name: "In Search of the Myreque".to_string(),
         quest_points: 2,
+        difficulty: QuestDifficulty::Intermediate,
+        length: QuestLength::Short,
+        members: true,
+        series: Some(QuestSeries::Myreque),
+        release_date: ReleaseDate::new(2004, 12, 6),
         requirements: IndexSet::from_iter([
             quest_req(QuestId::NATURE_SPIRIT),
             skill_req(Skill::Agility, Level(25)),
         ]),
         rewards: vec![
-            xp_reward(Skill::Attack, Xp(600.)),
-            xp_reward(Skill::Defence, Xp(600.)),
-            xp_reward(Skill::Strength, Xp(600.)),
-            xp_reward(Skill::Hitpoints, Xp(600.)),
-            xp_reward(Skill::Crafting, Xp(600.)),
+            xp_reward(Skill::Attack, Xp::new(600)),
+            xp_reward(Skill::Defence, Xp::new(600)),
+            xp_reward(Skill::Strength, Xp::new(600)),
+            xp_reward(Skill::Hitpoints, Xp::new(600)),
+            xp_reward(Skill::Crafting, Xp::new(600)),
         ],
     }
 }
@@ -2565,11 +3174,16 @@ fn jungle_potion() -> Quest {
         id: QuestId::JUNGLE_POTION,
         name: "Jungle Potion".to_string(),
         quest_points: 1,
+        difficulty: QuestDifficulty::Novice,
+        length: QuestLength::Short,
+        members: true,
+        series: None,
+        release_date: ReleaseDate::new(2002, 9, 10),
         requirements: IndexSet::from_iter([
             quest_req(QuestId::DRUIDIC_RITUAL),
             skill_req(Skill::Herblore, Level(3)),
         ]),
-        rewards: vec![xp_reward(Skill::Herblore, Xp(775.))],
+        rewards: vec![xp_reward(Skill::Herblore, Xp::new(775))],
     }
 }
 
@@ -2579,6 +3193,11 @@ fn kings_ransom() -> Quest {
         id: QuestId::KINGS_RANSOM,
         name: "King's Ransom".to_string(),
         quest_points: 1,
+        difficulty: QuestDifficulty::Experienced,
+        length: QuestLength::Medium,
+        members: true,
+        series: Some(QuestSeries::Camelot),
+        release_date: ReleaseDate::new(2007, 6, 12),
         requirements: IndexSet::from_iter([
             quest_req(QuestId::BLACK_KNIGHTS_FORTRESS),
             quest_req(QuestId::HOLY_GRAIL),
@@ -2588,9 +3207,10 @@ fn kings_ransom() -> Quest {
             skill_req(Skill::Defence, Level(65)),
         ]),
         rewards: vec![
-            xp_reward(Skill::Defence, Xp(33_000.)),
-            xp_reward(Skill::Magic, Xp(5_000.)),
-            // TODO: Figure out how to model antique lamp.
+            xp_reward(Skill::Defence, Xp::new(33_000)),
+            xp_reward(Skill::Magic, Xp::new(5_000)),
+            lamp_reward(Xp::new(5_000), Skill::iter(), None, 1),
+            unlock_reward(UnlockKind::Feature, "Knight Waves Training Ground"),
         ],
     }
 }
@@ -2601,6 +3221,11 @@ fn a_kingdom_divided() -> Quest {
         id: QuestId::A_KINGDOM_DIVIDED,
         name: "A Kingdom Divided".to_string(),
         quest_points: 2,
+        difficulty: QuestDifficulty::Experienced,
+        length: QuestLength::Long,
+        members: true,
+        series: Some(QuestSeries::GreatKourend),
+        release_date: ReleaseDate::new(2021, 6, 9),
         requirements: IndexSet::from_iter([
             quest_req(QuestId::THE_DEPTHS_OF_DESPAIR),
             quest_req(QuestId::THE_QUEEN_OF_THIEVES),
@@ -2615,9 +3240,7 @@ fn a_kingdom_divided() -> Quest {
             skill_req(Skill::Crafting, Level(38)),
             skill_req(Skill::Magic, Level(35)),
         ]),
-        rewards: vec![
-            // TODO: Figure out how to model two antique lamps.
-        ],
+        rewards: vec![lamp_reward(Xp::new(10_000), Skill::iter(), None, 2)],
     }
 }
 
@@ -2627,6 +3250,11 @@ fn land_of_the_goblins() -> Quest {
         id: QuestId::LAND_OF_THE_GOBLINS,
         name: "Land of the Goblins".to_string(),
         quest_points: 2,
+        difficulty: QuestDifficulty::Experienced,
+        length: QuestLength::Medium,
+        members: true,
+        series: Some(QuestSeries::Dorgeshuun),
+        release_date: ReleaseDate::new(2024, 11, 6),
         requirements: IndexSet::from_iter([
             quest_req(QuestId::ANOTHER_SLICE_OF_HAM),
             quest_req(QuestId::FISHING_CONTEST),
@@ -2636,10 +3264,10 @@ fn land_of_the_goblins() -> Quest {
             skill_req(Skill::Herblore, Level(48)),
         ]),
         rewards: vec![
-            xp_reward(Skill::Agility, Xp(8_000.)),
-            xp_reward(Skill::Fishing, Xp(8_000.)),
-            xp_reward(Skill::Thieving, Xp(8_000.)),
-            xp_reward(Skill::Herblore, Xp(8_000.)),
+            xp_reward(Skill::Agility, Xp::new(8_000)),
+            xp_reward(Skill::Fishing, Xp::new(8_000)),
+            xp_reward(Skill::Thieving, Xp::new(8_000)),
+            xp_reward(Skill::Herblore, Xp::new(8_000)),
         ],
     }
 }
@@ -2650,6 +3278,11 @@ fn legends_quest() -> Quest {
         id: QuestId::LEGENDS_QUEST,
         name: "Legends' Quest".to_string(),
         quest_points: 4,
+        difficulty: QuestDifficulty::Master,
+        length: QuestLength::Long,
+        members: true,
+        series: None,
+        release_date: ReleaseDate::new(2002, 4, 1),
         requirements: IndexSet::from_iter([
             quest_point_req(107),
             quest_req(QuestId::FAMILY_CREST),
@@ -2662,14 +3295,33 @@ fn legends_quest() -> Quest {
             skill_req(Skill::Herblore, Level(45)),
             skill_req(Skill::Magic, Level(56)),
             skill_req(Skill::Mining, Level(52)),
-            skill_req(Skill::Prayer, Level(42)),
+            skill_req_unboostable(Skill::Prayer, Level(42)),
             skill_req(Skill::Smithing, Level(50)),
             skill_req(Skill::Strength, Level(50)),
             skill_req(Skill::Thieving, Level(50)),
             skill_req(Skill::Woodcutting, Level(50)),
         ]),
         rewards: vec![
-            // TODO: Figure out how to model 30,000 experience each in four skills of choice.
+            lamp_reward(
+                Xp::new(7_650),
+                [
+                    Skill::Attack,
+                    Skill::Defence,
+                    Skill::Strength,
+                    Skill::Hitpoints,
+                    Skill::Prayer,
+                    Skill::Magic,
+                    Skill::Woodcutting,
+                    Skill::Crafting,
+                    Skill::Smithing,
+                    Skill::Herblore,
+                    Skill::Agility,
+                    Skill::Thieving,
+                ],
+                None,
+                4,
+            ),
+            unlock_reward(UnlockKind::Area, "Legends' Guild"),
         ],
     }
 }
@@ -2680,11 +3332,20 @@ fn lost_city() -> Quest {
         id: QuestId::LOST_CITY,
         name: "Lost City".to_string(),
         quest_points: 3,
+        difficulty: QuestDifficulty::Experienced,
+        length: QuestLength::Medium,
+        members: true,
+        series: None,
+        release_date: ReleaseDate::new(2001, 9, 3),
         requirements: IndexSet::from_iter([
             skill_req(Skill::Crafting, Level(31)),
             skill_req(Skill::Woodcutting, Level(36)),
         ]),
-        rewards: Vec::new(),
+        rewards: vec![
+            unlock_reward(UnlockKind::Area, "Zanaris"),
+            unlock_reward(UnlockKind::Equipment, "Dragon longsword"),
+            unlock_reward(UnlockKind::Equipment, "Dragon dagger"),
+        ],
     }
 }
 
@@ -2694,6 +3355,11 @@ fn the_lost_tribe() -> Quest {
         id: QuestId::THE_LOST_TRIBE,
         name: "The Lost Tribe".to_string(),
         quest_points: 1,
+        difficulty: QuestDifficulty::Intermediate,
+        length: QuestLength::Medium,
+        members: true,
+        series: Some(QuestSeries::Dorgeshuun),
+        release_date: ReleaseDate::new(2004, 7, 6),
         requirements: IndexSet::from_iter([
             quest_req(QuestId::GOBLIN_DIPLOMACY),
             quest_req(QuestId::RUNE_MYSTERIES),
@@ -2701,7 +3367,7 @@ fn the_lost_tribe() -> Quest {
             skill_req(Skill::Thieving, Level(13)),
             skill_req(Skill::Mining, Level(17)),
         ]),
-        rewards: vec![xp_reward(Skill::Mining, Xp(3_000.))],
+        rewards: vec![xp_reward(Skill::Mining, Xp::new(3_000))],
     }
 }
 
@@ -2711,6 +3377,11 @@ fn lunar_diplomacy() -> Quest {
         id: QuestId::LUNAR_DIPLOMACY,
         name: "Lunar Diplomacy".to_string(),
         quest_points: 2,
+        difficulty: QuestDifficulty::Experienced,
+        length: QuestLength::Long,
+        members: true,
+        series: Some(QuestSeries::Fremennik),
+        release_date: ReleaseDate::new(2005, 10, 10),
         requirements: IndexSet::from_iter([
             quest_req(QuestId::THE_FREMENNIK_TRIALS),
             quest_req(QuestId::LOST_CITY),
@@ -2725,8 +3396,9 @@ fn lunar_diplomacy() -> Quest {
             skill_req(Skill::Woodcutting, Level(55)),
         ]),
         rewards: vec![
-            xp_reward(Skill::Magic, Xp(5_000.)),
-            xp_reward(Skill::Runecraft, Xp(5_000.)),
+            xp_reward(Skill::Magic, Xp::new(5_000)),
+            xp_reward(Skill::Runecraft, Xp::new(5_000)),
+            unlock_reward(UnlockKind::Spellbook, "Lunar"),
         ],
     }
 }
@@ -2737,6 +3409,11 @@ fn making_friends_with_my_arm() -> Quest {
         id: QuestId::MAKING_FRIENDS_WITH_MY_ARM,
         name: "Making Friends with My Arm".to_string(),
         quest_points: 2,
+        difficulty: QuestDifficulty::Master,
+        length: QuestLength::Long,
+        members: true,
+        series: Some(QuestSeries::Troll),
+        release_date: ReleaseDate::new(2018, 4, 26),
         requirements: IndexSet::from_iter([
             quest_req(QuestId::MY_ARMS_BIG_ADVENTURE),
             quest_req(QuestId::SWAN_SONG),
@@ -2748,10 +3425,10 @@ fn making_friends_with_my_arm() -> Quest {
             skill_req(Skill::Agility, Level(68)),
         ]),
         rewards: vec![
-            xp_reward(Skill::Construction, Xp(10_000.)),
-            xp_reward(Skill::Firemaking, Xp(40_000.)),
-            xp_reward(Skill::Mining, Xp(50_000.)),
-            xp_reward(Skill::Agility, Xp(50_000.)),
+            xp_reward(Skill::Construction, Xp::new(10_000)),
+            xp_reward(Skill::Firemaking, Xp::new(40_000)),
+            xp_reward(Skill::Mining, Xp::new(50_000)),
+            xp_reward(Skill::Agility, Xp::new(50_000)),
         ],
     }
 }
@@ -2762,13 +3439,18 @@ fn making_history() -> Quest {
         id: QuestId::MAKING_HISTORY,
         name: "Making History".to_string(),
         quest_points: 3,
+        difficulty: QuestDifficulty::Intermediate,
+        length: QuestLength::Medium,
+        members: true,
+        series: None,
+        release_date: ReleaseDate::new(2004, 10, 11),
         requirements: IndexSet::from_iter([
             quest_req(QuestId::PRIEST_IN_PERIL),
             quest_req(QuestId::THE_RESTLESS_GHOST),
         ]),
         rewards: vec![
-            xp_reward(Skill::Crafting, Xp(1_000.)),
-            xp_reward(Skill::Prayer, Xp(1_000.)),
+            xp_reward(Skill::Crafting, Xp::new(1_000)),
+            xp_reward(Skill::Prayer, Xp::new(1_000)),
         ],
     }
 }
@@ -2779,8 +3461,13 @@ fn meat_and_greet() -> Quest {
         id: QuestId::MEAT_AND_GREET,
         name: "Meat and Greet".to_string(),
         quest_points: 1,
+        difficulty: QuestDifficulty::Experienced,
+        length: QuestLength::Medium,
+        members: true,
+        series: None,
+        release_date: ReleaseDate::new(2024, 8, 28),
         requirements: IndexSet::from_iter([quest_req(QuestId::CHILDREN_OF_THE_SUN)]),
-        rewards: vec![xp_reward(Skill::Cooking, Xp(8_000.))],
+        rewards: vec![xp_reward(Skill::Cooking, Xp::new(8_000))],
     }
 }
 
@@ -2790,8 +3477,13 @@ fn merlins_crystal() -> Quest {
         id: QuestId::MERLINS_CRYSTAL,
         name: "Merlin's Crystal".to_string(),
         quest_points: 6,
+        difficulty: QuestDifficulty::Intermediate,
+        length: QuestLength::Medium,
+        members: true,
+        series: Some(QuestSeries::Camelot),
+        release_date: ReleaseDate::new(2001, 10, 8),
         requirements: IndexSet::new(),
-        rewards: Vec::new(),
+        rewards: vec![item_reward("Excalibur", 1)],
     }
 }
 
@@ -2801,8 +3493,13 @@ fn monks_friend() -> Quest {
         id: QuestId::MONKS_FRIEND,
         name: "Monk's Friend".to_string(),
         quest_points: 1,
+        difficulty: QuestDifficulty::Novice,
+        length: QuestLength::Short,
+        members: true,
+        series: None,
+        release_date: ReleaseDate::new(2002, 1, 21),
         requirements: IndexSet::new(),
-        rewards: vec![xp_reward(Skill::Woodcutting, Xp(2_000.))],
+        rewards: vec![xp_reward(Skill::Woodcutting, Xp::new(2_000))],
     }
 }
 
@@ -2812,12 +3509,27 @@ fn monkey_madness_i() -> Quest {
         id: QuestId::MONKEY_MADNESS_I,
         name: "Monkey Madness I".to_string(),
         quest_points: 3,
+        difficulty: QuestDifficulty::Master,
+        length: QuestLength::Long,
+        members: true,
+        series: Some(QuestSeries::Gnome),
+        release_date: ReleaseDate::new(2003, 10, 6),
         requirements: IndexSet::from_iter([
             quest_req(QuestId::THE_GRAND_TREE),
             quest_req(QuestId::TREE_GNOME_VILLAGE),
         ]),
         rewards: vec![
-            // TODO: Experience rewards based on player choice.
+            QuestReward::Choice(vec![
+                vec![
+                    xp_reward(Skill::Attack, Xp::new(35_000)),
+                    xp_reward(Skill::Defence, Xp::new(35_000)),
+                ],
+                vec![
+                    xp_reward(Skill::Strength, Xp::new(35_000)),
+                    xp_reward(Skill::Hitpoints, Xp::new(35_000)),
+                ],
+            ]),
+            unlock_reward(UnlockKind::Equipment, "Dragon scimitar"),
         ],
     }
 }
@@ -2828,6 +3540,11 @@ fn monkey_madness_ii() -> Quest {
         id: QuestId::MONKEY_MADNESS_II,
         name: "Monkey Madness II".to_string(),
         quest_points: 4,
+        difficulty: QuestDifficulty::Grandmaster,
+        length: QuestLength::VeryLong,
+        members: true,
+        series: Some(QuestSeries::Gnome),
+        release_date: ReleaseDate::new(2016, 7, 7),
         requirements: IndexSet::from_iter([
             quest_req(QuestId::RECIPE_FOR_DISASTER_FREEING_KING_AWOWOGEI),
             quest_req(QuestId::ENLIGHTENED_JOURNEY),
@@ -2842,11 +3559,22 @@ fn monkey_madness_ii() -> Quest {
             skill_req(Skill::Firemaking, Level(60)),
         ]),
         rewards: vec![
-            xp_reward(Skill::Slayer, Xp(80_000.)),
-            xp_reward(Skill::Agility, Xp(60_000.)),
-            xp_reward(Skill::Thieving, Xp(50_000.)),
-            xp_reward(Skill::Hunter, Xp(50_000.)),
-            // TODO: Additional experience rewards based on player choice
+            xp_reward(Skill::Slayer, Xp::new(80_000)),
+            xp_reward(Skill::Agility, Xp::new(60_000)),
+            xp_reward(Skill::Thieving, Xp::new(50_000)),
+            xp_reward(Skill::Hunter, Xp::new(50_000)),
+            lamp_reward(
+                Xp::new(50_000),
+                [
+                    Skill::Attack,
+                    Skill::Strength,
+                    Skill::Defence,
+                    Skill::Hitpoints,
+                ],
+                None,
+                2,
+            ),
+            unlock_reward(UnlockKind::Equipment, "Heavy ballista"),
         ],
     }
 }
@@ -2857,10 +3585,15 @@ fn mountain_daughter() -> Quest {
         id: QuestId::MOUNTAIN_DAUGHTER,
         name: "Mountain Daughter".to_string(),
         quest_points: 2,
+        difficulty: QuestDifficulty::Intermediate,
+        length: QuestLength::Medium,
+        members: true,
+        series: Some(QuestSeries::Fremennik),
+        release_date: ReleaseDate::new(2004, 3, 1),
         requirements: IndexSet::from_iter([skill_req(Skill::Agility, Level(20))]),
         rewards: vec![
-            xp_reward(Skill::Prayer, Xp(2_000.)),
-            xp_reward(Skill::Attack, Xp(1_000.)),
+            xp_reward(Skill::Prayer, Xp::new(2_000)),
+            xp_reward(Skill::Attack, Xp::new(1_000)),
         ],
     }
 }
@@ -2871,6 +3604,11 @@ fn mournings_end_part_i() -> Quest {
         id: QuestId::MOURNINGS_END_PART_I,
         name: "Mourning's End Part I".to_string(),
         quest_points: 2,
+        difficulty: QuestDifficulty::Master,
+        length: QuestLength::Long,
+        members: true,
+        series: Some(QuestSeries::Elf),
+        release_date: ReleaseDate::new(2005, 4, 26),
         requirements: IndexSet::from_iter([
             quest_req(QuestId::ROVING_ELVES),
             quest_req(QuestId::BIG_CHOMPY_BIRD_HUNTING),
@@ -2879,8 +3617,8 @@ fn mournings_end_part_i() -> Quest {
             skill_req(Skill::Thieving, Level(50)),
         ]),
         rewards: vec![
-            xp_reward(Skill::Thieving, Xp(40_000.)),
-            xp_reward(Skill::Hitpoints, Xp(25_000.)),
+            xp_reward(Skill::Thieving, Xp::new(40_000)),
+            xp_reward(Skill::Hitpoints, Xp::new(25_000)),
         ],
     }
 }
@@ -2891,8 +3629,16 @@ fn mournings_end_part_ii() -> Quest {
         id: QuestId::MOURNINGS_END_PART_II,
         name: "Mourning's End Part II".to_string(),
         quest_points: 2,
+        difficulty: QuestDifficulty::Master,
+        length: QuestLength::Long,
+        members: true,
+        series: Some(QuestSeries::Elf),
+        release_date: ReleaseDate::new(2006, 1, 17),
         requirements: IndexSet::from_iter([quest_req(QuestId::MOURNINGS_END_PART_I)]),
-        rewards: vec![xp_reward(Skill::Agility, Xp(60_000.))],
+        rewards: vec![
+            xp_reward(Skill::Agility, Xp::new(60_000)),
+            unlock_reward(UnlockKind::Area, "Death Altar"),
+        ],
     }
 }
 
@@ -2902,8 +3648,13 @@ fn murder_mystery() -> Quest {
         id: QuestId::MURDER_MYSTERY,
         name: "Murder Mystery".to_string(),
         quest_points: 3,
+        difficulty: QuestDifficulty::Novice,
+        length: QuestLength::Short,
+        members: true,
+        series: None,
+        release_date: ReleaseDate::new(2002, 1, 21),
         requirements: IndexSet::new(),
-        rewards: vec![xp_reward(Skill::Crafting, Xp(1_406.))],
+        rewards: vec![xp_reward(Skill::Crafting, Xp::new(1_406))],
     }
 }
 
@@ -2913,6 +3664,11 @@ fn my_arms_big_adventure() -> Quest {
         id: QuestId::MY_ARMS_BIG_ADVENTURE,
         name: "My Arm's Big Adventure".to_string(),
         quest_points: 1,
+        difficulty: QuestDifficulty::Intermediate,
+        length: QuestLength::Medium,
+        members: true,
+        series: Some(QuestSeries::Troll),
+        release_date: ReleaseDate::new(2006, 12, 12),
         requirements: IndexSet::from_iter([
             quest_req(QuestId::EADGARS_RUSE),
             quest_req(QuestId::THE_FEUD),
@@ -2921,8 +3677,8 @@ fn my_arms_big_adventure() -> Quest {
             skill_req(Skill::Woodcutting, Level(10)),
         ]),
         rewards: vec![
-            xp_reward(Skill::Herblore, Xp(10_000.)),
-            xp_reward(Skill::Farming, Xp(5_000.)),
+            xp_reward(Skill::Herblore, Xp::new(10_000)),
+            xp_reward(Skill::Farming, Xp::new(5_000)),
         ],
     }
 }
@@ -2933,14 +3689,19 @@ fn nature_spirit() -> Quest {
         id: QuestId::NATURE_SPIRIT,
         name: "Nature Spirit".to_string(),
         quest_points: 2,
+        difficulty: QuestDifficulty::Novice,
+        length: QuestLength::Medium,
+        members: true,
+        series: Some(QuestSeries::Myreque),
+        release_date: ReleaseDate::new(2004, 4, 19),
         requirements: IndexSet::from_iter([
             quest_req(QuestId::PRIEST_IN_PERIL),
             quest_req(QuestId::THE_RESTLESS_GHOST),
         ]),
         rewards: vec![
-            xp_reward(Skill::Crafting, Xp(3_000.)),
-            xp_reward(Skill::Defence, Xp(2_000.)),
-            xp_reward(Skill::Hitpoints, Xp(2_000.)),
+            xp_reward(Skill::Crafting, Xp::new(3_000)),
+            xp_reward(Skill::Defence, Xp::new(2_000)),
+            xp_reward(Skill::Hitpoints, Xp::new(2_000)),
         ],
     }
 }
@@ -2951,10 +3712,13 @@ fn a_night_at_the_theatre() -> Quest {
         id: QuestId::A_NIGHT_AT_THE_THEATRE,
         name: "A Night at the Theatre".to_string(),
         quest_points: 2,
+        difficulty: QuestDifficulty::Master,
+        length: QuestLength::Medium,
+        members: true,
+        series: None,
+        release_date: ReleaseDate::new(2021, 5, 6),
         requirements: IndexSet::from_iter([quest_req(QuestId::A_TASTE_OF_HOPE)]),
-        rewards: vec![
-            // TODO: Figure out how to model 4 antique lamps.
-        ],
+        rewards: vec![lamp_reward(Xp::new(20_000), Skill::iter(), None, 4)],
     }
 }
 
@@ -2964,10 +3728,29 @@ fn observatory_quest() -> Quest {
         id: QuestId::OBSERVATORY_QUEST,
         name: "Observatory Quest".to_string(),
         quest_points: 2,
+        difficulty: QuestDifficulty::Novice,
+        length: QuestLength::Short,
+        members: true,
+        series: None,
+        release_date: ReleaseDate::new(2002, 1, 21),
         requirements: IndexSet::new(),
         rewards: vec![
-            xp_reward(Skill::Crafting, Xp(2_250.)),
-            // TODO: There's also the random reward, which can contain XP.
+            xp_reward(Skill::Crafting, Xp::new(2_250)),
+            // Depends on the constellation seen through the telescope.
+            QuestReward::Random(vec![
+                vec![item_reward("Water rune", 25)],
+                vec![xp_reward(Skill::Attack, Xp::new(875))],
+                vec![item_reward("Amulet of defence", 1)],
+                vec![xp_reward(Skill::Strength, Xp::new(875))],
+                vec![item_reward("Black 2h sword", 1)],
+                vec![xp_reward(Skill::Hitpoints, Xp::new(875))],
+                vec![item_reward("Law rune", 3)],
+                vec![item_reward("Tuna", 3)],
+                vec![item_reward("Maple longbow", 1)],
+                vec![item_reward("Weapon poison", 1)],
+                vec![item_reward("Super strength(4)", 1)],
+                vec![xp_reward(Skill::Defence, Xp::new(875))],
+            ]),
         ],
     }
 }
@@ -2978,12 +3761,17 @@ fn olafs_quest() -> Quest {
         id: QuestId::OLAFS_QUEST,
         name: "Olaf's Quest".to_string(),
         quest_points: 1,
+        difficulty: QuestDifficulty::Master,
+        length: QuestLength::Medium,
+        members: true,
+        series: Some(QuestSeries::Fremennik),
+        release_date: ReleaseDate::new(2007, 6, 26),
         requirements: IndexSet::from_iter([
             quest_req(QuestId::THE_FREMENNIK_TRIALS),
             skill_req(Skill::Firemaking, Level(40)),
             skill_req(Skill::Woodcutting, Level(50)),
         ]),
-        rewards: vec![xp_reward(Skill::Defence, Xp(12_000.))],
+        rewards: vec![xp_reward(Skill::Defence, Xp::new(12_000))],
     }
 }
 
@@ -2993,6 +3781,11 @@ fn one_small_favour() -> Quest {
         id: QuestId::ONE_SMALL_FAVOUR,
         name: "One Small Favour".to_string(),
         quest_points: 2,
+        difficulty: QuestDifficulty::Experienced,
+        length: QuestLength::VeryLong,
+        members: true,
+        series: None,
+        release_date: ReleaseDate::new(2004, 6, 7),
         requirements: IndexSet::from_iter([
             quest_req(QuestId::RUNE_MYSTERIES),
             quest_req(QuestId::SHILO_VILLAGE),
@@ -3001,9 +3794,7 @@ fn one_small_favour() -> Quest {
             skill_req(Skill::Herblore, Level(18)),
             skill_req(Skill::Smithing, Level(30)),
         ]),
-        rewards: vec![
-            // TODO: Figure out how to model 2 XP lamps.
-        ],
+        rewards: vec![lamp_reward(Xp::new(10_000), Skill::iter(), None, 2)],
     }
 }
 
@@ -3013,8 +3804,13 @@ fn pandemonium() -> Quest {
         id: QuestId::PANDEMONIUM,
         name: "Pandemonium".to_string(),
         quest_points: 1,
+        difficulty: QuestDifficulty::Novice,
+        length: QuestLength::Short,
+        members: true,
+        series: None,
+        release_date: ReleaseDate::new(2025, 11, 19),
         requirements: IndexSet::new(),
-        rewards: vec![xp_reward(Skill::Sailing, Xp(300.))],
+        rewards: vec![xp_reward(Skill::Sailing, Xp::new(300))],
     }
 }
 
@@ -3024,6 +3820,11 @@ fn the_path_of_glouphrie() -> Quest {
         id: QuestId::THE_PATH_OF_GLOUPHRIE,
         name: "The Path of Glouphrie".to_string(),
         quest_points: 2,
+        difficulty: QuestDifficulty::Experienced,
+        length: QuestLength::Medium,
+        members: true,
+        series: Some(QuestSeries::Gnome),
+        release_date: ReleaseDate::new(2023, 3, 8),
         requirements: IndexSet::from_iter([
             quest_req(QuestId::THE_EYES_OF_GLOUPHRIE),
             quest_req(QuestId::WATERFALL_QUEST),
@@ -3035,11 +3836,10 @@ fn the_path_of_glouphrie() -> Quest {
             skill_req(Skill::Agility, Level(45)),
         ]),
         rewards: vec![
-            // Note: These are all received in lamp form.
-            xp_reward(Skill::Strength, Xp(30_000.)),
-            xp_reward(Skill::Slayer, Xp(20_000.)),
-            xp_reward(Skill::Thieving, Xp(5_000.)),
-            xp_reward(Skill::Magic, Xp(5_000.)),
+            lamp_reward(Xp::new(30_000), [Skill::Strength], None, 1),
+            lamp_reward(Xp::new(20_000), [Skill::Slayer], None, 1),
+            lamp_reward(Xp::new(5_000), [Skill::Thieving], None, 1),
+            lamp_reward(Xp::new(5_000), [Skill::Magic], None, 1),
         ],
     }
 }
@@ -3050,6 +3850,11 @@ fn perilous_moons() -> Quest {
         id: QuestId::PERILOUS_MOONS,
         name: "Perilous Moons".to_string(),
         quest_points: 2,
+        difficulty: QuestDifficulty::Experienced,
+        length: QuestLength::Medium,
+        members: true,
+        series: None,
+        release_date: ReleaseDate::new(2024, 3, 20),
         requirements: IndexSet::from_iter([
             quest_req(QuestId::TWILIGHTS_PROMISE),
             skill_req(Skill::Slayer, Level(48)),
@@ -3059,10 +3864,10 @@ fn perilous_moons() -> Quest {
             skill_req(Skill::Construction, Level(10)),
         ]),
         rewards: vec![
-            xp_reward(Skill::Slayer, Xp(40_000.)),
-            xp_reward(Skill::Runecraft, Xp(5_000.)),
-            xp_reward(Skill::Hunter, Xp(5_000.)),
-            xp_reward(Skill::Fishing, Xp(5_000.)),
+            xp_reward(Skill::Slayer, Xp::new(40_000)),
+            xp_reward(Skill::Runecraft, Xp::new(5_000)),
+            xp_reward(Skill::Hunter, Xp::new(5_000)),
+            xp_reward(Skill::Fishing, Xp::new(5_000)),
         ],
     }
 }
@@ -3073,8 +3878,13 @@ fn plague_city() -> Quest {
         id: QuestId::PLAGUE_CITY,
         name: "Plague City".to_string(),
         quest_points: 1,
+        difficulty: QuestDifficulty::Novice,
+        length: QuestLength::Short,
+        members: true,
+        series: Some(QuestSeries::Elf),
+        release_date: ReleaseDate::new(2002, 4, 29),
         requirements: IndexSet::new(),
-        rewards: vec![xp_reward(Skill::Mining, Xp(2_425.))],
+        rewards: vec![xp_reward(Skill::Mining, Xp::new(2_425))],
     }
 }
 
@@ -3084,8 +3894,13 @@ fn a_porcine_of_interest() -> Quest {
         id: QuestId::A_PORCINE_OF_INTEREST,
         name: "A Porcine of Interest".to_string(),
         quest_points: 1,
+        difficulty: QuestDifficulty::Novice,
+        length: QuestLength::Short,
+        members: true,
+        series: None,
+        release_date: ReleaseDate::new(2021, 6, 10),
         requirements: IndexSet::new(),
-        rewards: vec![xp_reward(Skill::Slayer, Xp(1_000.))],
+        rewards: vec![xp_reward(Skill::Slayer, Xp::new(1_000))],
     }
 }
 
@@ -3095,8 +3910,16 @@ fn priest_in_peril() -> Quest {
         id: QuestId::PRIEST_IN_PERIL,
         name: "Priest in Peril".to_string(),
         quest_points: 1,
+        difficulty: QuestDifficulty::Novice,
+        length: QuestLength::Short,
+        members: true,
+        series: Some(QuestSeries::Myreque),
+        release_date: ReleaseDate::new(2004, 3, 29),
         requirements: IndexSet::new(),
-        rewards: vec![xp_reward(Skill::Prayer, Xp(1_406.))],
+        rewards: vec![
+            xp_reward(Skill::Prayer, Xp::new(1_406)),
+            unlock_reward(UnlockKind::Area, "Morytania"),
+        ],
     }
 }
 
@@ -3106,6 +3929,11 @@ fn prying_times() -> Quest {
         id: QuestId::PRYING_TIMES,
         name: "Prying Times".to_string(),
         quest_points: 1,
+        difficulty: QuestDifficulty::Intermediate,
+        length: QuestLength::Medium,
+        members: true,
+        series: None,
+        release_date: ReleaseDate::new(2025, 11, 19),
         requirements: IndexSet::from_iter([
             quest_req(QuestId::PANDEMONIUM),
             quest_req(QuestId::THE_KNIGHTS_SWORD),
@@ -3113,8 +3941,8 @@ fn prying_times() -> Quest {
             skill_req(Skill::Sailing, Level(12)),
         ]),
         rewards: vec![
-            xp_reward(Skill::Sailing, Xp(800.)),
-            xp_reward(Skill::Smithing, Xp(1_000.)),
+            xp_reward(Skill::Sailing, Xp::new(800)),
+            xp_reward(Skill::Smithing, Xp::new(1_000)),
         ],
     }
 }
@@ -3125,11 +3953,16 @@ fn the_queen_of_thieves() -> Quest {
         id: QuestId::THE_QUEEN_OF_THIEVES,
         name: "The Queen of Thieves".to_string(),
         quest_points: 1,
+        difficulty: QuestDifficulty::Novice,
+        length: QuestLength::Short,
+        members: true,
+        series: Some(QuestSeries::GreatKourend),
+        release_date: ReleaseDate::new(2018, 11, 22),
         requirements: IndexSet::from_iter([
             quest_req(QuestId::CLIENT_OF_KOUREND),
             skill_req(Skill::Thieving, Level(20)),
         ]),
-        rewards: vec![xp_reward(Skill::Thieving, Xp(2_000.))],
+        rewards: vec![xp_reward(Skill::Thieving, Xp::new(2_000))],
     }
 }
 
@@ -3139,10 +3972,15 @@ fn rag_and_bone_man_i() -> Quest {
         id: QuestId::RAG_AND_BONE_MAN_I,
         name: "Rag and Bone Man I".to_string(),
         quest_points: 1,
+        difficulty: QuestDifficulty::Novice,
+        length: QuestLength::Short,
+        members: true,
+        series: Some(QuestSeries::RagAndBoneMan),
+        release_date: ReleaseDate::new(2005, 5, 31),
         requirements: IndexSet::new(),
         rewards: vec![
-            xp_reward(Skill::Cooking, Xp(500.)),
-            xp_reward(Skill::Prayer, Xp(500.)),
+            xp_reward(Skill::Cooking, Xp::new(500)),
+            xp_reward(Skill::Prayer, Xp::new(500)),
         ],
     }
 }
@@ -3153,15 +3991,20 @@ fn rag_and_bone_man_ii() -> Quest {
         id: QuestId::RAG_AND_BONE_MAN_II,
         name: "Rag and Bone Man II".to_string(),
         quest_points: 1,
+        difficulty: QuestDifficulty::Intermediate,
+        length: QuestLength::Long,
+        members: true,
+        series: Some(QuestSeries::RagAndBoneMan),
+        release_date: ReleaseDate::new(2005, 12, 6),
         requirements: IndexSet::from_iter([
             quest_req(QuestId::RAG_AND_BONE_MAN_I),
-            // TODO: Figure out how to model Skippy and the Mogres miniquest requirement.
+            miniquest_req(MiniquestId::SKIPPY_AND_THE_MOGRES),
             quest_req(QuestId::HORROR_FROM_THE_DEEP),
             quest_req(QuestId::CREATURE_OF_FENKENSTRAIN),
             skill_req(Skill::Slayer, Level(40)),
             skill_req(Skill::Defence, Level(20)),
         ]),
-        rewards: vec![xp_reward(Skill::Prayer, Xp(5_000.))],
+        rewards: vec![xp_reward(Skill::Prayer, Xp::new(5_000))],
     }
 }
 
@@ -3171,8 +4014,16 @@ fn ratcatchers() -> Quest {
         id: QuestId::RATCATCHERS,
         name: "Ratcatchers".to_string(),
         quest_points: 2,
-        requirements: IndexSet::from_iter([quest_req(QuestId::ICTHLARINS_LITTLE_HELPER)]),
-        rewards: vec![xp_reward(Skill::Thieving, Xp(4_500.))],
+        difficulty: QuestDifficulty::Intermediate,
+        length: QuestLength::Medium,
+        members: true,
+        series: None,
+        release_date: ReleaseDate::new(2006, 4, 4),
+        requirements: IndexSet::from_iter([
+            quest_req(QuestId::ICTHLARINS_LITTLE_HELPER),
+            item_req("Pet cat", 1),
+        ]),
+        rewards: vec![xp_reward(Skill::Thieving, Xp::new(4_500))],
     }
 }
 
@@ -3182,6 +4033,11 @@ fn recipe_for_disaster() -> Quest {
         id: QuestId::RECIPE_FOR_DISASTER,
         name: "Recipe for Disaster".to_string(),
         quest_points: 1,
+        difficulty: QuestDifficulty::Special,
+        length: QuestLength::VeryLong,
+        members: true,
+        series: None,
+        release_date: ReleaseDate::new(2006, 5, 16),
         requirements: IndexSet::from_iter([
             quest_req(QuestId::RECIPE_FOR_DISASTER_ANOTHER_COOKS_QUEST),
             quest_req(QuestId::RECIPE_FOR_DISASTER_FREEING_THE_MOUNTAIN_DWARF),
@@ -3194,7 +4050,8 @@ fn recipe_for_disaster() -> Quest {
             quest_req(QuestId::RECIPE_FOR_DISASTER_FREEING_KING_AWOWOGEI),
         ]),
         rewards: vec![
-            // TODO: Figure out how to model antique lamp.
+            lamp_reward(Xp::new(20_000), Skill::iter(), Some(Level(50)), 1),
+            unlock_reward(UnlockKind::Equipment, "Barrows gloves"),
         ],
     }
 }
@@ -3205,6 +4062,11 @@ fn recipe_for_disaster_another_cooks_quest() -> Quest {
         id: QuestId::RECIPE_FOR_DISASTER_ANOTHER_COOKS_QUEST,
         name: "Recipe for Disaster: Another Cook's Quest".to_string(),
         quest_points: 1,
+        difficulty: QuestDifficulty::Novice,
+        length: QuestLength::Short,
+        members: true,
+        series: None,
+        release_date: ReleaseDate::new(2006, 5, 16),
         requirements: IndexSet::from_iter([
             quest_req(QuestId::COOKS_ASSISTANT),
             skill_req(Skill::Cooking, Level(10)),
@@ -3219,13 +4081,18 @@ fn recipe_for_disaster_freeing_the_mountain_dwarf() -> Quest {
         id: QuestId::RECIPE_FOR_DISASTER_FREEING_THE_MOUNTAIN_DWARF,
         name: "Recipe for Disaster: Freeing the Mountain Dwarf".to_string(),
         quest_points: 1,
+        difficulty: QuestDifficulty::Novice,
+        length: QuestLength::Short,
+        members: true,
+        series: None,
+        release_date: ReleaseDate::new(2006, 5, 16),
         requirements: IndexSet::from_iter([
             quest_req(QuestId::RECIPE_FOR_DISASTER_ANOTHER_COOKS_QUEST),
             quest_req(QuestId::FISHING_CONTEST),
         ]),
         rewards: vec![
-            xp_reward(Skill::Cooking, Xp(1_000.)),
-            xp_reward(Skill::Slayer, Xp(1_000.)),
+            xp_reward(Skill::Cooking, Xp::new(1_000)),
+            xp_reward(Skill::Slayer, Xp::new(1_000)),
         ],
     }
 }
@@ -3236,14 +4103,19 @@ fn recipe_for_disaster_freeing_the_goblin_generals() -> Quest {
         id: QuestId::RECIPE_FOR_DISASTER_FREEING_THE_GOBLIN_GENERALS,
         name: "Recipe for Disaster: Freeing the Goblin Generals".to_string(),
         quest_points: 1,
+        difficulty: QuestDifficulty::Novice,
+        length: QuestLength::Short,
+        members: true,
+        series: None,
+        release_date: ReleaseDate::new(2006, 5, 16),
         requirements: IndexSet::from_iter([
             quest_req(QuestId::RECIPE_FOR_DISASTER_ANOTHER_COOKS_QUEST),
             quest_req(QuestId::GOBLIN_DIPLOMACY),
         ]),
         rewards: vec![
-            xp_reward(Skill::Cooking, Xp(1_000.)),
-            xp_reward(Skill::Farming, Xp(1_000.)),
-            xp_reward(Skill::Crafting, Xp(1_000.)),
+            xp_reward(Skill::Cooking, Xp::new(1_000)),
+            xp_reward(Skill::Farming, Xp::new(1_000)),
+            xp_reward(Skill::Crafting, Xp::new(1_000)),
         ],
     }
 }
@@ -3254,15 +4126,20 @@ fn recipe_for_disaster_freeing_pirate_pete() -> Quest {
         id: QuestId::RECIPE_FOR_DISASTER_FREEING_PIRATE_PETE,
         name: "Recipe for Disaster: Freeing Pirate Pete".to_string(),
         quest_points: 1,
+        difficulty: QuestDifficulty::Intermediate,
+        length: QuestLength::Medium,
+        members: true,
+        series: None,
+        release_date: ReleaseDate::new(2006, 5, 16),
         requirements: IndexSet::from_iter([
             quest_req(QuestId::RECIPE_FOR_DISASTER_ANOTHER_COOKS_QUEST),
             skill_req(Skill::Cooking, Level(31)),
         ]),
         rewards: vec![
-            xp_reward(Skill::Cooking, Xp(1_000.)),
-            xp_reward(Skill::Crafting, Xp(1_000.)),
-            xp_reward(Skill::Fishing, Xp(1_000.)),
-            xp_reward(Skill::Smithing, Xp(1_000.)),
+            xp_reward(Skill::Cooking, Xp::new(1_000)),
+            xp_reward(Skill::Crafting, Xp::new(1_000)),
+            xp_reward(Skill::Fishing, Xp::new(1_000)),
+            xp_reward(Skill::Smithing, Xp::new(1_000)),
         ],
     }
 }
@@ -3273,6 +4150,11 @@ fn recipe_for_disaster_freeing_the_lumbridge_guide() -> Quest {
         id: QuestId::RECIPE_FOR_DISASTER_FREEING_THE_LUMBRIDGE_GUIDE,
         name: "Recipe for Disaster: Freeing the Lumbridge Guide".to_string(),
         quest_points: 1,
+        difficulty: QuestDifficulty::Experienced,
+        length: QuestLength::Short,
+        members: true,
+        series: None,
+        release_date: ReleaseDate::new(2006, 5, 16),
         requirements: IndexSet::from_iter([
             quest_req(QuestId::RECIPE_FOR_DISASTER_ANOTHER_COOKS_QUEST),
             quest_req(QuestId::BIG_CHOMPY_BIRD_HUNTING),
@@ -3284,8 +4166,8 @@ fn recipe_for_disaster_freeing_the_lumbridge_guide() -> Quest {
             skill_req(Skill::Cooking, Level(40)),
         ]),
         rewards: vec![
-            xp_reward(Skill::Cooking, Xp(2_500.)),
-            xp_reward(Skill::Magic, Xp(2_500.)),
+            xp_reward(Skill::Cooking, Xp::new(2_500)),
+            xp_reward(Skill::Magic, Xp::new(2_500)),
         ],
     }
 }
@@ -3296,13 +4178,18 @@ fn recipe_for_disaster_freeing_evil_dave() -> Quest {
         id: QuestId::RECIPE_FOR_DISASTER_FREEING_EVIL_DAVE,
         name: "Recipe for Disaster: Freeing Evil Dave".to_string(),
         quest_points: 1,
+        difficulty: QuestDifficulty::Experienced,
+        length: QuestLength::Short,
+        members: true,
+        series: None,
+        release_date: ReleaseDate::new(2006, 5, 16),
         requirements: IndexSet::from_iter([
             quest_req(QuestId::RECIPE_FOR_DISASTER_ANOTHER_COOKS_QUEST),
             quest_req(QuestId::GERTRUDES_CAT),
             quest_req(QuestId::SHADOW_OF_THE_STORM),
             skill_req(Skill::Cooking, Level(25)),
         ]),
-        rewards: vec![xp_reward(Skill::Cooking, Xp(7_000.))],
+        rewards: vec![xp_reward(Skill::Cooking, Xp::new(7_000))],
     }
 }
 
@@ -3312,6 +4199,11 @@ fn recipe_for_disaster_freeing_skrach_uglogwee() -> Quest {
         id: QuestId::RECIPE_FOR_DISASTER_FREEING_SKRACH_UGLOGWEE,
         name: "Recipe for Disaster: Freeing Skrach Uglogwee".to_string(),
         quest_points: 1,
+        difficulty: QuestDifficulty::Intermediate,
+        length: QuestLength::Short,
+        members: true,
+        series: None,
+        release_date: ReleaseDate::new(2006, 5, 16),
         requirements: IndexSet::from_iter([
             quest_req(QuestId::RECIPE_FOR_DISASTER_ANOTHER_COOKS_QUEST),
             quest_req(QuestId::BIG_CHOMPY_BIRD_HUNTING),
@@ -3319,10 +4211,10 @@ fn recipe_for_disaster_freeing_skrach_uglogwee() -> Quest {
             skill_req(Skill::Firemaking, Level(20)),
         ]),
         rewards: vec![
-            xp_reward(Skill::Woodcutting, Xp(1_500.)),
-            xp_reward(Skill::Cooking, Xp(1_500.)),
-            xp_reward(Skill::Crafting, Xp(1_500.)),
-            xp_reward(Skill::Ranged, Xp(1_500.)),
+            xp_reward(Skill::Woodcutting, Xp::new(1_500)),
+            xp_reward(Skill::Cooking, Xp::new(1_500)),
+            xp_reward(Skill::Crafting, Xp::new(1_500)),
+            xp_reward(Skill::Ranged, Xp::new(1_500)),
         ],
     }
 }
@@ -3333,6 +4225,11 @@ fn recipe_for_disaster_freeing_sir_amik_varze() -> Quest {
         id: QuestId::RECIPE_FOR_DISASTER_FREEING_SIR_AMIK_VARZE,
         name: "Recipe for Disaster: Freeing Sir Amik Varze".to_string(),
         quest_points: 1,
+        difficulty: QuestDifficulty::Experienced,
+        length: QuestLength::Medium,
+        members: true,
+        series: None,
+        release_date: ReleaseDate::new(2006, 5, 16),
         requirements: IndexSet::from_iter([
             quest_point_req(107),
             quest_req(QuestId::RECIPE_FOR_DISASTER_ANOTHER_COOKS_QUEST),
@@ -3343,8 +4240,8 @@ fn recipe_for_disaster_freeing_sir_amik_varze() -> Quest {
             quest_req(QuestId::WATERFALL_QUEST),
         ]),
         rewards: vec![
-            xp_reward(Skill::Cooking, Xp(4_000.)),
-            xp_reward(Skill::Hitpoints, Xp(4_000.)),
+            xp_reward(Skill::Cooking, Xp::new(4_000)),
+            xp_reward(Skill::Hitpoints, Xp::new(4_000)),
         ],
     }
 }
@@ -3355,6 +4252,11 @@ fn recipe_for_disaster_freeing_king_awowogei() -> Quest {
         id: QuestId::RECIPE_FOR_DISASTER_FREEING_KING_AWOWOGEI,
         name: "Recipe for Disaster: Freeing King Awowogei".to_string(),
         quest_points: 1,
+        difficulty: QuestDifficulty::Experienced,
+        length: QuestLength::Medium,
+        members: true,
+        series: None,
+        release_date: ReleaseDate::new(2006, 5, 16),
         requirements: IndexSet::from_iter([
             quest_req(QuestId::RECIPE_FOR_DISASTER_ANOTHER_COOKS_QUEST),
             quest_req(QuestId::MONKEY_MADNESS_I),
@@ -3362,8 +4264,8 @@ fn recipe_for_disaster_freeing_king_awowogei() -> Quest {
             skill_req(Skill::Agility, Level(48)),
         ]),
         rewards: vec![
-            xp_reward(Skill::Cooking, Xp(10_000.)),
-            xp_reward(Skill::Agility, Xp(10_000.)),
+            xp_reward(Skill::Cooking, Xp::new(10_000)),
+            xp_reward(Skill::Agility, Xp::new(10_000)),
         ],
     }
 }
@@ -3374,14 +4276,19 @@ fn recruitment_drive() -> Quest {
         id: QuestId::RECRUITMENT_DRIVE,
         name: "Recruitment Drive".to_string(),
         quest_points: 1,
+        difficulty: QuestDifficulty::Novice,
+        length: QuestLength::Short,
+        members: true,
+        series: Some(QuestSeries::TempleKnight),
+        release_date: ReleaseDate::new(2005, 3, 22),
         requirements: IndexSet::from_iter([
             quest_req(QuestId::BLACK_KNIGHTS_FORTRESS),
             quest_req(QuestId::DRUIDIC_RITUAL),
         ]),
         rewards: vec![
-            xp_reward(Skill::Prayer, Xp(1_000.5)),
-            xp_reward(Skill::Herblore, Xp(1_000.5)),
-            xp_reward(Skill::Agility, Xp(1_000.5)),
+            xp_reward(Skill::Prayer, Xp::from_tenths(10_005)),
+            xp_reward(Skill::Herblore, Xp::from_tenths(10_005)),
+            xp_reward(Skill::Agility, Xp::from_tenths(10_005)),
         ],
     }
 }
@@ -3392,12 +4299,20 @@ fn regicide() -> Quest {
         id: QuestId::REGICIDE,
         name: "Regicide".to_string(),
         quest_points: 3,
+        difficulty: QuestDifficulty::Master,
+        length: QuestLength::Long,
+        members: true,
+        series: Some(QuestSeries::Elf),
+        release_date: ReleaseDate::new(2004, 5, 25),
         requirements: IndexSet::from_iter([
             quest_req(QuestId::UNDERGROUND_PASS),
             skill_req(Skill::Crafting, Level(10)),
             skill_req(Skill::Agility, Level(56)),
         ]),
-        rewards: vec![xp_reward(Skill::Agility, Xp(13_750.))],
+        rewards: vec![
+            xp_reward(Skill::Agility, Xp::new(13_750)),
+            unlock_reward(UnlockKind::Area, "Tirannwn"),
+        ],
     }
 }
 
@@ -3407,11 +4322,16 @@ fn the_ribbiting_tale_of_a_lily_pad_labour_dispute() -> Quest {
         id: QuestId::THE_RIBBITING_TALE_OF_A_LILY_PAD_LABOUR_DISPUTE,
         name: "The Ribbiting Tale of a Lily Pad Labour Dispute".to_string(),
         quest_points: 1,
+        difficulty: QuestDifficulty::Novice,
+        length: QuestLength::Short,
+        members: true,
+        series: None,
+        release_date: ReleaseDate::new(2024, 3, 20),
         requirements: IndexSet::from_iter([
             quest_req(QuestId::CHILDREN_OF_THE_SUN),
             skill_req(Skill::Woodcutting, Level(15)),
         ]),
-        rewards: vec![xp_reward(Skill::Woodcutting, Xp(2_000.))],
+        rewards: vec![xp_reward(Skill::Woodcutting, Xp::new(2_000))],
     }
 }
 
@@ -3421,11 +4341,16 @@ fn roving_elves() -> Quest {
         id: QuestId::ROVING_ELVES,
         name: "Roving Elves".to_string(),
         quest_points: 1,
+        difficulty: QuestDifficulty::Experienced,
+        length: QuestLength::Medium,
+        members: true,
+        series: Some(QuestSeries::Elf),
+        release_date: ReleaseDate::new(2004, 8, 24),
         requirements: IndexSet::from_iter([
             quest_req(QuestId::REGICIDE),
             skill_req(Skill::Agility, Level(56)),
         ]),
-        rewards: vec![xp_reward(Skill::Strength, Xp(10_000.))],
+        rewards: vec![xp_reward(Skill::Strength, Xp::new(10_000))],
     }
 }
 
@@ -3435,15 +4360,20 @@ fn royal_trouble() -> Quest {
         id: QuestId::ROYAL_TROUBLE,
         name: "Royal Trouble".to_string(),
         quest_points: 1,
+        difficulty: QuestDifficulty::Experienced,
+        length: QuestLength::Medium,
+        members: true,
+        series: Some(QuestSeries::Fremennik),
+        release_date: ReleaseDate::new(2006, 6, 13),
         requirements: IndexSet::from_iter([
             quest_req(QuestId::THRONE_OF_MISCELLANIA),
             skill_req(Skill::Agility, Level(40)),
             skill_req(Skill::Slayer, Level(40)),
         ]),
         rewards: vec![
-            xp_reward(Skill::Agility, Xp(5_000.)),
-            xp_reward(Skill::Slayer, Xp(5_000.)),
-            xp_reward(Skill::Hitpoints, Xp(5_000.)),
+            xp_reward(Skill::Agility, Xp::new(5_000)),
+            xp_reward(Skill::Slayer, Xp::new(5_000)),
+            xp_reward(Skill::Hitpoints, Xp::new(5_000)),
         ],
     }
 }
@@ -3454,19 +4384,24 @@ fn rum_deal() -> Quest {
         id: QuestId::RUM_DEAL,
         name: "Rum Deal".to_string(),
         quest_points: 2,
+        difficulty: QuestDifficulty::Experienced,
+        length: QuestLength::Medium,
+        members: true,
+        series: Some(QuestSeries::Pirate),
+        release_date: ReleaseDate::new(2005, 7, 11),
         requirements: IndexSet::from_iter([
             quest_req(QuestId::ZOGRE_FLESH_EATERS),
             quest_req(QuestId::PRIEST_IN_PERIL),
             skill_req(Skill::Crafting, Level(42)),
             skill_req(Skill::Fishing, Level(50)),
             skill_req(Skill::Farming, Level(40)),
-            skill_req(Skill::Prayer, Level(47)),
+            skill_req_unboostable(Skill::Prayer, Level(47)),
             skill_req(Skill::Slayer, Level(42)),
         ]),
         rewards: vec![
-            xp_reward(Skill::Fishing, Xp(7_000.)),
-            xp_reward(Skill::Prayer, Xp(7_000.)),
-            xp_reward(Skill::Farming, Xp(7_000.)),
+            xp_reward(Skill::Fishing, Xp::new(7_000)),
+            xp_reward(Skill::Prayer, Xp::new(7_000)),
+            xp_reward(Skill::Farming, Xp::new(7_000)),
         ],
     }
 }
@@ -3477,11 +4412,16 @@ fn scorpion_catcher() -> Quest {
         id: QuestId::SCORPION_CATCHER,
         name: "Scorpion Catcher".to_string(),
         quest_points: 1,
+        difficulty: QuestDifficulty::Intermediate,
+        length: QuestLength::Short,
+        members: true,
+        series: None,
+        release_date: ReleaseDate::new(2002, 2, 11),
         requirements: IndexSet::from_iter([
-            skill_req(Skill::Prayer, Level(31)),
-            // TODO: Alfred Grimhand's Barcrawl requirement.
+            skill_req_unboostable(Skill::Prayer, Level(31)),
+            miniquest_req(MiniquestId::ALFRED_GRIMHANDS_BARCRAWL),
         ]),
-        rewards: vec![xp_reward(Skill::Strength, Xp(6_625.))],
+        rewards: vec![xp_reward(Skill::Strength, Xp::new(6_625))],
     }
 }
 
@@ -3491,6 +4431,11 @@ fn scrambled() -> Quest {
         id: QuestId::SCRAMBLED,
         name: "Scrambled!".to_string(),
         quest_points: 1,
+        difficulty: QuestDifficulty::Novice,
+        length: QuestLength::Short,
+        members: true,
+        series: None,
+        release_date: ReleaseDate::new(2025, 7, 23),
         requirements: IndexSet::from_iter([
             quest_req(QuestId::CHILDREN_OF_THE_SUN),
             skill_req(Skill::Construction, Level(38)),
@@ -3498,9 +4443,9 @@ fn scrambled() -> Quest {
             skill_req(Skill::Smithing, Level(35)),
         ]),
         rewards: vec![
-            xp_reward(Skill::Construction, Xp(5_000.)),
-            xp_reward(Skill::Cooking, Xp(5_000.)),
-            xp_reward(Skill::Smithing, Xp(5_000.)),
+            xp_reward(Skill::Construction, Xp::new(5_000)),
+            xp_reward(Skill::Cooking, Xp::new(5_000)),
+            xp_reward(Skill::Smithing, Xp::new(5_000)),
         ],
     }
 }
@@ -3511,8 +4456,13 @@ fn sea_slug() -> Quest {
         id: QuestId::SEA_SLUG,
         name: "Sea Slug".to_string(),
         quest_points: 1,
+        difficulty: QuestDifficulty::Intermediate,
+        length: QuestLength::Medium,
+        members: true,
+        series: Some(QuestSeries::TempleKnight),
+        release_date: ReleaseDate::new(2004, 3, 29),
         requirements: IndexSet::from_iter([skill_req(Skill::Firemaking, Level(30))]),
-        rewards: vec![xp_reward(Skill::Fishing, Xp(7_175.))],
+        rewards: vec![xp_reward(Skill::Fishing, Xp::new(7_175))],
     }
 }
 
@@ -3522,19 +4472,24 @@ fn secrets_of_the_north() -> Quest {
         id: QuestId::SECRETS_OF_THE_NORTH,
         name: "Secrets of the North".to_string(),
         quest_points: 2,
+        difficulty: QuestDifficulty::Experienced,
+        length: QuestLength::Medium,
+        members: true,
+        series: Some(QuestSeries::Mahjarrat),
+        release_date: ReleaseDate::new(2022, 11, 16),
         requirements: IndexSet::from_iter([
             quest_req(QuestId::MAKING_FRIENDS_WITH_MY_ARM),
             quest_req(QuestId::DEVIOUS_MINDS),
-            // TODO: The General's Shadow miniquest requirement.
+            miniquest_req(MiniquestId::THE_GENERALS_SHADOW),
             quest_req(QuestId::HAZEEL_CULT),
             skill_req(Skill::Agility, Level(69)),
             skill_req(Skill::Thieving, Level(64)),
             skill_req(Skill::Hunter, Level(56)),
         ]),
         rewards: vec![
-            xp_reward(Skill::Agility, Xp(60_000.)),
-            xp_reward(Skill::Thieving, Xp(50_000.)),
-            xp_reward(Skill::Hunter, Xp(40_000.)),
+            xp_reward(Skill::Agility, Xp::new(60_000)),
+            xp_reward(Skill::Thieving, Xp::new(50_000)),
+            xp_reward(Skill::Hunter, Xp::new(40_000)),
         ],
     }
 }
@@ -3545,6 +4500,11 @@ fn shades_of_mortton() -> Quest {
         id: QuestId::SHADES_OF_MORTTON,
         name: "Shades of Mort'ton".to_string(),
         quest_points: 3,
+        difficulty: QuestDifficulty::Intermediate,
+        length: QuestLength::Medium,
+        members: true,
+        series: None,
+        release_date: ReleaseDate::new(2004, 4, 26),
         requirements: IndexSet::from_iter([
             quest_req(QuestId::PRIEST_IN_PERIL),
             skill_req(Skill::Crafting, Level(20)),
@@ -3552,8 +4512,8 @@ fn shades_of_mortton() -> Quest {
             skill_req(Skill::Firemaking, Level(5)),
         ]),
         rewards: vec![
-            xp_reward(Skill::Herblore, Xp(2_000.)),
-            xp_reward(Skill::Crafting, Xp(2_000.)),
+            xp_reward(Skill::Herblore, Xp::new(2_000)),
+            xp_reward(Skill::Crafting, Xp::new(2_000)),
         ],
     }
 }
@@ -3564,14 +4524,22 @@ fn shadow_of_the_storm() -> Quest {
         id: QuestId::SHADOW_OF_THE_STORM,
         name: "Shadow of the Storm".to_string(),
         quest_points: 1,
+        difficulty: QuestDifficulty::Intermediate,
+        length: QuestLength::Medium,
+        members: true,
+        series: Some(QuestSeries::Desert),
+        release_date: ReleaseDate::new(2005, 8, 16),
         requirements: IndexSet::from_iter([
             quest_req(QuestId::THE_GOLEM),
             quest_req(QuestId::DEMON_SLAYER),
             skill_req(Skill::Crafting, Level(30)),
         ]),
-        rewards: vec![
-            // TODO: 10,000 experience in any combat skill other than Prayer.
-        ],
+        rewards: vec![lamp_reward(
+            Xp::new(10_000),
+            COMBAT_SKILLS_EXCEPT_PRAYER,
+            None,
+            1,
+        )],
     }
 }
 
@@ -3581,6 +4549,11 @@ fn shadows_of_custodia() -> Quest {
         id: QuestId::SHADOWS_OF_CUSTODIA,
         name: "Shadows of Custodia".to_string(),
         quest_points: 2,
+        difficulty: QuestDifficulty::Intermediate,
+        length: QuestLength::Medium,
+        members: true,
+        series: None,
+        release_date: ReleaseDate::new(2025, 7, 23),
         requirements: IndexSet::from_iter([
             quest_req(QuestId::CHILDREN_OF_THE_SUN),
             skill_req(Skill::Slayer, Level(54)),
@@ -3589,10 +4562,10 @@ fn shadows_of_custodia() -> Quest {
             skill_req(Skill::Hunter, Level(36)),
         ]),
         rewards: vec![
-            xp_reward(Skill::Slayer, Xp(10_000.)),
-            xp_reward(Skill::Hunter, Xp(4_000.)),
-            xp_reward(Skill::Fishing, Xp(3_000.)),
-            xp_reward(Skill::Construction, Xp(3_000.)),
+            xp_reward(Skill::Slayer, Xp::new(10_000)),
+            xp_reward(Skill::Hunter, Xp::new(4_000)),
+            xp_reward(Skill::Fishing, Xp::new(3_000)),
+            xp_reward(Skill::Construction, Xp::new(3_000)),
         ],
     }
 }
@@ -3603,6 +4576,11 @@ fn sheep_herder() -> Quest {
         id: QuestId::SHEEP_HERDER,
         name: "Sheep Herder".to_string(),
         quest_points: 4,
+        difficulty: QuestDifficulty::Novice,
+        length: QuestLength::Short,
+        members: true,
+        series: None,
+        release_date: ReleaseDate::new(2002, 1, 21),
         requirements: IndexSet::new(),
         rewards: Vec::new(),
     }
@@ -3614,12 +4592,17 @@ fn shilo_village() -> Quest {
         id: QuestId::SHILO_VILLAGE,
         name: "Shilo Village".to_string(),
         quest_points: 2,
+        difficulty: QuestDifficulty::Experienced,
+        length: QuestLength::Medium,
+        members: true,
+        series: None,
+        release_date: ReleaseDate::new(2002, 5, 7),
         requirements: IndexSet::from_iter([
             quest_req(QuestId::JUNGLE_POTION),
             skill_req(Skill::Crafting, Level(20)),
             skill_req(Skill::Agility, Level(32)),
         ]),
-        rewards: vec![xp_reward(Skill::Crafting, Xp(3_875.))],
+        rewards: vec![xp_reward(Skill::Crafting, Xp::new(3_875))],
     }
 }
 
@@ -3629,6 +4612,11 @@ fn sins_of_the_father() -> Quest {
         id: QuestId::SINS_OF_THE_FATHER,
         name: "Sins of the Father".to_string(),
         quest_points: 2,
+        difficulty: QuestDifficulty::Master,
+        length: QuestLength::Long,
+        members: true,
+        series: Some(QuestSeries::Myreque),
+        release_date: ReleaseDate::new(2020, 12, 10),
         requirements: IndexSet::from_iter([
             quest_req(QuestId::VAMPYRE_SLAYER),
             quest_req(QuestId::A_TASTE_OF_HOPE),
@@ -3641,7 +4629,8 @@ fn sins_of_the_father() -> Quest {
             skill_req(Skill::Magic, Level(49)),
         ]),
         rewards: vec![
-            // TODO: 6x tome of experience (15,000 XP to any skill level 60+).
+            lamp_reward(Xp::new(15_000), Skill::iter(), Some(Level(60)), 6),
+            unlock_reward(UnlockKind::Area, "Darkmeyer"),
         ],
     }
 }
@@ -3652,8 +4641,13 @@ fn sleeping_giants() -> Quest {
         id: QuestId::SLEEPING_GIANTS,
         name: "Sleeping Giants".to_string(),
         quest_points: 1,
+        difficulty: QuestDifficulty::Novice,
+        length: QuestLength::Short,
+        members: true,
+        series: None,
+        release_date: ReleaseDate::new(2021, 6, 3),
         requirements: IndexSet::from_iter([skill_req(Skill::Smithing, Level(15))]),
-        rewards: vec![xp_reward(Skill::Smithing, Xp(6_000.))],
+        rewards: vec![xp_reward(Skill::Smithing, Xp::new(6_000))],
     }
 }
 
@@ -3663,6 +4657,11 @@ fn the_slug_menace() -> Quest {
         id: QuestId::THE_SLUG_MENACE,
         name: "The Slug Menace".to_string(),
         quest_points: 1,
+        difficulty: QuestDifficulty::Intermediate,
+        length: QuestLength::Medium,
+        members: true,
+        series: Some(QuestSeries::TempleKnight),
+        release_date: ReleaseDate::new(2006, 6, 27),
         requirements: IndexSet::from_iter([
             quest_req(QuestId::WANTED),
             quest_req(QuestId::SEA_SLUG),
@@ -3672,9 +4671,9 @@ fn the_slug_menace() -> Quest {
             skill_req(Skill::Thieving, Level(30)),
         ]),
         rewards: vec![
-            xp_reward(Skill::Crafting, Xp(3_500.)),
-            xp_reward(Skill::Runecraft, Xp(3_500.)),
-            xp_reward(Skill::Thieving, Xp(3_500.)),
+            xp_reward(Skill::Crafting, Xp::new(3_500)),
+            xp_reward(Skill::Runecraft, Xp::new(3_500)),
+            xp_reward(Skill::Thieving, Xp::new(3_500)),
         ],
     }
 }
@@ -3685,6 +4684,11 @@ fn song_of_the_elves() -> Quest {
         id: QuestId::SONG_OF_THE_ELVES,
         name: "Song of the Elves".to_string(),
         quest_points: 4,
+        difficulty: QuestDifficulty::Grandmaster,
+        length: QuestLength::VeryLong,
+        members: true,
+        series: Some(QuestSeries::Elf),
+        release_date: ReleaseDate::new(2019, 7, 25),
         requirements: IndexSet::from_iter([
             quest_req(QuestId::MOURNINGS_END_PART_II),
             skill_req(Skill::Agility, Level(70)),
@@ -3697,14 +4701,15 @@ fn song_of_the_elves() -> Quest {
             skill_req(Skill::Woodcutting, Level(70)),
         ]),
         rewards: vec![
-            xp_reward(Skill::Agility, Xp(40_000.)),
-            xp_reward(Skill::Construction, Xp(40_000.)),
-            xp_reward(Skill::Farming, Xp(40_000.)),
-            xp_reward(Skill::Herblore, Xp(40_000.)),
-            xp_reward(Skill::Hunter, Xp(40_000.)),
-            xp_reward(Skill::Mining, Xp(40_000.)),
-            xp_reward(Skill::Smithing, Xp(40_000.)),
-            xp_reward(Skill::Woodcutting, Xp(40_000.)),
+            xp_reward(Skill::Agility, Xp::new(40_000)),
+            xp_reward(Skill::Construction, Xp::new(40_000)),
+            xp_reward(Skill::Farming, Xp::new(40_000)),
+            xp_reward(Skill::Herblore, Xp::new(40_000)),
+            xp_reward(Skill::Hunter, Xp::new(40_000)),
+            xp_reward(Skill::Mining, Xp::new(40_000)),
+            xp_reward(Skill::Smithing, Xp::new(40_000)),
+            xp_reward(Skill::Woodcutting, Xp::new(40_000)),
+            unlock_reward(UnlockKind::Area, "Prifddinas"),
         ],
     }
 }
@@ -3715,10 +4720,15 @@ fn a_souls_bane() -> Quest {
         id: QuestId::A_SOULS_BANE,
         name: "A Soul's Bane".to_string(),
         quest_points: 1,
+        difficulty: QuestDifficulty::Novice,
+        length: QuestLength::Medium,
+        members: true,
+        series: None,
+        release_date: ReleaseDate::new(2005, 9, 12),
         requirements: IndexSet::new(),
         rewards: vec![
-            xp_reward(Skill::Defence, Xp(500.)),
-            xp_reward(Skill::Hitpoints, Xp(500.)),
+            xp_reward(Skill::Defence, Xp::new(500)),
+            xp_reward(Skill::Hitpoints, Xp::new(500)),
         ],
     }
 }
@@ -3729,6 +4739,11 @@ fn spirits_of_the_elid() -> Quest {
         id: QuestId::SPIRITS_OF_THE_ELID,
         name: "Spirits of the Elid".to_string(),
         quest_points: 2,
+        difficulty: QuestDifficulty::Intermediate,
+        length: QuestLength::Medium,
+        members: true,
+        series: Some(QuestSeries::Desert),
+        release_date: ReleaseDate::new(2005, 10, 24),
         requirements: IndexSet::from_iter([
             skill_req(Skill::Magic, Level(33)),
             skill_req(Skill::Ranged, Level(37)),
@@ -3736,9 +4751,9 @@ fn spirits_of_the_elid() -> Quest {
             skill_req(Skill::Thieving, Level(37)),
         ]),
         rewards: vec![
-            xp_reward(Skill::Prayer, Xp(8_000.)),
-            xp_reward(Skill::Thieving, Xp(1_000.)),
-            xp_reward(Skill::Magic, Xp(1_000.)),
+            xp_reward(Skill::Prayer, Xp::new(8_000)),
+            xp_reward(Skill::Thieving, Xp::new(1_000)),
+            xp_reward(Skill::Magic, Xp::new(1_000)),
         ],
     }
 }
@@ -3749,6 +4764,11 @@ fn swan_song() -> Quest {
         id: QuestId::SWAN_SONG,
         name: "Swan Song".to_string(),
         quest_points: 2,
+        difficulty: QuestDifficulty::Master,
+        length: QuestLength::Medium,
+        members: true,
+        series: None,
+        release_date: ReleaseDate::new(2006, 10, 24),
         requirements: IndexSet::from_iter([
             quest_point_req(100),
             quest_req(QuestId::ONE_SMALL_FAVOUR),
@@ -3761,9 +4781,9 @@ fn swan_song() -> Quest {
             skill_req(Skill::Crafting, Level(40)),
         ]),
         rewards: vec![
-            xp_reward(Skill::Magic, Xp(15_000.)),
-            xp_reward(Skill::Prayer, Xp(10_000.)),
-            xp_reward(Skill::Fishing, Xp(50_000.)),
+            xp_reward(Skill::Magic, Xp::new(15_000)),
+            xp_reward(Skill::Prayer, Xp::new(10_000)),
+            xp_reward(Skill::Fishing, Xp::new(50_000)),
         ],
     }
 }
@@ -3774,6 +4794,11 @@ fn tai_bwo_wannai_trio() -> Quest {
         id: QuestId::TAI_BWO_WANNAI_TRIO,
         name: "Tai Bwo Wannai Trio".to_string(),
         quest_points: 2,
+        difficulty: QuestDifficulty::Intermediate,
+        length: QuestLength::Long,
+        members: true,
+        series: None,
+        release_date: ReleaseDate::new(2003, 10, 21),
         requirements: IndexSet::from_iter([
             quest_req(QuestId::JUNGLE_POTION),
             skill_req(Skill::Agility, Level(15)),
@@ -3781,10 +4806,10 @@ fn tai_bwo_wannai_trio() -> Quest {
             skill_req(Skill::Fishing, Level(5)),
         ]),
         rewards: vec![
-            xp_reward(Skill::Cooking, Xp(5_000.)),
-            xp_reward(Skill::Fishing, Xp(5_000.)),
-            xp_reward(Skill::Attack, Xp(2_500.)),
-            xp_reward(Skill::Strength, Xp(2_500.)),
+            xp_reward(Skill::Cooking, Xp::new(5_000)),
+            xp_reward(Skill::Fishing, Xp::new(5_000)),
+            xp_reward(Skill::Attack, Xp::new(2_500)),
+            xp_reward(Skill::Strength, Xp::new(2_500)),
         ],
     }
 }
@@ -3795,7 +4820,15 @@ fn a_tail_of_two_cats() -> Quest {
         id: QuestId::A_TAIL_OF_TWO_CATS,
         name: "A Tail of Two Cats".to_string(),
         quest_points: 2,
-        requirements: IndexSet::from_iter([quest_req(QuestId::ICTHLARINS_LITTLE_HELPER)]),
+        difficulty: QuestDifficulty::Intermediate,
+        length: QuestLength::Medium,
+        members: true,
+        series: None,
+        release_date: ReleaseDate::new(2005, 1, 25),
+        requirements: IndexSet::from_iter([
+            quest_req(QuestId::ICTHLARINS_LITTLE_HELPER),
+            item_req("Pet cat", 1),
+        ]),
         rewards: Vec::new(),
     }
 }
@@ -3806,6 +4839,11 @@ fn tale_of_the_righteous() -> Quest {
         id: QuestId::TALE_OF_THE_RIGHTEOUS,
         name: "Tale of the Righteous".to_string(),
         quest_points: 1,
+        difficulty: QuestDifficulty::Novice,
+        length: QuestLength::Short,
+        members: true,
+        series: Some(QuestSeries::GreatKourend),
+        release_date: ReleaseDate::new(2018, 7, 26),
         requirements: IndexSet::from_iter([
             quest_req(QuestId::CLIENT_OF_KOUREND),
             skill_req(Skill::Strength, Level(16)),
@@ -3821,6 +4859,11 @@ fn a_taste_of_hope() -> Quest {
         id: QuestId::A_TASTE_OF_HOPE,
         name: "A Taste of Hope".to_string(),
         quest_points: 1,
+        difficulty: QuestDifficulty::Experienced,
+        length: QuestLength::Long,
+        members: true,
+        series: Some(QuestSeries::Myreque),
+        release_date: ReleaseDate::new(2019, 5, 30),
         requirements: IndexSet::from_iter([
             quest_req(QuestId::DARKNESS_OF_HALLOWVALE),
             skill_req(Skill::Crafting, Level(48)),
@@ -3829,9 +4872,12 @@ fn a_taste_of_hope() -> Quest {
             skill_req(Skill::Herblore, Level(40)),
             skill_req(Skill::Slayer, Level(38)),
         ]),
-        rewards: vec![
-            // TODO: Figure out how to model tome of experience.
-        ],
+        rewards: vec![lamp_reward(
+            Xp::new(2_500),
+            Skill::iter(),
+            Some(Level(35)),
+            3,
+        )],
     }
 }
 
@@ -3841,13 +4887,18 @@ fn tears_of_guthix() -> Quest {
         id: QuestId::TEARS_OF_GUTHIX,
         name: "Tears of Guthix".to_string(),
         quest_points: 1,
+        difficulty: QuestDifficulty::Intermediate,
+        length: QuestLength::Short,
+        members: true,
+        series: None,
+        release_date: ReleaseDate::new(2005, 2, 8),
         requirements: IndexSet::from_iter([
             quest_point_req(43),
             skill_req(Skill::Firemaking, Level(49)),
             skill_req(Skill::Crafting, Level(20)),
             skill_req(Skill::Mining, Level(20)),
         ]),
-        rewards: vec![xp_reward(Skill::Crafting, Xp(1_000.))],
+        rewards: vec![xp_reward(Skill::Crafting, Xp::new(1_000))],
     }
 }
 
@@ -3857,13 +4908,18 @@ fn temple_of_ikov() -> Quest {
         id: QuestId::TEMPLE_OF_IKOV,
         name: "Temple of Ikov".to_string(),
         quest_points: 1,
+        difficulty: QuestDifficulty::Experienced,
+        length: QuestLength::Medium,
+        members: true,
+        series: Some(QuestSeries::Mahjarrat),
+        release_date: ReleaseDate::new(2001, 12, 3),
         requirements: IndexSet::from_iter([
             skill_req(Skill::Thieving, Level(42)),
             skill_req(Skill::Ranged, Level(40)),
         ]),
         rewards: vec![
-            xp_reward(Skill::Ranged, Xp(10_500.)),
-            xp_reward(Skill::Fletching, Xp(8_000.)),
+            xp_reward(Skill::Ranged, Xp::new(10_500)),
+            xp_reward(Skill::Fletching, Xp::new(8_000)),
         ],
     }
 }
@@ -3874,12 +4930,17 @@ fn temple_of_the_eye() -> Quest {
         id: QuestId::TEMPLE_OF_THE_EYE,
         name: "Temple of the Eye".to_string(),
         quest_points: 1,
+        difficulty: QuestDifficulty::Intermediate,
+        length: QuestLength::Medium,
+        members: true,
+        series: None,
+        release_date: ReleaseDate::new(2021, 8, 11),
         requirements: IndexSet::from_iter([
-            // TODO: Figure out how to handle Enter the Abyss requirement.
+            miniquest_req(MiniquestId::ENTER_THE_ABYSS),
             quest_req(QuestId::RUNE_MYSTERIES),
             skill_req(Skill::Runecraft, Level(10)),
         ]),
-        rewards: vec![xp_reward(Skill::Runecraft, Xp(5_000.))],
+        rewards: vec![xp_reward(Skill::Runecraft, Xp::new(5_000))],
     }
 }
 
@@ -3889,6 +4950,11 @@ fn throne_of_miscellania() -> Quest {
         id: QuestId::THRONE_OF_MISCELLANIA,
         name: "Throne of Miscellania".to_string(),
         quest_points: 1,
+        difficulty: QuestDifficulty::Experienced,
+        length: QuestLength::Medium,
+        members: true,
+        series: Some(QuestSeries::Fremennik),
+        release_date: ReleaseDate::new(2004, 9, 21),
         requirements: IndexSet::from_iter([
             quest_req(QuestId::HEROES_QUEST),
             quest_req(QuestId::THE_FREMENNIK_TRIALS),
@@ -3906,13 +4972,26 @@ fn the_tourist_trap() -> Quest {
         id: QuestId::THE_TOURIST_TRAP,
         name: "The Tourist Trap".to_string(),
         quest_points: 2,
+        difficulty: QuestDifficulty::Intermediate,
+        length: QuestLength::Medium,
+        members: true,
+        series: Some(QuestSeries::Desert),
+        release_date: ReleaseDate::new(2003, 8, 19),
         requirements: IndexSet::from_iter([
             skill_req(Skill::Fletching, Level(10)),
             skill_req(Skill::Smithing, Level(20)),
         ]),
-        rewards: vec![
-            // TODO: Figure out how to model XP choice.
-        ],
+        rewards: vec![lamp_reward(
+            Xp::new(4_650),
+            [
+                Skill::Agility,
+                Skill::Fletching,
+                Skill::Smithing,
+                Skill::Thieving,
+            ],
+            None,
+            2,
+        )],
     }
 }
 
@@ -3922,11 +5001,16 @@ fn tower_of_life() -> Quest {
         id: QuestId::TOWER_OF_LIFE,
         name: "Tower of Life".to_string(),
         quest_points: 2,
+        difficulty: QuestDifficulty::Novice,
+        length: QuestLength::Medium,
+        members: true,
+        series: None,
+        release_date: ReleaseDate::new(2007, 7, 10),
         requirements: IndexSet::from_iter([skill_req(Skill::Construction, Level(10))]),
         rewards: vec![
-            xp_reward(Skill::Construction, Xp(1_000.)),
-            xp_reward(Skill::Crafting, Xp(500.)),
-            xp_reward(Skill::Thieving, Xp(500.)),
+            xp_reward(Skill::Construction, Xp::new(1_000)),
+            xp_reward(Skill::Crafting, Xp::new(500)),
+            xp_reward(Skill::Thieving, Xp::new(500)),
         ],
     }
 }
@@ -3937,8 +5021,13 @@ fn tree_gnome_village() -> Quest {
         id: QuestId::TREE_GNOME_VILLAGE,
         name: "Tree Gnome Village".to_string(),
         quest_points: 2,
+        difficulty: QuestDifficulty::Intermediate,
+        length: QuestLength::Medium,
+        members: true,
+        series: Some(QuestSeries::Gnome),
+        release_date: ReleaseDate::new(2002, 2, 25),
         requirements: IndexSet::new(),
-        rewards: vec![xp_reward(Skill::Attack, Xp(11_450.))],
+        rewards: vec![xp_reward(Skill::Attack, Xp::new(11_450))],
     }
 }
 
@@ -3948,8 +5037,13 @@ fn tribal_totem() -> Quest {
         id: QuestId::TRIBAL_TOTEM,
         name: "Tribal Totem".to_string(),
         quest_points: 1,
+        difficulty: QuestDifficulty::Intermediate,
+        length: QuestLength::Short,
+        members: true,
+        series: None,
+        release_date: ReleaseDate::new(2002, 1, 21),
         requirements: IndexSet::from_iter([skill_req(Skill::Thieving, Level(21))]),
-        rewards: vec![xp_reward(Skill::Thieving, Xp(1_775.))],
+        rewards: vec![xp_reward(Skill::Thieving, Xp::new(1_775))],
     }
 }
 
@@ -3959,13 +5053,18 @@ fn troll_romance() -> Quest {
         id: QuestId::TROLL_ROMANCE,
         name: "Troll Romance".to_string(),
         quest_points: 2,
+        difficulty: QuestDifficulty::Experienced,
+        length: QuestLength::Long,
+        members: true,
+        series: Some(QuestSeries::Troll),
+        release_date: ReleaseDate::new(2004, 8, 31),
         requirements: IndexSet::from_iter([
             quest_req(QuestId::TROLL_STRONGHOLD),
             skill_req(Skill::Agility, Level(28)),
         ]),
         rewards: vec![
-            xp_reward(Skill::Agility, Xp(8_000.)),
-            xp_reward(Skill::Strength, Xp(4_000.)),
+            xp_reward(Skill::Agility, Xp::new(8_000)),
+            xp_reward(Skill::Strength, Xp::new(4_000)),
         ],
     }
 }
@@ -3976,6 +5075,11 @@ fn troll_stronghold() -> Quest {
         id: QuestId::TROLL_STRONGHOLD,
         name: "Troll Stronghold".to_string(),
         quest_points: 1,
+        difficulty: QuestDifficulty::Experienced,
+        length: QuestLength::Medium,
+        members: true,
+        series: Some(QuestSeries::Troll),
+        release_date: ReleaseDate::new(2003, 5, 19),
         requirements: IndexSet::from_iter([
             quest_req(QuestId::DEATH_PLATEAU),
             skill_req(Skill::Agility, Level(15)),
@@ -3990,6 +5094,11 @@ fn troubled_tortugans() -> Quest {
         id: QuestId::TROUBLED_TORTUGANS,
         name: "Troubled Tortugans".to_string(),
         quest_points: 1,
+        difficulty: QuestDifficulty::Experienced,
+        length: QuestLength::Medium,
+        members: true,
+        series: None,
+        release_date: ReleaseDate::new(2025, 11, 19),
         requirements: IndexSet::from_iter([
             quest_req(QuestId::PANDEMONIUM),
             skill_req(Skill::Slayer, Level(51)),
@@ -4000,8 +5109,8 @@ fn troubled_tortugans() -> Quest {
             skill_req(Skill::Crafting, Level(34)),
         ]),
         rewards: vec![
-            xp_reward(Skill::Sailing, Xp(10_000.)),
-            xp_reward(Skill::Slayer, Xp(8_000.)),
+            xp_reward(Skill::Sailing, Xp::new(10_000)),
+            xp_reward(Skill::Slayer, Xp::new(8_000)),
         ],
     }
 }
@@ -4012,8 +5121,13 @@ fn twilights_promise() -> Quest {
         id: QuestId::TWILIGHTS_PROMISE,
         name: "Twilight's Promise".to_string(),
         quest_points: 1,
+        difficulty: QuestDifficulty::Novice,
+        length: QuestLength::Short,
+        members: true,
+        series: Some(QuestSeries::Varlamore),
+        release_date: ReleaseDate::new(2024, 3, 20),
         requirements: IndexSet::from_iter([quest_req(QuestId::CHILDREN_OF_THE_SUN)]),
-        rewards: vec![xp_reward(Skill::Thieving, Xp(3_000.))],
+        rewards: vec![xp_reward(Skill::Thieving, Xp::new(3_000))],
     }
 }
 
@@ -4023,13 +5137,19 @@ fn underground_pass() -> Quest {
         id: QuestId::UNDERGROUND_PASS,
         name: "Underground Pass".to_string(),
         quest_points: 5,
+        difficulty: QuestDifficulty::Experienced,
+        length: QuestLength::Long,
+        members: true,
+        series: Some(QuestSeries::Elf),
+        release_date: ReleaseDate::new(2003, 3, 10),
         requirements: IndexSet::from_iter([
             quest_req(QuestId::BIOHAZARD),
             skill_req(Skill::Ranged, Level(25)),
         ]),
         rewards: vec![
-            xp_reward(Skill::Agility, Xp(3_000.)),
-            xp_reward(Skill::Attack, Xp(3_000.)),
+            xp_reward(Skill::Agility, Xp::new(3_000)),
+            xp_reward(Skill::Attack, Xp::new(3_000)),
+            unlock_reward(UnlockKind::Spell, "Iban Blast"),
         ],
     }
 }
@@ -4040,14 +5160,19 @@ fn wanted() -> Quest {
         id: QuestId::WANTED,
         name: "Wanted!".to_string(),
         quest_points: 1,
+        difficulty: QuestDifficulty::Intermediate,
+        length: QuestLength::Medium,
+        members: true,
+        series: Some(QuestSeries::TempleKnight),
+        release_date: ReleaseDate::new(2006, 1, 24),
         requirements: IndexSet::from_iter([
             quest_point_req(32),
             quest_req(QuestId::RECRUITMENT_DRIVE),
             quest_req(QuestId::THE_LOST_TRIBE),
             quest_req(QuestId::PRIEST_IN_PERIL),
-            // TODO: Figure out how to model Enter the Abyss requirement.
+            miniquest_req(MiniquestId::ENTER_THE_ABYSS),
         ]),
-        rewards: vec![xp_reward(Skill::Slayer, Xp(5_000.))],
+        rewards: vec![xp_reward(Skill::Slayer, Xp::new(5_000))],
     }
 }
 
@@ -4057,6 +5182,11 @@ fn watchtower() -> Quest {
         id: QuestId::WATCHTOWER,
         name: "Watchtower".to_string(),
         quest_points: 4,
+        difficulty: QuestDifficulty::Intermediate,
+        length: QuestLength::Long,
+        members: true,
+        series: None,
+        release_date: ReleaseDate::new(2002, 6, 18),
         requirements: IndexSet::from_iter([
             skill_req(Skill::Magic, Level(14)),
             skill_req(Skill::Thieving, Level(15)),
@@ -4064,7 +5194,7 @@ fn watchtower() -> Quest {
             skill_req(Skill::Herblore, Level(14)),
             skill_req(Skill::Mining, Level(40)),
         ]),
-        rewards: vec![xp_reward(Skill::Magic, Xp(15_250.))],
+        rewards: vec![xp_reward(Skill::Magic, Xp::new(15_250))],
     }
 }
 
@@ -4074,10 +5204,15 @@ fn waterfall_quest() -> Quest {
         id: QuestId::WATERFALL_QUEST,
         name: "Waterfall Quest".to_string(),
         quest_points: 1,
+        difficulty: QuestDifficulty::Intermediate,
+        length: QuestLength::Short,
+        members: true,
+        series: None,
+        release_date: ReleaseDate::new(2001, 11, 13),
         requirements: IndexSet::new(),
         rewards: vec![
-            xp_reward(Skill::Strength, Xp(13_750.)),
-            xp_reward(Skill::Attack, Xp(13_750.)),
+            xp_reward(Skill::Strength, Xp::new(13_750)),
+            xp_reward(Skill::Attack, Xp::new(13_750)),
         ],
     }
 }
@@ -4088,13 +5223,18 @@ fn what_lies_below() -> Quest {
         id: QuestId::WHAT_LIES_BELOW,
         name: "What Lies Below".to_string(),
         quest_points: 1,
+        difficulty: QuestDifficulty::Intermediate,
+        length: QuestLength::Short,
+        members: true,
+        series: None,
+        release_date: ReleaseDate::new(2007, 2, 20),
         requirements: IndexSet::from_iter([
             quest_req(QuestId::RUNE_MYSTERIES),
             skill_req(Skill::Runecraft, Level(35)),
         ]),
         rewards: vec![
-            xp_reward(Skill::Runecraft, Xp(8_000.)),
-            xp_reward(Skill::Defence, Xp(2_000.)),
+            xp_reward(Skill::Runecraft, Xp::new(8_000)),
+            xp_reward(Skill::Defence, Xp::new(2_000)),
         ],
     }
 }
@@ -4105,6 +5245,11 @@ fn while_guthix_sleeps() -> Quest {
         id: QuestId::WHILE_GUTHIX_SLEEPS,
         name: "While Guthix Sleeps".to_string(),
         quest_points: 5,
+        difficulty: QuestDifficulty::Grandmaster,
+        length: QuestLength::VeryLong,
+        members: true,
+        series: Some(QuestSeries::Mahjarrat),
+        release_date: ReleaseDate::new(2023, 6, 21),
         requirements: IndexSet::from_iter([
             quest_point_req(180),
             quest_req(QuestId::DEFENDER_OF_VARROCK),
@@ -4122,13 +5267,21 @@ fn while_guthix_sleeps() -> Quest {
             skill_req(Skill::Farming, Level(65)),
             skill_req(Skill::Herblore, Level(65)),
             skill_req(Skill::Hunter, Level(62)),
-            // TODO: Warriors' Guild requirement (Attack + Strength >= 130 OR 99 Attack OR 99 Strength)
+            // Entry to the Warriors' Guild.
+            QuestRequirement::Any(vec![
+                QuestRequirement::SkillTotal {
+                    skills: vec![Skill::Attack, Skill::Strength],
+                    level: Level(130),
+                },
+                skill_req(Skill::Attack, Level(99)),
+                skill_req(Skill::Strength, Level(99)),
+            ]),
         ]),
         rewards: vec![
-            xp_reward(Skill::Thieving, Xp(80_000.)),
-            xp_reward(Skill::Farming, Xp(75_000.)),
-            xp_reward(Skill::Herblore, Xp(75_000.)),
-            xp_reward(Skill::Hunter, Xp(50_000.)),
+            xp_reward(Skill::Thieving, Xp::new(80_000)),
+            xp_reward(Skill::Farming, Xp::new(75_000)),
+            xp_reward(Skill::Herblore, Xp::new(75_000)),
+            xp_reward(Skill::Hunter, Xp::new(50_000)),
         ],
     }
 }
@@ -4139,8 +5292,13 @@ fn witchs_house() -> Quest {
         id: QuestId::WITCHS_HOUSE,
         name: "Witch's House".to_string(),
         quest_points: 4,
+        difficulty: QuestDifficulty::Intermediate,
+        length: QuestLength::Short,
+        members: true,
+        series: None,
+        release_date: ReleaseDate::new(2002, 1, 21),
         requirements: IndexSet::new(),
-        rewards: vec![xp_reward(Skill::Hitpoints, Xp(6_325.))],
+        rewards: vec![xp_reward(Skill::Hitpoints, Xp::new(6_325))],
     }
 }
 
@@ -4150,6 +5308,11 @@ fn zogre_flesh_eaters() -> Quest {
         id: QuestId::ZOGRE_FLESH_EATERS,
         name: "Zogre Flesh Eaters".to_string(),
         quest_points: 1,
+        difficulty: QuestDifficulty::Intermediate,
+        length: QuestLength::Medium,
+        members: true,
+        series: None,
+        release_date: ReleaseDate::new(2005, 7, 25),
         requirements: IndexSet::from_iter([
             quest_req(QuestId::BIG_CHOMPY_BIRD_HUNTING),
             quest_req(QuestId::JUNGLE_POTION),
@@ -4158,9 +5321,9 @@ fn zogre_flesh_eaters() -> Quest {
             skill_req(Skill::Ranged, Level(30)),
         ]),
         rewards: vec![
-            xp_reward(Skill::Fletching, Xp(2_000.)),
-            xp_reward(Skill::Ranged, Xp(2_000.)),
-            xp_reward(Skill::Herblore, Xp(2_000.)),
+            xp_reward(Skill::Fletching, Xp::new(2_000)),
+            xp_reward(Skill::Ranged, Xp::new(2_000)),
+            xp_reward(Skill::Herblore, Xp::new(2_000)),
         ],
     }
 }
@@ -4169,6 +5332,8 @@ fn zogre_flesh_eaters() -> Quest {
 mod tests {
     use pretty_assertions::assert_eq;
 
+    use crate::{PlayerState, RewardChoice, UnmetRequirement};
+
     use super::*;
 
     #[test]
@@ -4190,12 +5355,344 @@ mod tests {
     #[test]
     fn test_total_quest_points() {
         let total_quest_points = QuestId::all()
-            .into_iter()
+            .iter()
             .map(|id| id.quest().unwrap().quest_points as u32)
             .sum::<u32>();
         assert_eq!(total_quest_points, 331);
     }
 
+    #[test]
+    fn test_free_to_play_quests() {
+        let free_to_play = Quest::free_to_play().collect::<Vec<_>>();
+        assert_eq!(free_to_play.len(), 22);
+        assert!(free_to_play.iter().all(|quest| quest.quest_points > 0));
+        assert!(
+            free_to_play
+                .iter()
+                .any(|quest| quest.id == QuestId::DRAGON_SLAYER_I)
+        );
+
+        assert_eq!(
+            Quest::members_only().count() + free_to_play.len(),
+            QuestId::all().len()
+        );
+    }
+
+    #[test]
+    fn test_quest_series() {
+        let myreque = Quest::in_series(QuestSeries::Myreque)
+            .iter()
+            .map(|quest| quest.id.clone())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            myreque,
+            vec![
+                QuestId::PRIEST_IN_PERIL,
+                QuestId::NATURE_SPIRIT,
+                QuestId::IN_SEARCH_OF_THE_MYREQUE,
+                QuestId::IN_AID_OF_THE_MYREQUE,
+                QuestId::DARKNESS_OF_HALLOWVALE,
+                QuestId::A_TASTE_OF_HOPE,
+                QuestId::SINS_OF_THE_FATHER,
+            ]
+        );
+
+        let recipe_for_disaster = QuestId::RECIPE_FOR_DISASTER.quest().unwrap();
+        assert_eq!(recipe_for_disaster.difficulty, QuestDifficulty::Special);
+        assert_eq!(recipe_for_disaster.length, QuestLength::VeryLong);
+        assert_eq!(
+            QuestId::DESERT_TREASURE_II
+                .quest()
+                .unwrap()
+                .release_date
+                .to_string(),
+            "2023-07-26"
+        );
+    }
+
+    #[test]
+    fn test_quest_check() {
+        let mut player = PlayerState::new();
+        player.set_level(Skill::Ranged, Level(30));
+        player.set_level(Skill::Herblore, Level(3));
+        player.completed_quests.insert(QuestId::JUNGLE_POTION);
+
+        let check = QuestId::ZOGRE_FLESH_EATERS.quest().unwrap().check(&player);
+        assert_eq!(
+            check.met,
+            vec![
+                quest_req(QuestId::JUNGLE_POTION),
+                skill_req(Skill::Ranged, Level(30)),
+            ]
+        );
+        assert_eq!(
+            check.unmet,
+            vec![
+                UnmetRequirement::Quest(QuestId::BIG_CHOMPY_BIRD_HUNTING),
+                UnmetRequirement::Skill {
+                    skill: Skill::Smithing,
+                    required: Level(4),
+                    current: Level(1),
+                },
+                UnmetRequirement::Skill {
+                    skill: Skill::Herblore,
+                    required: Level(8),
+                    current: Level(3),
+                },
+            ]
+        );
+        assert_eq!(check.unmet[2].level_shortfall(), Some(5));
+        assert!(!check.can_start());
+
+        assert!(
+            QuestId::COOKS_ASSISTANT
+                .quest()
+                .unwrap()
+                .check(&player)
+                .can_start()
+        );
+    }
+
+    #[test]
+    fn test_quest_check_with_boosts() {
+        let mut player = PlayerState::new();
+        player.set_level(Skill::Ranged, Level(30));
+        player.set_level(Skill::Herblore, Level(5));
+        player.set_level(Skill::Smithing, Level(3));
+        player.completed_quests.insert(QuestId::JUNGLE_POTION);
+        player
+            .completed_quests
+            .insert(QuestId::BIG_CHOMPY_BIRD_HUNTING);
+
+        let zogre_flesh_eaters = QuestId::ZOGRE_FLESH_EATERS.quest().unwrap();
+        assert!(!zogre_flesh_eaters.check(&player).can_start());
+
+        let check = zogre_flesh_eaters.check_with_boosts(&player);
+        assert!(check.can_start());
+        assert!(check.needs_boosts());
+        assert_eq!(
+            check
+                .boosted
+                .iter()
+                .map(|boosted| (boosted.boost.name, boosted.boost.skill))
+                .collect::<Vec<_>>(),
+            vec![
+                ("Dwarven stout", Skill::Smithing),
+                ("Botanical pie", Skill::Herblore),
+            ]
+        );
+
+        player.xp[Skill::Herblore] = Xp::from_level(&Level(1));
+        let check = zogre_flesh_eaters.check_with_boosts(&player);
+        assert_eq!(
+            check.unmet,
+            vec![UnmetRequirement::Skill {
+                skill: Skill::Herblore,
+                required: Level(8),
+                current: Level(1),
+            }]
+        );
+
+        // Prayer cannot be boosted, and Spicy stews only add a random amount.
+        let the_great_brain_robbery = QuestId::THE_GREAT_BRAIN_ROBBERY.quest().unwrap();
+        assert!(
+            the_great_brain_robbery
+                .requirements
+                .contains(&skill_req_unboostable(Skill::Prayer, Level(50)))
+        );
+        player.set_level(Skill::Construction, Level(28));
+        assert_eq!(
+            skill_req(Skill::Construction, Level(30)).boost(&player),
+            None
+        );
+    }
+
+    #[test]
+    fn test_quest_check_alternatives() {
+        let while_guthix_sleeps = QuestId::WHILE_GUTHIX_SLEEPS.quest().unwrap();
+        let warriors_guild = while_guthix_sleeps
+            .requirements
+            .iter()
+            .find(|requirement| matches!(requirement, QuestRequirement::Any(_)))
+            .unwrap();
+
+        let mut player = PlayerState::new();
+        player.set_level(Skill::Attack, Level(70));
+        player.set_level(Skill::Strength, Level(50));
+        assert_eq!(
+            warriors_guild.check(&player),
+            Err(UnmetRequirement::Any(vec![
+                UnmetRequirement::SkillTotal {
+                    skills: vec![Skill::Attack, Skill::Strength],
+                    required: Level(130),
+                    current: Level(120),
+                },
+                UnmetRequirement::Skill {
+                    skill: Skill::Attack,
+                    required: Level(99),
+                    current: Level(70),
+                },
+                UnmetRequirement::Skill {
+                    skill: Skill::Strength,
+                    required: Level(99),
+                    current: Level(50),
+                },
+            ]))
+        );
+
+        player.set_level(Skill::Strength, Level(60));
+        assert!(warriors_guild.is_met(&player));
+
+        let dream_mentor = QuestId::DREAM_MENTOR.quest().unwrap().check(&player);
+        assert!(dream_mentor.unmet.contains(&UnmetRequirement::CombatLevel {
+            required: Level(85),
+            current: Level(45),
+        }));
+
+        let bone_voyage = QuestId::BONE_VOYAGE.quest().unwrap().check(&player);
+        assert!(bone_voyage.unmet.contains(&UnmetRequirement::Kudos {
+            required: 100,
+            current: 0,
+        }));
+
+        let fairytale_i = QuestId::FAIRYTALE_I.quest().unwrap();
+        player.completed_quests.insert(QuestId::LOST_CITY);
+        assert!(
+            fairytale_i
+                .check(&player)
+                .unmet
+                .contains(&UnmetRequirement::All(vec![UnmetRequirement::Any(vec![
+                    UnmetRequirement::Item {
+                        name: "Dramen staff".to_string(),
+                        required: 1,
+                        current: 0,
+                    },
+                    UnmetRequirement::Item {
+                        name: "Lunar staff".to_string(),
+                        required: 1,
+                        current: 0,
+                    },
+                ])]))
+        );
+
+        player.items.insert("Lunar staff".to_string(), 1);
+        assert!(
+            !fairytale_i
+                .check(&player)
+                .unmet
+                .iter()
+                .any(|requirement| matches!(requirement, UnmetRequirement::All(_)))
+        );
+    }
+
+    #[test]
+    fn test_quest_rewards() {
+        let sins_of_the_father = QuestId::SINS_OF_THE_FATHER.quest().unwrap();
+        let Some(QuestReward::Lamp { lamp, count }) = sins_of_the_father.rewards.first() else {
+            panic!("expected a lamp reward");
+        };
+        assert_eq!(*count, 6);
+
+        let mut player = PlayerState::new();
+        assert!(!lamp.can_use_on(Skill::Slayer, &player));
+
+        player.set_level(Skill::Slayer, Level(60));
+        assert!(lamp.can_use_on(Skill::Slayer, &player));
+
+        let rune_mysteries = QuestId::RUNE_MYSTERIES.quest().unwrap();
+        player.complete_quest(&rune_mysteries);
+        assert_eq!(player.item_quantity("Air talisman"), 1);
+        assert!(
+            rune_mysteries
+                .rewards
+                .contains(&QuestReward::Unlock(Unlock {
+                    kind: UnlockKind::Skill,
+                    name: "Runecraft".to_string(),
+                }))
+        );
+
+        let the_path_of_glouphrie = QuestId::THE_PATH_OF_GLOUPHRIE.quest().unwrap();
+        let strength = player.xp(Skill::Strength);
+        player.complete_quest_with(&the_path_of_glouphrie, &RewardChoice::Skill(Skill::Attack));
+        assert_eq!(player.xp(Skill::Strength), strength + Xp::new(30_000));
+
+        // Single-skill lamps are claimed without a choice.
+        let mut player = PlayerState::new();
+        player.complete_quest(&the_path_of_glouphrie);
+        assert_eq!(player.xp(Skill::Strength), Xp::new(30_000));
+        assert_eq!(player.xp(Skill::Slayer), Xp::new(20_000));
+        assert_eq!(player.xp(Skill::Thieving), Xp::new(5_000));
+        assert_eq!(player.xp(Skill::Magic), Xp::new(5_000));
+
+        // The Observatory Quest's random reward is never claimed.
+        let observatory_quest = QuestId::OBSERVATORY_QUEST.quest().unwrap();
+        let mut player = PlayerState::new();
+        player.complete_quest_with(&observatory_quest, &RewardChoice::Skill(Skill::Attack));
+        assert_eq!(player.xp(Skill::Attack), Xp::ZERO);
+        assert_eq!(player.xp(Skill::Crafting), Xp::new(2_250));
+    }
+
+    #[test]
+    fn test_startable_quests() {
+        let player = PlayerState::new();
+        let startable = player.startable_quests();
+
+        assert!(startable.contains(&QuestId::COOKS_ASSISTANT));
+        assert!(!startable.contains(&QuestId::DRAGON_SLAYER_I));
+        assert!(player.blocked_quests().contains(&QuestId::DRAGON_SLAYER_I));
+        assert_eq!(
+            startable.len() + player.blocked_quests().len(),
+            QuestId::all().len()
+        );
+    }
+
+    #[test]
+    fn test_resolve_requirements() {
+        let resolved = QuestId::DRAGON_SLAYER_II.resolve_requirements().unwrap();
+
+        assert!(!resolved.quests.contains(&QuestId::DRAGON_SLAYER_II));
+        for quest_id in [
+            QuestId::LEGENDS_QUEST,
+            QuestId::HEROES_QUEST,
+            QuestId::SHIELD_OF_ARRAV,
+            QuestId::DRAGON_SLAYER_I,
+            QuestId::LUNAR_DIPLOMACY,
+        ] {
+            assert!(resolved.quests.contains(&quest_id), "missing {quest_id:?}");
+        }
+
+        for (index, quest_id) in resolved.quests.iter().enumerate() {
+            for requirement in &quest_id.quest().unwrap().requirements {
+                if let QuestRequirement::Quest(prerequisite) = requirement {
+                    assert!(
+                        resolved.quests.get_index_of(prerequisite) < Some(index),
+                        "{prerequisite:?} should come before {quest_id:?}"
+                    );
+                }
+            }
+        }
+
+        assert_eq!(resolved.skills.get(&Skill::Magic), Some(&Level(75)));
+        assert_eq!(resolved.quest_points, 200);
+
+        // Either-or requirements are kept as is rather than guessing an alternative.
+        let resolved = QuestId::WHILE_GUTHIX_SLEEPS.resolve_requirements().unwrap();
+        assert_eq!(resolved.skills.get(&Skill::Attack), None);
+        assert!(resolved.other.iter().any(|requirement| matches!(
+            requirement,
+            QuestRequirement::Any(alternatives)
+                if alternatives.contains(&skill_req(Skill::Attack, Level(99)))
+        )));
+
+        let resolved = QuestId::ICTHLARINS_LITTLE_HELPER
+            .resolve_requirements()
+            .unwrap();
+        assert!(resolved.other.contains(&QuestRequirement::Any(vec![
+            item_req("Pet kitten", 1),
+            item_req("Pet cat", 1)
+        ])));
+    }
+
     #[test]
     fn test_quest_not_found_error() {
         let quest_id = QuestId::new_static("non_existent_quest");
@@ -1,3 +1,11 @@
+#[cfg(any(feature = "blocking", feature = "async"))]
+mod client;
+mod diff;
+#[cfg(feature = "json")]
+mod json;
+mod layout;
+mod writer;
+
 use std::collections::HashMap;
 use std::num::ParseIntError;
 use std::str::FromStr;
@@ -6,9 +14,16 @@ use strum::IntoEnumIterator;
 use thiserror::Error;
 use url::Url;
 
-use crate::{Boss, ClueTier, Skill};
+use crate::{Activity, Boss, ClueTier, Skill, Xp};
+
+#[cfg(any(feature = "blocking", feature = "async"))]
+pub use client::*;
+pub use diff::*;
+#[cfg(feature = "json")]
+pub use json::*;
+pub use layout::*;
 
-#[derive(Clone)]
+#[derive(Debug, Clone)]
 pub struct Hiscore {
     entries: HashMap<HiscoreEntryKind, HiscoreEntry>,
 }
@@ -16,8 +31,17 @@ pub struct Hiscore {
 impl Hiscore {
     /// Returns the hiscores URL for the player with the given name and account type.
     pub fn url(name: &str, account_type: AccountType) -> Url {
+        Self::endpoint_url("index_lite.ws", name, account_type)
+    }
+
+    /// Returns the JSON hiscores URL for the player with the given name and account type.
+    pub fn json_url(name: &str, account_type: AccountType) -> Url {
+        Self::endpoint_url("index_lite.json", name, account_type)
+    }
+
+    fn endpoint_url(endpoint: &str, name: &str, account_type: AccountType) -> Url {
         let url = format!(
-            "https://secure.runescape.com/m=hiscore_oldschool{}/index_lite.ws",
+            "https://secure.runescape.com/m=hiscore_oldschool{}/{endpoint}",
             account_type.hiscores_suffix()
         );
         let mut url = Url::parse(&url).unwrap();
@@ -42,6 +66,12 @@ impl Hiscore {
             .as_scalar()
     }
 
+    pub fn activity(&self, activity: Activity) -> Option<&ScalarHiscoreEntry> {
+        self.entries
+            .get(&HiscoreEntryKind::Activity(activity))?
+            .as_scalar()
+    }
+
     pub fn rifts_closed(&self) -> Option<&ScalarHiscoreEntry> {
         self.entries
             .get(&HiscoreEntryKind::RiftsClosed)?
@@ -57,39 +87,179 @@ impl Hiscore {
     pub fn boss(&self, boss: Boss) -> Option<&ScalarHiscoreEntry> {
         self.entries.get(&HiscoreEntryKind::Boss(boss))?.as_scalar()
     }
+
+    /// Returns the kill count of the given boss, including the kills of all of its variants.
+    ///
+    /// Unranked entries are counted as zero kills.
+    pub fn boss_kill_count(&self, boss: Boss) -> i32 {
+        boss.with_variants()
+            .into_iter()
+            .filter_map(|boss| self.boss(boss))
+            .map(|entry| entry.amount.max(0))
+            .sum()
+    }
 }
 
 #[derive(Error, Debug)]
 pub enum ParseHiscoreError {
-    #[error(transparent)]
-    InvalidEntry(#[from] ParseHiscoreEntryError),
-    #[error("index out of range: {0}")]
-    IndexOutOfRange(u32),
+    #[error("line {line}, column {column}: {source} ({text:?})")]
+    InvalidEntry {
+        line: usize,
+        column: usize,
+        text: String,
+        #[source]
+        source: ParseHiscoreEntryError,
+    },
+    #[error("line {line}, column {column}: expected {expected} rows, found {found} ({text:?})")]
+    RowCountMismatch {
+        line: usize,
+        column: usize,
+        text: String,
+        expected: usize,
+        found: usize,
+    },
 }
 
-impl FromStr for Hiscore {
-    type Err = ParseHiscoreError;
+impl ParseHiscoreError {
+    /// Returns the (1-based) line number at which the error occurred.
+    pub fn line(&self) -> usize {
+        match self {
+            Self::InvalidEntry { line, .. } | Self::RowCountMismatch { line, .. } => *line,
+        }
+    }
 
-    fn from_str(text: &str) -> Result<Self, Self::Err> {
-        let mut hiscore = Self {
-            entries: HashMap::new(),
+    /// Returns the (1-based) column at which the error occurred.
+    pub fn column(&self) -> usize {
+        match self {
+            Self::InvalidEntry { column, .. } | Self::RowCountMismatch { column, .. } => *column,
+        }
+    }
+
+    /// Returns the text of the offending line.
+    pub fn text(&self) -> &str {
+        match self {
+            Self::InvalidEntry { text, .. } | Self::RowCountMismatch { text, .. } => text,
+        }
+    }
+
+    fn row_count_mismatch(lines: &[&str], expected: usize) -> Self {
+        let line = expected.min(lines.len());
+
+        Self::RowCountMismatch {
+            line: line + 1,
+            column: 1,
+            text: lines.get(line).copied().unwrap_or_default().to_string(),
+            expected,
+            found: lines.len(),
+        }
+    }
+}
+
+/// A [`Hiscore`] parsed in lenient mode, along with any problems that were
+/// encountered while parsing it.
+#[derive(Debug)]
+pub struct LenientHiscore {
+    pub hiscore: Hiscore,
+    pub warnings: Vec<ParseHiscoreError>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ParseMode {
+    Strict,
+    Lenient,
+}
+
+impl Hiscore {
+    /// Parses a [`Hiscore`] from the `index_lite.ws` CSV format using the given layout.
+    ///
+    /// Returns an error if any of the rows are invalid, or if the number of rows
+    /// does not match the layout.
+    pub fn parse_with_layout(
+        text: &str,
+        layout: &HiscoreLayout,
+    ) -> Result<Self, ParseHiscoreError> {
+        Self::parse(text, layout, ParseMode::Strict).map(|parsed| parsed.hiscore)
+    }
+
+    /// Parses a [`Hiscore`] from the `index_lite.ws` CSV format, collecting
+    /// problems as warnings instead of failing.
+    ///
+    /// The layout is detected from the number of rows, falling back to the
+    /// latest layout if the number of rows does not match any known layout.
+    pub fn parse_lenient(text: &str) -> LenientHiscore {
+        let layout = HiscoreLayout::detect(text.lines().count()).unwrap_or(HiscoreLayout::latest());
+
+        Self::parse_lenient_with_layout(text, layout)
+    }
+
+    /// Parses a [`Hiscore`] from the `index_lite.ws` CSV format using the given
+    /// layout, collecting problems as warnings instead of failing.
+    ///
+    /// Invalid rows are skipped, and rows beyond the end of the layout are ignored.
+    pub fn parse_lenient_with_layout(text: &str, layout: &HiscoreLayout) -> LenientHiscore {
+        Self::parse(text, layout, ParseMode::Lenient)
+            .expect("lenient parsing should report errors as warnings")
+    }
+
+    fn parse(
+        text: &str,
+        layout: &HiscoreLayout,
+        mode: ParseMode,
+    ) -> Result<LenientHiscore, ParseHiscoreError> {
+        let mut parsed = LenientHiscore {
+            hiscore: Self {
+                entries: HashMap::new(),
+            },
+            warnings: Vec::new(),
+        };
+
+        let mut report = |error: ParseHiscoreError| match mode {
+            ParseMode::Strict => Err(error),
+            ParseMode::Lenient => {
+                parsed.warnings.push(error);
+                Ok(())
+            }
         };
 
-        let entries = text
-            .lines()
-            .map(HiscoreEntry::from_str)
-            .collect::<Result<Vec<_>, _>>()?;
+        let lines = text.lines().collect::<Vec<_>>();
+        if lines.len() != layout.len() {
+            report(ParseHiscoreError::row_count_mismatch(&lines, layout.len()))?;
+        }
 
-        for (entry_kind, entry) in HiscoreEntryKind::order().zip(entries) {
-            let entry_kind = match entry_kind {
-                Ok(kind) => kind,
-                Err(IgnoreEntry) => continue,
-            };
+        let mut entries = Vec::with_capacity(lines.len());
+        for (index, line) in lines.iter().enumerate() {
+            match HiscoreEntry::parse(line) {
+                Ok(entry) => entries.push((index, entry)),
+                Err((column, source)) => report(ParseHiscoreError::InvalidEntry {
+                    line: index + 1,
+                    column,
+                    text: line.to_string(),
+                    source,
+                })?,
+            }
+        }
 
-            hiscore.entries.insert(entry_kind, entry);
+        for (index, entry) in entries {
+            if let Some(entry_kind) = layout.kinds().get(index) {
+                parsed.hiscore.entries.insert(*entry_kind, entry);
+            }
         }
 
-        Ok(hiscore)
+        Ok(parsed)
+    }
+}
+
+impl FromStr for Hiscore {
+    type Err = ParseHiscoreError;
+
+    /// Parses a [`Hiscore`] from the `index_lite.ws` CSV format.
+    ///
+    /// The layout is detected from the number of rows. Returns an error if the
+    /// number of rows does not match any known layout, or if any of the rows are invalid.
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let layout = HiscoreLayout::detect(text.lines().count()).unwrap_or(HiscoreLayout::latest());
+
+        Self::parse_with_layout(text, layout)
     }
 }
 
@@ -97,6 +267,7 @@ impl FromStr for Hiscore {
 pub enum HiscoreEntryKind {
     Overall,
     Skill(Skill),
+    Activity(Activity),
     ClueScrollsAll,
     ClueScrolls(ClueTier),
     RiftsClosed,
@@ -105,41 +276,43 @@ pub enum HiscoreEntryKind {
     Boss(Boss),
 }
 
-#[derive(Debug, Clone)]
-struct IgnoreEntry;
-
 impl HiscoreEntryKind {
-    fn order() -> impl Iterator<Item = Result<HiscoreEntryKind, IgnoreEntry>> {
-        fn one(kind: HiscoreEntryKind) -> std::iter::Once<Result<HiscoreEntryKind, IgnoreEntry>> {
-            std::iter::once(Ok(kind))
-        }
-
-        fn many(
-            kinds: impl IntoIterator<Item = HiscoreEntryKind>,
-        ) -> impl Iterator<Item = Result<HiscoreEntryKind, IgnoreEntry>> {
-            kinds.into_iter().map(Ok)
-        }
-
-        fn ignore(n: usize) -> std::iter::RepeatN<Result<HiscoreEntryKind, IgnoreEntry>> {
-            std::iter::repeat_n(Err(IgnoreEntry), n)
-        }
-
-        one(Self::Overall)
-            .chain(Skill::iter().map(|skill| Ok(Self::Skill(skill))))
-            .chain(ignore(7))
-            .chain(one(Self::ClueScrollsAll))
-            .chain(ClueTier::iter().map(|tier| Ok(Self::ClueScrolls(tier))))
-            .chain(ignore(3))
-            .chain(many([
+    /// Returns the entry kinds in the order of the latest hiscores layout.
+    fn order() -> impl Iterator<Item = HiscoreEntryKind> {
+        std::iter::once(Self::Overall)
+            .chain(Skill::iter().map(Self::Skill))
+            .chain(
+                [
+                    Activity::GridPoints,
+                    Activity::LeaguePoints,
+                    Activity::DeadmanPoints,
+                    Activity::BountyHunterHunter,
+                    Activity::BountyHunterRogue,
+                    Activity::BountyHunterLegacyHunter,
+                    Activity::BountyHunterLegacyRogue,
+                ]
+                .map(Self::Activity),
+            )
+            .chain(std::iter::once(Self::ClueScrollsAll))
+            .chain(ClueTier::iter().map(Self::ClueScrolls))
+            .chain(
+                [
+                    Activity::LastManStanding,
+                    Activity::PvpArena,
+                    Activity::SoulWarsZeal,
+                ]
+                .map(Self::Activity),
+            )
+            .chain([
                 Self::RiftsClosed,
                 Self::ColosseumGlory,
                 Self::CollectionsLogged,
-            ]))
-            .chain(Boss::iter().map(|boss| Ok(Self::Boss(boss))))
+            ])
+            .chain(Boss::iter().map(Self::Boss))
     }
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, derive_more::Display)]
 pub enum HiscoreEntry {
     Skill(SkillHiscoreEntry),
     Scalar(ScalarHiscoreEntry),
@@ -169,41 +342,74 @@ pub enum ParseHiscoreEntryError {
     ParseIntError(#[from] ParseIntError),
 }
 
-impl FromStr for HiscoreEntry {
-    type Err = ParseHiscoreEntryError;
+impl HiscoreEntry {
+    /// Parses a [`HiscoreEntry`], returning the (1-based) column of the
+    /// offending field alongside any error.
+    fn parse(text: &str) -> Result<Self, (usize, ParseHiscoreEntryError)> {
+        let mut column = 1;
+        let mut parts = Vec::new();
+        for part in text.split(',') {
+            parts.push((column, part));
+            column += part.len() + 1;
+        }
 
-    fn from_str(text: &str) -> Result<Self, Self::Err> {
-        let parts = text.split(',').collect::<Vec<_>>();
+        fn parse_field(
+            (column, field): (usize, &str),
+        ) -> Result<i32, (usize, ParseHiscoreEntryError)> {
+            field
+                .parse()
+                .map_err(|err| (column, ParseHiscoreEntryError::from(err)))
+        }
 
         match parts.as_slice() {
             [rank, level, xp] => {
-                let rank = rank.parse()?;
-                let level = level.parse()?;
-                let xp = xp.parse()?;
+                let rank = parse_field(*rank)?;
+                let level = parse_field(*level)?;
+                let xp = parse_field(*xp)?;
 
                 Ok(Self::Skill(SkillHiscoreEntry { rank, level, xp }))
             }
             [rank, quantity] => {
-                let rank = rank.parse()?;
-                let amount = quantity.parse()?;
+                let rank = parse_field(*rank)?;
+                let amount = parse_field(*quantity)?;
 
                 Ok(Self::Scalar(ScalarHiscoreEntry { rank, amount }))
             }
-            _ => Err(ParseHiscoreEntryError::InvalidEntry {
-                text: text.to_string(),
-            }),
+            _ => Err((
+                1,
+                ParseHiscoreEntryError::InvalidEntry {
+                    text: text.to_string(),
+                },
+            )),
         }
     }
 }
 
-#[derive(Debug, PartialEq, Clone, Copy)]
+impl FromStr for HiscoreEntry {
+    type Err = ParseHiscoreEntryError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        Self::parse(text).map_err(|(_column, err)| err)
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy, derive_more::Display)]
+#[display("{rank},{level},{xp}")]
 pub struct SkillHiscoreEntry {
     pub rank: i32,
     pub level: i32,
     pub xp: i32,
 }
 
-#[derive(Debug, PartialEq, Clone, Copy)]
+impl SkillHiscoreEntry {
+    /// Returns the XP for this entry, or `None` if the entry is unranked.
+    pub fn to_xp(&self) -> Option<Xp> {
+        (self.xp >= 0).then(|| Xp::from(self.xp))
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy, derive_more::Display)]
+#[display("{rank},{amount}")]
 pub struct ScalarHiscoreEntry {
     pub rank: i32,
     pub amount: i32,
@@ -251,6 +457,10 @@ mod tests {
                 xp: 424_043_990,
             })
         );
+        assert_eq!(
+            hiscore.overall().and_then(SkillHiscoreEntry::to_xp),
+            Some(Xp::new(424_043_990))
+        );
 
         assert_eq!(
             hiscore.skill(Skill::Sailing),
@@ -269,6 +479,22 @@ mod tests {
             })
         );
 
+        assert_eq!(
+            hiscore.activity(Activity::LastManStanding),
+            Some(&ScalarHiscoreEntry {
+                rank: -1,
+                amount: 486,
+            })
+        );
+
+        assert_eq!(
+            hiscore.activity(Activity::PvpArena),
+            Some(&ScalarHiscoreEntry {
+                rank: -1,
+                amount: 2136,
+            })
+        );
+
         assert_eq!(
             hiscore.rifts_closed(),
             Some(&ScalarHiscoreEntry {
@@ -293,4 +519,56 @@ mod tests {
             })
         );
     }
+
+    #[test]
+    fn test_boss_kill_count() {
+        let hiscore = Hiscore::from_str(include_str!("../fixtures/hiscores_1.txt")).unwrap();
+
+        assert_eq!(hiscore.boss_kill_count(Boss::TheGauntlet), 22 + 127);
+        assert_eq!(hiscore.boss_kill_count(Boss::TheCorruptedGauntlet), 127);
+        assert_eq!(hiscore.boss_kill_count(Boss::Callisto), 25);
+        assert_eq!(hiscore.boss_kill_count(Boss::ChambersOfXeric), 9);
+    }
+
+    #[test]
+    fn test_hiscore_strict_parsing_errors() {
+        let fixture = include_str!("../fixtures/hiscores_1.txt");
+
+        let invalid = fixture.replacen("48469,99,27354592", "48469,99,27x54592", 1);
+        let error = Hiscore::from_str(&invalid).expect_err("expected invalid entry");
+        assert_eq!((error.line(), error.column()), (2, 10));
+        assert_eq!(error.text(), "48469,99,27x54592");
+
+        let extra = format!("{fixture}-1,0\n-1,0\n-1,0\n-1,0\n");
+        let error = Hiscore::from_str(&extra).expect_err("expected row count mismatch");
+        assert_eq!(
+            error.to_string(),
+            r#"line 114, column 1: expected 113 rows, found 117 ("-1,0")"#
+        );
+    }
+
+    #[test]
+    fn test_hiscore_lenient_parsing() {
+        let fixture = include_str!("../fixtures/hiscores_1.txt");
+        let invalid = fixture.replacen("48469,99,27354592", "48469,99,oops", 1);
+        let invalid = format!("{invalid}-1,0\n-1,0\n-1,0\n-1,0\n");
+
+        let parsed = Hiscore::parse_lenient(&invalid);
+        assert_eq!(
+            parsed
+                .warnings
+                .iter()
+                .map(|warning| (warning.line(), warning.column()))
+                .collect::<Vec<_>>(),
+            vec![(114, 1), (2, 10)]
+        );
+        assert_eq!(parsed.hiscore.skill(Skill::Attack), None);
+        assert_eq!(
+            parsed.hiscore.boss(Boss::Zulrah),
+            Some(&ScalarHiscoreEntry {
+                rank: 430_027,
+                amount: 57,
+            })
+        );
+    }
 }
@@ -0,0 +1,223 @@
+use std::str::FromStr;
+
+use thiserror::Error;
+use url::Url;
+
+use crate::{AccountType, Hiscore, ParseHiscoreError};
+
+/// A response returned by a [`HiscoresTransport`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HiscoresResponse {
+    pub status: u16,
+    pub body: String,
+}
+
+impl HiscoresResponse {
+    pub fn new(status: u16, body: impl Into<String>) -> Self {
+        Self {
+            status,
+            body: body.into(),
+        }
+    }
+
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+}
+
+/// A blocking transport used by [`HiscoresClient`] to perform HTTP requests.
+#[cfg(feature = "blocking")]
+pub trait HiscoresTransport {
+    type Error;
+
+    /// Performs a `GET` request against the given URL.
+    fn get(&self, url: &Url) -> Result<HiscoresResponse, Self::Error>;
+}
+
+#[cfg(feature = "blocking")]
+impl<F, E> HiscoresTransport for F
+where
+    F: Fn(&Url) -> Result<HiscoresResponse, E>,
+{
+    type Error = E;
+
+    fn get(&self, url: &Url) -> Result<HiscoresResponse, Self::Error> {
+        self(url)
+    }
+}
+
+/// An asynchronous transport used by [`HiscoresClient`] to perform HTTP requests.
+#[cfg(feature = "async")]
+pub trait AsyncHiscoresTransport {
+    type Error;
+
+    /// Performs a `GET` request against the given URL.
+    fn get(&self, url: &Url) -> impl Future<Output = Result<HiscoresResponse, Self::Error>>;
+}
+
+#[derive(Error, Debug)]
+pub enum FetchHiscoreError<E> {
+    #[error("player not found: {name:?} ({account_type})")]
+    PlayerNotFound {
+        name: String,
+        account_type: AccountType,
+    },
+    #[error("unexpected response status: {0}")]
+    UnexpectedStatus(u16),
+    #[error(transparent)]
+    Parse(#[from] ParseHiscoreError),
+    #[error("transport error: {0}")]
+    Transport(#[source] E),
+}
+
+/// A client for fetching player hiscores.
+///
+/// The client is generic over the transport used to perform the HTTP requests,
+/// which allows it to be used with any HTTP client (or a stand-in for testing).
+#[derive(Debug, Clone)]
+pub struct HiscoresClient<T> {
+    transport: T,
+}
+
+impl<T> HiscoresClient<T> {
+    pub fn new(transport: T) -> Self {
+        Self { transport }
+    }
+
+    pub fn transport(&self) -> &T {
+        &self.transport
+    }
+
+    fn handle_response<E>(
+        name: &str,
+        account_type: AccountType,
+        response: HiscoresResponse,
+    ) -> Result<Hiscore, FetchHiscoreError<E>> {
+        match response.status {
+            404 => Err(FetchHiscoreError::PlayerNotFound {
+                name: name.to_string(),
+                account_type,
+            }),
+            _ if response.is_success() => Ok(Hiscore::from_str(&response.body)?),
+            status => Err(FetchHiscoreError::UnexpectedStatus(status)),
+        }
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl<T: HiscoresTransport> HiscoresClient<T> {
+    /// Fetches the hiscores for the player with the given name and account type.
+    pub fn fetch(
+        &self,
+        name: &str,
+        account_type: AccountType,
+    ) -> Result<Hiscore, FetchHiscoreError<T::Error>> {
+        let url = Hiscore::url(name, account_type);
+        let response = self
+            .transport
+            .get(&url)
+            .map_err(FetchHiscoreError::Transport)?;
+
+        Self::handle_response(name, account_type, response)
+    }
+}
+
+#[cfg(feature = "async")]
+impl<T: AsyncHiscoresTransport> HiscoresClient<T> {
+    /// Fetches the hiscores for the player with the given name and account type.
+    pub async fn fetch_async(
+        &self,
+        name: &str,
+        account_type: AccountType,
+    ) -> Result<Hiscore, FetchHiscoreError<T::Error>> {
+        let url = Hiscore::url(name, account_type);
+        let response = self
+            .transport
+            .get(&url)
+            .await
+            .map_err(FetchHiscoreError::Transport)?;
+
+        Self::handle_response(name, account_type, response)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+
+    use pretty_assertions::assert_eq;
+
+    use crate::{Skill, SkillHiscoreEntry};
+
+    use super::*;
+
+    const FIXTURE: &str = include_str!("../../fixtures/hiscores_1.txt");
+
+    fn fixture_transport(url: &Url) -> Result<HiscoresResponse, Infallible> {
+        let player = url
+            .query_pairs()
+            .find(|(key, _)| key == "player")
+            .map(|(_, value)| value.into_owned());
+
+        match player.as_deref() {
+            Some("Zezima") => Ok(HiscoresResponse::new(200, FIXTURE)),
+            _ => Ok(HiscoresResponse::new(404, "")),
+        }
+    }
+
+    #[cfg(feature = "blocking")]
+    #[test]
+    fn test_fetch() {
+        let client = HiscoresClient::new(fixture_transport);
+
+        let hiscore = client.fetch("Zezima", AccountType::Main).unwrap();
+        assert_eq!(
+            hiscore.skill(Skill::Sailing),
+            Some(&SkillHiscoreEntry {
+                rank: 768,
+                level: 99,
+                xp: 15_437_526,
+            })
+        );
+
+        let error = client
+            .fetch("Nobody", AccountType::Ironman)
+            .expect_err("expected player to not exist");
+        assert_eq!(error.to_string(), r#"player not found: "Nobody" (ironman)"#);
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn test_fetch_async() {
+        use std::pin::pin;
+        use std::task::{Context, Poll, Waker};
+
+        struct FixtureTransport;
+
+        impl AsyncHiscoresTransport for FixtureTransport {
+            type Error = Infallible;
+
+            async fn get(&self, url: &Url) -> Result<HiscoresResponse, Self::Error> {
+                fixture_transport(url)
+            }
+        }
+
+        let client = HiscoresClient::new(FixtureTransport);
+        let mut future = pin!(client.fetch_async("Zezima", AccountType::Main));
+        let Poll::Ready(hiscore) = future
+            .as_mut()
+            .poll(&mut Context::from_waker(Waker::noop()))
+        else {
+            panic!("expected fetch to complete immediately");
+        };
+
+        assert_eq!(
+            hiscore.unwrap().skill(Skill::Sailing),
+            Some(&SkillHiscoreEntry {
+                rank: 768,
+                level: 99,
+                xp: 15_437_526,
+            })
+        );
+    }
+}
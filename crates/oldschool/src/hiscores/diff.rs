@@ -0,0 +1,295 @@
+use std::collections::HashMap;
+
+use strum::IntoEnumIterator;
+
+use crate::{
+    Activity, Boss, ClueTier, Hiscore, HiscoreEntry, HiscoreEntryKind, ScalarHiscoreEntry, Skill,
+    SkillHiscoreEntry,
+};
+
+/// The change in a skill between two [`Hiscore`] snapshots.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct SkillDelta {
+    /// The change in rank, or `None` if the skill was unranked in either snapshot.
+    ///
+    /// A negative change means the rank improved.
+    pub rank: Option<i32>,
+    pub level: i32,
+    pub xp: i32,
+}
+
+impl SkillDelta {
+    fn new(older: &SkillHiscoreEntry, newer: &SkillHiscoreEntry) -> Self {
+        Self {
+            rank: rank_delta(older.rank, newer.rank),
+            level: newer.level.max(1) - older.level.max(1),
+            xp: newer.xp.max(0) - older.xp.max(0),
+        }
+    }
+
+    /// Returns whether the level or XP changed.
+    pub fn is_changed(&self) -> bool {
+        self.level != 0 || self.xp != 0
+    }
+}
+
+/// The change in a scalar entry (such as a kill count) between two [`Hiscore`] snapshots.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct ScalarDelta {
+    /// The change in rank, or `None` if the entry was unranked in either snapshot.
+    ///
+    /// A negative change means the rank improved.
+    pub rank: Option<i32>,
+    pub amount: i32,
+}
+
+impl ScalarDelta {
+    fn new(older: &ScalarHiscoreEntry, newer: &ScalarHiscoreEntry) -> Self {
+        Self {
+            rank: rank_delta(older.rank, newer.rank),
+            amount: newer.amount.max(0) - older.amount.max(0),
+        }
+    }
+
+    /// Returns whether the amount changed.
+    pub fn is_changed(&self) -> bool {
+        self.amount != 0
+    }
+}
+
+fn rank_delta(older: i32, newer: i32) -> Option<i32> {
+    (older > 0 && newer > 0).then(|| newer - older)
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum HiscoreEntryDelta {
+    Skill(SkillDelta),
+    Scalar(ScalarDelta),
+}
+
+impl HiscoreEntryDelta {
+    pub fn as_skill(&self) -> Option<&SkillDelta> {
+        match self {
+            Self::Skill(delta) => Some(delta),
+            _ => None,
+        }
+    }
+
+    pub fn as_scalar(&self) -> Option<&ScalarDelta> {
+        match self {
+            Self::Scalar(delta) => Some(delta),
+            _ => None,
+        }
+    }
+}
+
+/// The difference between two [`Hiscore`] snapshots.
+#[derive(Debug, Clone)]
+pub struct HiscoreDiff {
+    entries: HashMap<HiscoreEntryKind, HiscoreEntryDelta>,
+}
+
+impl Hiscore {
+    /// Returns the difference between this [`Hiscore`] and an older snapshot.
+    ///
+    /// Entries that are missing from either snapshot are treated as unranked.
+    pub fn diff(&self, older: &Hiscore) -> HiscoreDiff {
+        const UNRANKED_SKILL: HiscoreEntry = HiscoreEntry::Skill(SkillHiscoreEntry {
+            rank: -1,
+            level: -1,
+            xp: -1,
+        });
+        const UNRANKED_SCALAR: HiscoreEntry = HiscoreEntry::Scalar(ScalarHiscoreEntry {
+            rank: -1,
+            amount: -1,
+        });
+
+        let mut entries = HashMap::new();
+        for kind in self.entries.keys().chain(older.entries.keys()) {
+            if entries.contains_key(kind) {
+                continue;
+            }
+
+            let unranked = if kind.is_skill() {
+                &UNRANKED_SKILL
+            } else {
+                &UNRANKED_SCALAR
+            };
+            let older = older.entries.get(kind).unwrap_or(unranked);
+            let newer = self.entries.get(kind).unwrap_or(unranked);
+
+            let delta = match (older, newer) {
+                (HiscoreEntry::Skill(older), HiscoreEntry::Skill(newer)) => {
+                    HiscoreEntryDelta::Skill(SkillDelta::new(older, newer))
+                }
+                (HiscoreEntry::Scalar(older), HiscoreEntry::Scalar(newer)) => {
+                    HiscoreEntryDelta::Scalar(ScalarDelta::new(older, newer))
+                }
+                _ => continue,
+            };
+
+            entries.insert(*kind, delta);
+        }
+
+        HiscoreDiff { entries }
+    }
+}
+
+impl HiscoreDiff {
+    pub fn entry(&self, kind: HiscoreEntryKind) -> Option<&HiscoreEntryDelta> {
+        self.entries.get(&kind)
+    }
+
+    pub fn overall(&self) -> Option<&SkillDelta> {
+        self.entries.get(&HiscoreEntryKind::Overall)?.as_skill()
+    }
+
+    pub fn skill(&self, skill: Skill) -> Option<&SkillDelta> {
+        self.entries
+            .get(&HiscoreEntryKind::Skill(skill))?
+            .as_skill()
+    }
+
+    pub fn activity(&self, activity: Activity) -> Option<&ScalarDelta> {
+        self.entries
+            .get(&HiscoreEntryKind::Activity(activity))?
+            .as_scalar()
+    }
+
+    pub fn clue_scrolls(&self, tier: ClueTier) -> Option<&ScalarDelta> {
+        self.entries
+            .get(&HiscoreEntryKind::ClueScrolls(tier))?
+            .as_scalar()
+    }
+
+    pub fn boss(&self, boss: Boss) -> Option<&ScalarDelta> {
+        self.entries.get(&HiscoreEntryKind::Boss(boss))?.as_scalar()
+    }
+
+    /// Returns the skills whose level or XP changed, sorted by the magnitude of the XP change.
+    pub fn changed_skills(&self) -> Vec<(Skill, SkillDelta)> {
+        let mut changed = Skill::iter()
+            .filter_map(|skill| Some((skill, *self.skill(skill)?)))
+            .filter(|(_, delta)| delta.is_changed())
+            .collect::<Vec<_>>();
+        changed.sort_by_key(|(_, delta)| std::cmp::Reverse(delta.xp.unsigned_abs()));
+
+        changed
+    }
+
+    /// Returns the activities whose score changed, sorted by the magnitude of the change.
+    pub fn changed_activities(&self) -> Vec<(Activity, ScalarDelta)> {
+        Self::changed_scalars(Activity::iter(), |activity| self.activity(activity))
+    }
+
+    /// Returns the clue scroll tiers whose completion count changed, sorted by
+    /// the magnitude of the change.
+    pub fn changed_clue_scrolls(&self) -> Vec<(ClueTier, ScalarDelta)> {
+        Self::changed_scalars(ClueTier::iter(), |tier| self.clue_scrolls(tier))
+    }
+
+    /// Returns the bosses whose kill count changed, sorted by the magnitude of the change.
+    pub fn changed_bosses(&self) -> Vec<(Boss, ScalarDelta)> {
+        Self::changed_scalars(Boss::iter(), |boss| self.boss(boss))
+    }
+
+    fn changed_scalars<'a, T>(
+        items: impl Iterator<Item = T>,
+        delta: impl Fn(T) -> Option<&'a ScalarDelta>,
+    ) -> Vec<(T, ScalarDelta)>
+    where
+        T: Copy,
+    {
+        let mut changed = items
+            .filter_map(|item| Some((item, *delta(item)?)))
+            .filter(|(_, delta)| delta.is_changed())
+            .collect::<Vec<_>>();
+        changed.sort_by_key(|(_, delta)| std::cmp::Reverse(delta.amount.unsigned_abs()));
+
+        changed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_hiscore_diff() {
+        let fixture = include_str!("../../fixtures/hiscores_1.txt");
+        let older = Hiscore::from_str(fixture).unwrap();
+
+        let mut newer = older.clone();
+        newer.entries.insert(
+            HiscoreEntryKind::Skill(Skill::Sailing),
+            HiscoreEntry::Skill(SkillHiscoreEntry {
+                rank: 700,
+                level: 100,
+                xp: 15_500_000,
+            }),
+        );
+        newer.entries.insert(
+            HiscoreEntryKind::Skill(Skill::Cooking),
+            HiscoreEntry::Skill(SkillHiscoreEntry {
+                rank: 1,
+                level: 99,
+                xp: 200_000_000,
+            }),
+        );
+        newer.entries.insert(
+            HiscoreEntryKind::Boss(Boss::Zulrah),
+            HiscoreEntry::Scalar(ScalarHiscoreEntry {
+                rank: 430_000,
+                amount: 60,
+            }),
+        );
+        newer.entries.insert(
+            HiscoreEntryKind::Boss(Boss::Vorkath),
+            HiscoreEntry::Scalar(ScalarHiscoreEntry {
+                rank: -1,
+                amount: 60,
+            }),
+        );
+
+        let diff = newer.diff(&older);
+        assert_eq!(
+            diff.skill(Skill::Sailing),
+            Some(&SkillDelta {
+                rank: Some(-68),
+                level: 1,
+                xp: 62_474,
+            })
+        );
+        assert_eq!(
+            diff.changed_skills()
+                .into_iter()
+                .map(|(skill, _)| skill)
+                .collect::<Vec<_>>(),
+            vec![Skill::Cooking, Skill::Sailing]
+        );
+        assert_eq!(
+            diff.changed_bosses(),
+            vec![
+                (
+                    Boss::Vorkath,
+                    ScalarDelta {
+                        rank: None,
+                        amount: 8,
+                    }
+                ),
+                (
+                    Boss::Zulrah,
+                    ScalarDelta {
+                        rank: Some(-27),
+                        amount: 3,
+                    }
+                ),
+            ]
+        );
+        assert_eq!(diff.changed_clue_scrolls(), Vec::new());
+    }
+}
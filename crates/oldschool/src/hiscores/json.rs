@@ -0,0 +1,193 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use serde::Deserialize;
+use strum::IntoEnumIterator;
+use thiserror::Error;
+
+use crate::{
+    Activity, Boss, ClueTier, Hiscore, HiscoreEntry, HiscoreEntryKind, ScalarHiscoreEntry, Skill,
+    SkillHiscoreEntry,
+};
+
+/// Boss names used by the JSON endpoint that differ from [`Boss::name`].
+const BOSS_ALIASES: &[(&str, Boss)] = &[("Bryophyta", Boss::Byrophyta), ("Vet'ion", Boss::Vetion)];
+
+#[derive(Error, Debug)]
+pub enum ParseHiscoreJsonError {
+    #[error("invalid hiscore JSON: {0}")]
+    InvalidJson(#[from] serde_json::Error),
+    #[error("unknown hiscore entries: {0:?}")]
+    UnknownEntries(Vec<String>),
+}
+
+#[derive(Debug, Deserialize)]
+struct JsonHiscore {
+    skills: Vec<JsonSkill>,
+    activities: Vec<JsonActivity>,
+}
+
+#[derive(Debug, Deserialize)]
+struct JsonSkill {
+    name: String,
+    rank: i32,
+    level: i32,
+    xp: i32,
+}
+
+#[derive(Debug, Deserialize)]
+struct JsonActivity {
+    name: String,
+    rank: i32,
+    score: i32,
+}
+
+impl Hiscore {
+    /// Parses a [`Hiscore`] from the JSON returned by the `index_lite.json` endpoint.
+    ///
+    /// Entries are matched by name rather than by position. Any names that are
+    /// not recognized are reported in [`ParseHiscoreJsonError::UnknownEntries`].
+    pub fn from_json(json: &str) -> Result<Self, ParseHiscoreJsonError> {
+        let json = serde_json::from_str::<JsonHiscore>(json)?;
+
+        let mut hiscore = Self {
+            entries: HashMap::new(),
+        };
+        let mut unknown_entries = Vec::new();
+
+        let skills = json.skills.into_iter().map(|skill| {
+            let entry = HiscoreEntry::Skill(SkillHiscoreEntry {
+                rank: skill.rank,
+                level: skill.level,
+                xp: skill.xp,
+            });
+
+            (skill.name, entry)
+        });
+        let activities = json.activities.into_iter().map(|activity| {
+            let entry = HiscoreEntry::Scalar(ScalarHiscoreEntry {
+                rank: activity.rank,
+                amount: activity.score,
+            });
+
+            (activity.name, entry)
+        });
+
+        for (name, entry) in skills.chain(activities) {
+            match HiscoreEntryKind::from_name(&name) {
+                Some(kind) => {
+                    hiscore.entries.insert(kind, entry);
+                }
+                None => unknown_entries.push(name),
+            }
+        }
+
+        if !unknown_entries.is_empty() {
+            return Err(ParseHiscoreJsonError::UnknownEntries(unknown_entries));
+        }
+
+        Ok(hiscore)
+    }
+}
+
+impl HiscoreEntryKind {
+    /// Returns the entry kind with the given hiscores name.
+    fn from_name(name: &str) -> Option<HiscoreEntryKind> {
+        if name.eq_ignore_ascii_case("Overall") {
+            return Some(Self::Overall);
+        }
+
+        if let Ok(skill) = Skill::from_str(name) {
+            return Some(Self::Skill(skill));
+        }
+
+        if let Some(tier) = name
+            .strip_prefix("Clue Scrolls (")
+            .and_then(|name| name.strip_suffix(')'))
+        {
+            if tier.eq_ignore_ascii_case("all") {
+                return Some(Self::ClueScrollsAll);
+            }
+
+            return ClueTier::iter()
+                .find(|clue_tier| clue_tier.to_string().eq_ignore_ascii_case(tier))
+                .map(Self::ClueScrolls);
+        }
+
+        let kind = match name.to_ascii_lowercase().as_str() {
+            "rifts closed" => Some(Self::RiftsClosed),
+            "colosseum glory" => Some(Self::ColosseumGlory),
+            "collections logged" => Some(Self::CollectionsLogged),
+            _ => None,
+        };
+        if kind.is_some() {
+            return kind;
+        }
+
+        if let Some(activity) =
+            Activity::iter().find(|activity| activity.name().eq_ignore_ascii_case(name))
+        {
+            return Some(Self::Activity(activity));
+        }
+
+        Boss::iter()
+            .find(|boss| boss.name().eq_ignore_ascii_case(name))
+            .or_else(|| {
+                BOSS_ALIASES
+                    .iter()
+                    .find(|(alias, _)| alias.eq_ignore_ascii_case(name))
+                    .map(|(_, boss)| *boss)
+            })
+            .map(Self::Boss)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_hiscore_json_parsing() {
+        let csv = Hiscore::from_str(include_str!("../../fixtures/hiscores_1.txt")).unwrap();
+        let json = Hiscore::from_json(include_str!("../../fixtures/hiscores_1.json")).unwrap();
+
+        assert_eq!(json.entries.len(), csv.entries.len());
+        for (kind, entry) in &csv.entries {
+            let json_entry = json.entries.get(kind);
+            assert_eq!(
+                json_entry.and_then(HiscoreEntry::as_skill),
+                entry.as_skill(),
+                "mismatched {kind:?}"
+            );
+            assert_eq!(
+                json_entry.and_then(HiscoreEntry::as_scalar),
+                entry.as_scalar(),
+                "mismatched {kind:?}"
+            );
+        }
+
+        assert_eq!(
+            json.boss(Boss::Vetion),
+            Some(&ScalarHiscoreEntry {
+                rank: -1,
+                amount: 0
+            })
+        );
+    }
+
+    #[test]
+    fn test_hiscore_json_unknown_entries() {
+        let json = r#"{
+            "skills": [{ "id": 0, "name": "Overall", "rank": 1, "level": 2376, "xp": 460000000 }],
+            "activities": [{ "id": 0, "name": "The Brand New Boss", "rank": 1, "score": 5 }]
+        }"#;
+
+        let error = Hiscore::from_json(json).expect_err("expected unknown entry");
+        assert_eq!(
+            error.to_string(),
+            r#"unknown hiscore entries: ["The Brand New Boss"]"#
+        );
+    }
+}
@@ -0,0 +1,263 @@
+use std::sync::LazyLock;
+
+use crate::{Activity, Boss, HiscoreEntryKind, Skill};
+
+/// The order of the entries in the `index_lite.ws` CSV format at a point in time.
+///
+/// Every game update that adds a skill, activity, or boss to the hiscores
+/// shifts the position of the entries that follow it, so snapshots taken
+/// before the update need to be parsed with the layout that was live at the time.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HiscoreLayout {
+    name: &'static str,
+    kinds: Vec<HiscoreEntryKind>,
+}
+
+impl HiscoreLayout {
+    /// Returns all of the known layouts, ordered from oldest to newest.
+    pub fn all() -> &'static [HiscoreLayout] {
+        &LAYOUTS
+    }
+
+    /// Returns the current layout.
+    pub fn latest() -> &'static HiscoreLayout {
+        LAYOUTS.last().unwrap()
+    }
+
+    /// Returns the layout with the given name.
+    pub fn by_name(name: &str) -> Option<&'static HiscoreLayout> {
+        LAYOUTS.iter().find(|layout| layout.name == name)
+    }
+
+    /// Returns the layout with the given number of rows.
+    pub fn detect(row_count: usize) -> Option<&'static HiscoreLayout> {
+        LAYOUTS
+            .iter()
+            .rev()
+            .find(|layout| layout.len() == row_count)
+    }
+
+    /// Returns the name of the update that introduced this layout.
+    pub fn name(&self) -> &str {
+        self.name
+    }
+
+    /// Returns the entry kinds in this layout, in the order they appear.
+    pub fn kinds(&self) -> &[HiscoreEntryKind] {
+        &self.kinds
+    }
+
+    /// Returns the number of rows in this layout.
+    pub fn len(&self) -> usize {
+        self.kinds.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.kinds.is_empty()
+    }
+}
+
+static LAYOUTS: LazyLock<Vec<HiscoreLayout>> = LazyLock::new(build_layouts);
+
+fn build_layouts() -> Vec<HiscoreLayout> {
+    // Updates are listed from newest to oldest, along with the entries they
+    // added. Each older layout is derived by removing the entries added by the
+    // update that followed it.
+    let updates: [(&'static str, &[HiscoreEntryKind]); 17] = [
+        ("sailing", &[HiscoreEntryKind::Skill(Skill::Sailing)]),
+        (
+            "grid_points",
+            &[HiscoreEntryKind::Activity(Activity::GridPoints)],
+        ),
+        (
+            "shellbane_gryphon",
+            &[HiscoreEntryKind::Boss(Boss::ShellbaneGryphon)],
+        ),
+        (
+            "doom_of_mokhaiotl",
+            &[HiscoreEntryKind::Boss(Boss::DoomOfMokhaiotl)],
+        ),
+        ("yama", &[HiscoreEntryKind::Boss(Boss::Yama)]),
+        (
+            "the_royal_titans",
+            &[HiscoreEntryKind::Boss(Boss::TheRoyalTitans)],
+        ),
+        (
+            "varlamore_part_two",
+            &[
+                HiscoreEntryKind::Boss(Boss::Amoxliatl),
+                HiscoreEntryKind::Boss(Boss::TheHueycoatl),
+            ],
+        ),
+        ("collections_logged", &[HiscoreEntryKind::CollectionsLogged]),
+        ("araxxor", &[HiscoreEntryKind::Boss(Boss::Araxxor)]),
+        (
+            "varlamore_part_one",
+            &[
+                HiscoreEntryKind::ColosseumGlory,
+                HiscoreEntryKind::Boss(Boss::LunarChests),
+                HiscoreEntryKind::Boss(Boss::SolHeredit),
+            ],
+        ),
+        ("scurrius", &[HiscoreEntryKind::Boss(Boss::Scurrius)]),
+        (
+            "deranged_archaeologist",
+            &[HiscoreEntryKind::Boss(Boss::DerangedArchaeologist)],
+        ),
+        (
+            "desert_treasure_ii",
+            &[
+                HiscoreEntryKind::Boss(Boss::DukeSucellus),
+                HiscoreEntryKind::Boss(Boss::TheLeviathan),
+                HiscoreEntryKind::Boss(Boss::TheWhisperer),
+                HiscoreEntryKind::Boss(Boss::Vardorvis),
+            ],
+        ),
+        (
+            "bounty_hunter",
+            &[
+                HiscoreEntryKind::Activity(Activity::BountyHunterHunter),
+                HiscoreEntryKind::Activity(Activity::BountyHunterRogue),
+            ],
+        ),
+        (
+            "wilderness_bosses",
+            &[
+                HiscoreEntryKind::Boss(Boss::Artio),
+                HiscoreEntryKind::Boss(Boss::Calvarion),
+                HiscoreEntryKind::Boss(Boss::Spindel),
+            ],
+        ),
+        (
+            "phantom_muspah",
+            &[HiscoreEntryKind::Boss(Boss::PhantomMuspah)],
+        ),
+        (
+            "tombs_of_amascut",
+            &[
+                HiscoreEntryKind::Boss(Boss::TombsOfAmascut),
+                HiscoreEntryKind::Boss(Boss::TombsOfAmascutExpertMode),
+            ],
+        ),
+    ];
+
+    let mut layouts = Vec::with_capacity(updates.len());
+    let mut kinds = HiscoreEntryKind::order().collect::<Vec<_>>();
+    for (name, added) in updates {
+        layouts.push(HiscoreLayout {
+            name,
+            kinds: kinds.clone(),
+        });
+
+        kinds.retain(|kind| !added.contains(kind));
+    }
+
+    layouts.reverse();
+    layouts
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use pretty_assertions::assert_eq;
+
+    use crate::{Hiscore, ScalarHiscoreEntry};
+
+    use super::*;
+
+    #[test]
+    fn test_layouts_have_distinct_row_counts() {
+        for pair in HiscoreLayout::all().windows(2) {
+            assert!(
+                pair[0].len() < pair[1].len(),
+                "{} should have fewer rows than {}",
+                pair[0].name(),
+                pair[1].name()
+            );
+        }
+
+        assert_eq!(HiscoreLayout::latest().name(), "sailing");
+        assert_eq!(HiscoreLayout::latest().len(), 113);
+    }
+
+    #[test]
+    fn test_hiscore_parsing_with_pre_2024_layout() {
+        let fixture = include_str!("../../fixtures/hiscores_1.txt");
+        let latest = Hiscore::from_str(fixture).unwrap();
+
+        // Remove every entry added since Desert Treasure II, released in July 2023.
+        let added_since = [
+            HiscoreEntryKind::Skill(Skill::Sailing),
+            HiscoreEntryKind::Activity(Activity::GridPoints),
+            HiscoreEntryKind::ColosseumGlory,
+            HiscoreEntryKind::CollectionsLogged,
+            HiscoreEntryKind::Boss(Boss::Amoxliatl),
+            HiscoreEntryKind::Boss(Boss::Araxxor),
+            HiscoreEntryKind::Boss(Boss::DerangedArchaeologist),
+            HiscoreEntryKind::Boss(Boss::DoomOfMokhaiotl),
+            HiscoreEntryKind::Boss(Boss::LunarChests),
+            HiscoreEntryKind::Boss(Boss::Scurrius),
+            HiscoreEntryKind::Boss(Boss::ShellbaneGryphon),
+            HiscoreEntryKind::Boss(Boss::SolHeredit),
+            HiscoreEntryKind::Boss(Boss::TheHueycoatl),
+            HiscoreEntryKind::Boss(Boss::TheRoyalTitans),
+            HiscoreEntryKind::Boss(Boss::Yama),
+        ];
+        let snapshot = fixture
+            .lines()
+            .zip(HiscoreLayout::latest().kinds())
+            .filter(|(_, kind)| !added_since.contains(kind))
+            .map(|(line, _)| line)
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let row_count = snapshot.lines().count();
+        assert_eq!(row_count, 98);
+        assert_eq!(
+            HiscoreLayout::detect(row_count).map(HiscoreLayout::name),
+            Some("desert_treasure_ii")
+        );
+
+        let hiscore = Hiscore::from_str(&snapshot).unwrap();
+        assert_eq!(hiscore.boss(Boss::Scurrius), None);
+        for boss in [Boss::Vorkath, Boss::Wintertodt, Boss::Zalcano, Boss::Zulrah] {
+            assert!(latest.boss(boss).is_some(), "{boss}");
+            assert_eq!(hiscore.boss(boss), latest.boss(boss), "{boss}");
+        }
+    }
+
+    #[test]
+    fn test_hiscore_parsing_with_older_layout() {
+        let fixture = include_str!("../../fixtures/hiscores_1.txt");
+
+        // Remove the Sailing row to simulate a snapshot from before its release.
+        let snapshot = fixture
+            .lines()
+            .enumerate()
+            .filter(|(index, _)| *index != 24)
+            .map(|(_, line)| line)
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let hiscore = Hiscore::from_str(&snapshot).unwrap();
+        assert_eq!(hiscore.skill(Skill::Sailing), None);
+        assert_eq!(
+            hiscore.boss(Boss::Zulrah),
+            Some(&ScalarHiscoreEntry {
+                rank: 430_027,
+                amount: 57,
+            })
+        );
+
+        let layout = HiscoreLayout::by_name("grid_points").unwrap();
+        let hiscore = Hiscore::parse_with_layout(&snapshot, layout).unwrap();
+        assert_eq!(
+            hiscore.boss(Boss::Zulrah),
+            Some(&ScalarHiscoreEntry {
+                rank: 430_027,
+                amount: 57,
+            })
+        );
+    }
+}
@@ -0,0 +1,94 @@
+use std::fmt::{self, Write};
+
+use crate::{Hiscore, HiscoreEntryKind, HiscoreLayout};
+
+impl Hiscore {
+    /// Writes this [`Hiscore`] in the `index_lite.ws` CSV format using the given layout.
+    ///
+    /// Entries that are not present are written as unranked (`-1`) placeholders,
+    /// with a score of `0` for activities and bosses as the hiscores API does.
+    pub fn write_with_layout(
+        &self,
+        writer: &mut impl Write,
+        layout: &HiscoreLayout,
+    ) -> fmt::Result {
+        for kind in layout.kinds() {
+            match self.entries.get(kind) {
+                Some(entry) => writeln!(writer, "{entry}")?,
+                None if kind.is_skill() => writeln!(writer, "-1,-1,-1")?,
+                None => writeln!(writer, "-1,0")?,
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns this [`Hiscore`] in the `index_lite.ws` CSV format using the given layout.
+    pub fn to_csv_with_layout(&self, layout: &HiscoreLayout) -> String {
+        let mut csv = String::new();
+        self.write_with_layout(&mut csv, layout)
+            .expect("writing to a String should not fail");
+
+        csv
+    }
+}
+
+impl fmt::Display for Hiscore {
+    /// Writes this [`Hiscore`] in the `index_lite.ws` CSV format using the latest layout.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_with_layout(f, HiscoreLayout::latest())
+    }
+}
+
+impl HiscoreEntryKind {
+    /// Returns whether entries of this kind have a level and XP.
+    pub fn is_skill(&self) -> bool {
+        matches!(self, Self::Overall | Self::Skill(_))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use pretty_assertions::assert_eq;
+
+    use crate::{Boss, Skill, SkillHiscoreEntry};
+
+    use super::*;
+
+    #[test]
+    fn test_hiscore_round_trip() {
+        let fixture = include_str!("../../fixtures/hiscores_1.txt");
+        let hiscore = Hiscore::from_str(fixture).unwrap();
+
+        assert_eq!(hiscore.to_string(), fixture);
+    }
+
+    #[test]
+    fn test_hiscore_writing_unranked_entries() {
+        let fixture = include_str!("../../fixtures/hiscores_1.txt");
+        let mut hiscore = Hiscore::from_str(fixture).unwrap();
+        hiscore
+            .entries
+            .remove(&HiscoreEntryKind::Skill(Skill::Sailing));
+        hiscore
+            .entries
+            .remove(&HiscoreEntryKind::Boss(Boss::Zulrah));
+
+        let csv = hiscore.to_csv_with_layout(HiscoreLayout::latest());
+        let lines = csv.lines().collect::<Vec<_>>();
+        assert_eq!(lines[24], "-1,-1,-1");
+        assert_eq!(lines[112], "-1,0");
+
+        let reparsed = Hiscore::from_str(&csv).unwrap();
+        assert_eq!(
+            reparsed.skill(Skill::Sailing),
+            Some(&SkillHiscoreEntry {
+                rank: -1,
+                level: -1,
+                xp: -1,
+            })
+        );
+    }
+}
@@ -7,9 +7,22 @@ use crate::{Xp, xp_table};
 pub struct Level(pub i32);
 
 impl Level {
+    /// The maximum level that can be reached in a skill.
+    pub const MAX: Level = Level(99);
+
+    /// The maximum virtual level, reached at 188,884,740 XP.
+    pub const MAX_VIRTUAL: Level = Level(126);
+
+    /// Returns the level for the given XP, capped at [`Level::MAX`].
     pub fn from_xp(xp: &Xp) -> Self {
+        Self::from_xp_virtual(xp).min(Self::MAX)
+    }
+
+    /// Returns the level for the given XP, including virtual levels beyond
+    /// [`Level::MAX`] up to [`Level::MAX_VIRTUAL`].
+    pub fn from_xp_virtual(xp: &Xp) -> Self {
         for (level, xp_for_level) in xp_table().iter().rev() {
-            if xp_for_level <= &xp {
+            if xp_for_level <= xp {
                 return *level;
             }
         }
@@ -20,6 +33,11 @@ impl Level {
     pub fn is_99(&self) -> bool {
         self.0 == 99
     }
+
+    /// Returns whether this is a virtual level (i.e., beyond [`Level::MAX`]).
+    pub fn is_virtual(&self) -> bool {
+        *self > Self::MAX
+    }
 }
 
 impl Default for Level {
@@ -47,3 +65,31 @@ impl Sum for Level {
         iter.fold(Level(0), Add::add)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_level_from_xp() {
+        assert_eq!(Level::from_xp(&Xp::new(0)), Level(1));
+        assert_eq!(Level::from_xp(&Xp::new(13_034_430)), Level(98));
+        assert_eq!(Level::from_xp(&Xp::new(13_034_431)), Level(99));
+        assert_eq!(Level::from_xp(&Xp::MAX), Level(99));
+    }
+
+    #[test]
+    fn test_virtual_level_from_xp() {
+        assert_eq!(Level::from_xp_virtual(&Xp::new(13_034_431)), Level(99));
+        assert_eq!(Level::from_xp_virtual(&Xp::new(14_391_160)), Level(100));
+        assert_eq!(Level::from_xp_virtual(&Xp::MAX), Level::MAX_VIRTUAL);
+
+        assert!(!Level(99).is_virtual());
+        assert!(Level(100).is_virtual());
+
+        assert!(Xp::MAX.is_maxed());
+        assert!(!Xp::new(188_884_740).is_maxed());
+    }
+}
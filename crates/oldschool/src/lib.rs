@@ -1,18 +1,43 @@
+mod achievement_diary;
+mod activity;
+mod boost;
 mod boss;
+mod boss_stats;
 mod clue;
+mod combat;
+mod combat_achievement;
 mod content;
 mod hiscores;
 mod level;
+mod miniquest;
+mod player;
+mod progress;
 mod quest;
+mod quest_plan;
+mod reward_choice;
 mod skill;
 mod xp;
 mod xp_table;
 
+pub use achievement_diary::*;
+pub use activity::*;
+pub use boost::*;
 pub use boss::*;
+pub use boss_stats::*;
 pub use clue::*;
+pub use combat::*;
+pub use combat_achievement::*;
+pub use content::combat_achievements::CombatAchievementNotFoundError;
+pub use content::miniquests::MiniquestNotFoundError;
+pub use content::quests::QuestNotFoundError;
 pub use hiscores::*;
 pub use level::*;
+pub use miniquest::*;
+pub use player::*;
+pub use progress::*;
 pub use quest::*;
+pub use quest_plan::*;
+pub use reward_choice::*;
 pub use skill::*;
 pub use xp::*;
 pub(crate) use xp_table::*;
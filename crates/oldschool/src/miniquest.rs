@@ -0,0 +1,47 @@
+use indexmap::IndexSet;
+use smol_str::SmolStr;
+
+use crate::{PlayerState, QuestCheck, QuestRequirement, QuestReward};
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone)]
+pub struct MiniquestId(pub(crate) SmolStr);
+
+impl MiniquestId {
+    pub fn new(name: &str) -> Self {
+        Self(SmolStr::new(name))
+    }
+
+    pub const fn new_static(name: &'static str) -> Self {
+        Self(SmolStr::new_static(name))
+    }
+
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
+/// A miniquest.
+///
+/// Miniquests are like quests, but do not award quest points.
+///
+/// <https://oldschool.runescape.wiki/w/Miniquests>
+#[derive(Debug)]
+pub struct Miniquest {
+    pub id: MiniquestId,
+    pub name: String,
+    pub requirements: IndexSet<QuestRequirement>,
+    pub rewards: Vec<QuestReward>,
+}
+
+impl Miniquest {
+    /// Checks the requirements of this miniquest against the given player.
+    pub fn check(&self, player: &PlayerState) -> QuestCheck {
+        QuestCheck::new(&self.requirements, player)
+    }
+
+    /// Checks the requirements of this miniquest against the given player,
+    /// allowing boostable skill requirements to be met with a temporary boost.
+    pub fn check_with_boosts(&self, player: &PlayerState) -> QuestCheck {
+        QuestCheck::with_boosts(&self.requirements, player)
+    }
+}
@@ -0,0 +1,229 @@
+use std::collections::BTreeMap;
+
+use enum_map::EnumMap;
+use indexmap::IndexSet;
+
+use crate::{
+    CombatLevel, Hiscore, Level, Miniquest, MiniquestId, Quest, QuestId, QuestReward, RewardChoice,
+    Skill, Xp,
+};
+
+/// The state of a player's account, as relevant to requirements.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlayerState {
+    pub xp: EnumMap<Skill, Xp>,
+    pub completed_quests: IndexSet<QuestId>,
+    pub completed_miniquests: IndexSet<MiniquestId>,
+    pub quest_points: u16,
+    /// The player's Kudos from the Varrock Museum.
+    pub kudos: u16,
+    /// The items the player has, by name.
+    pub items: BTreeMap<String, u32>,
+}
+
+impl PlayerState {
+    /// Returns the state of a brand new account.
+    pub fn new() -> Self {
+        Self {
+            xp: EnumMap::from_fn(|skill: Skill| Xp::from_level(&skill.starting_level())),
+            completed_quests: IndexSet::new(),
+            completed_miniquests: IndexSet::new(),
+            quest_points: 0,
+            kudos: 0,
+            items: BTreeMap::new(),
+        }
+    }
+
+    /// Returns the state of the account with the skills in the given [`Hiscore`].
+    ///
+    /// Skills that are unranked are assumed to be at their starting level.
+    pub fn from_hiscore(hiscore: &Hiscore) -> Self {
+        Self {
+            xp: EnumMap::from_fn(|skill| hiscore.skill_progress(skill).xp()),
+            ..Self::new()
+        }
+    }
+
+    pub fn xp(&self, skill: Skill) -> Xp {
+        self.xp[skill]
+    }
+
+    pub fn level(&self, skill: Skill) -> Level {
+        Level::from_xp(&self.xp[skill])
+    }
+
+    pub fn combat_level(&self) -> CombatLevel {
+        CombatLevel::new(|skill| self.level(skill))
+    }
+
+    /// Sets the given skill to the given level, unless it is already at or above it.
+    ///
+    /// The level is clamped to between 1 and [`Level::MAX_VIRTUAL`].
+    pub fn set_level(&mut self, skill: Skill, level: Level) {
+        let level = level.clamp(Level(1), Level::MAX_VIRTUAL);
+        self.xp[skill] = self.xp[skill].max(Xp::from_level(&level));
+    }
+
+    /// Adds the given XP to the given skill, capped at [`Xp::MAX`].
+    pub fn add_xp(&mut self, skill: Skill, xp: Xp) {
+        self.xp[skill] = self.xp[skill].saturating_add(xp);
+    }
+
+    pub fn has_completed(&self, quest: &QuestId) -> bool {
+        self.completed_quests.contains(quest)
+    }
+
+    pub fn has_completed_miniquest(&self, miniquest: &MiniquestId) -> bool {
+        self.completed_miniquests.contains(miniquest)
+    }
+
+    /// Returns how many of the given item the player has.
+    pub fn item_quantity(&self, name: &str) -> u32 {
+        self.items.get(name).copied().unwrap_or_default()
+    }
+
+    /// Marks the given quest as completed, awarding its quest points and rewards.
+    ///
+    /// Rewards that require the player to make a choice, such as XP lamps that
+    /// can be used on more than one skill, are not awarded. Use
+    /// [`PlayerState::complete_quest_with`] to award them.
+    pub fn complete_quest(&mut self, quest: &Quest) {
+        self.complete(quest, None);
+    }
+
+    /// Marks the given quest as completed, awarding its quest points and
+    /// rewards, and claiming any XP that requires a choice with the given strategy.
+    pub fn complete_quest_with(&mut self, quest: &Quest, choice: &RewardChoice) {
+        self.complete(quest, Some(choice));
+    }
+
+    /// Marks the given miniquest as completed, awarding its rewards.
+    ///
+    /// As with [`PlayerState::complete_quest`], rewards that require the player
+    /// to make a choice are not awarded.
+    pub fn complete_miniquest(&mut self, miniquest: &Miniquest) {
+        if self.completed_miniquests.insert(miniquest.id.clone()) {
+            self.claim_rewards(&miniquest.rewards, None);
+        }
+    }
+
+    fn complete(&mut self, quest: &Quest, choice: Option<&RewardChoice>) {
+        if !self.completed_quests.insert(quest.id.clone()) {
+            return;
+        }
+
+        self.quest_points += u16::from(quest.quest_points);
+        self.claim_rewards(&quest.rewards, choice);
+    }
+
+    fn claim_rewards(&mut self, rewards: &[QuestReward], choice: Option<&RewardChoice>) {
+        for reward in rewards {
+            match reward {
+                QuestReward::Xp { skill, xp } => self.add_xp(*skill, *xp),
+                QuestReward::Lamp { lamp, count } => {
+                    if let Some(choice) = choice {
+                        for (skill, xp) in choice.resolve(std::slice::from_ref(reward), self) {
+                            self.add_xp(skill, xp);
+                        }
+                    } else if let &[skill] = lamp.skills.as_slice() {
+                        // A lamp for a single skill leaves nothing to choose.
+                        for _ in 0..*count {
+                            if let Some(xp) = lamp.xp_for(skill, self) {
+                                self.add_xp(skill, xp);
+                            }
+                        }
+                    }
+                }
+                QuestReward::Choice(options) => {
+                    if let Some(choice) = choice
+                        && let Some(option) = choice.choose(options, self)
+                    {
+                        self.claim_rewards(option, Some(choice));
+                    }
+                }
+                QuestReward::Item { name, quantity } => {
+                    *self.items.entry(name.clone()).or_default() += quantity;
+                }
+                QuestReward::Kudos(kudos) => self.kudos += kudos,
+                QuestReward::Unlock(_) | QuestReward::Random(_) => {}
+            }
+        }
+    }
+
+    /// Returns the quests that have not been completed and whose requirements are all met.
+    pub fn startable_quests(&self) -> Vec<QuestId> {
+        self.incomplete_quests()
+            .filter(|quest| quest.check(self).can_start())
+            .map(|quest| quest.id.clone())
+            .collect()
+    }
+
+    /// Returns the quests that have not been completed and have unmet requirements.
+    pub fn blocked_quests(&self) -> Vec<QuestId> {
+        self.incomplete_quests()
+            .filter(|quest| !quest.check(self).can_start())
+            .map(|quest| quest.id.clone())
+            .collect()
+    }
+
+    fn incomplete_quests(&self) -> impl Iterator<Item = std::sync::Arc<Quest>> {
+        QuestId::all()
+            .iter()
+            .filter(|id| !self.has_completed(id))
+            .filter_map(|id| id.quest().ok())
+    }
+}
+
+impl Default for PlayerState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_set_level_out_of_range() {
+        let mut player = PlayerState::new();
+        player.set_level(Skill::Attack, Level(0));
+        player.set_level(Skill::Strength, Level(-5));
+        player.set_level(Skill::Defence, Level(127));
+
+        assert_eq!(player.xp(Skill::Attack), Xp::ZERO);
+        assert_eq!(player.xp(Skill::Strength), Xp::ZERO);
+        assert_eq!(
+            player.xp(Skill::Defence),
+            Xp::from_level(&Level::MAX_VIRTUAL)
+        );
+    }
+
+    #[test]
+    fn test_claim_chosen_rewards() {
+        let rewards = [QuestReward::Choice(vec![
+            vec![QuestReward::Kudos(10)],
+            vec![
+                QuestReward::Xp {
+                    skill: Skill::Attack,
+                    xp: Xp::new(100),
+                },
+                QuestReward::Item {
+                    name: "Coins".to_string(),
+                    quantity: 500,
+                },
+            ],
+        ])];
+
+        let mut player = PlayerState::new();
+        player.claim_rewards(&rewards, None);
+        assert_eq!(player, PlayerState::new());
+
+        player.claim_rewards(&rewards, Some(&RewardChoice::Skill(Skill::Attack)));
+        assert_eq!(player.xp(Skill::Attack), Xp::new(100));
+        assert_eq!(player.item_quantity("Coins"), 500);
+        assert_eq!(player.kudos, 0);
+    }
+}
@@ -0,0 +1,192 @@
+use crate::{Hiscore, Level, Skill, Xp};
+
+/// A player's progress in a skill, based on their XP.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct SkillProgress {
+    xp: Xp,
+}
+
+impl SkillProgress {
+    pub fn new(xp: Xp) -> Self {
+        Self { xp }
+    }
+
+    pub fn xp(&self) -> Xp {
+        self.xp
+    }
+
+    /// Returns the current level, capped at [`Level::MAX`].
+    pub fn level(&self) -> Level {
+        Level::from_xp(&self.xp)
+    }
+
+    /// Returns the current level, including virtual levels.
+    pub fn virtual_level(&self) -> Level {
+        Level::from_xp_virtual(&self.xp)
+    }
+
+    /// Returns the XP needed to reach the next (virtual) level, or `None` if
+    /// the current level is [`Level::MAX_VIRTUAL`].
+    pub fn xp_to_next_level(&self) -> Option<Xp> {
+        let next_level = self.virtual_level() + Level(1);
+        if next_level > Level::MAX_VIRTUAL {
+            return None;
+        }
+
+        Some(self.xp_to_level(next_level))
+    }
+
+    /// Returns the XP needed to reach the given level, or [`Xp::ZERO`] if it has
+    /// already been reached.
+    pub fn xp_to_level(&self, level: Level) -> Xp {
+        self.xp_to(Xp::from_level(&level.clamp(Level(1), Level::MAX_VIRTUAL)))
+    }
+
+    /// Returns the XP needed to reach the given amount of XP, or [`Xp::ZERO`]
+    /// if it has already been reached.
+    pub fn xp_to(&self, xp: Xp) -> Xp {
+        xp.saturating_sub(self.xp)
+    }
+
+    /// Returns the fraction (from `0.0` to `1.0`) of the way through the
+    /// current (virtual) level.
+    ///
+    /// Returns `1.0` once [`Level::MAX_VIRTUAL`] has been reached.
+    pub fn progress(&self) -> f64 {
+        let level = self.virtual_level();
+        if level >= Level::MAX_VIRTUAL {
+            return 1.;
+        }
+
+        let start = Xp::from_level(&level);
+        let end = Xp::from_level(&(level + Level(1)));
+
+        (self.xp - start) / (end - start)
+    }
+}
+
+impl From<Xp> for SkillProgress {
+    fn from(xp: Xp) -> Self {
+        Self::new(xp)
+    }
+}
+
+/// A target to reach in a skill.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum SkillGoal {
+    Level(Level),
+    Xp(Xp),
+}
+
+impl SkillGoal {
+    /// Returns the XP needed to complete this goal.
+    pub fn target_xp(&self) -> Xp {
+        match self {
+            Self::Level(level) => Xp::from_level(&(*level).clamp(Level(1), Level::MAX_VIRTUAL)),
+            Self::Xp(xp) => *xp,
+        }
+    }
+}
+
+/// The progress towards a [`SkillGoal`].
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct GoalProgress {
+    pub skill: Skill,
+    pub goal: SkillGoal,
+    pub current: Xp,
+    pub remaining: Xp,
+    /// The fraction (from `0.0` to `1.0`) of the goal's XP that has been reached.
+    pub completion: f64,
+}
+
+impl GoalProgress {
+    pub fn new(skill: Skill, goal: SkillGoal, current: Xp) -> Self {
+        let target = goal.target_xp();
+        let completion = if target > Xp::ZERO {
+            (current / target).min(1.)
+        } else {
+            1.
+        };
+
+        Self {
+            skill,
+            goal,
+            current,
+            remaining: SkillProgress::new(current).xp_to(target),
+            completion,
+        }
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.remaining == Xp::ZERO
+    }
+}
+
+impl Hiscore {
+    /// Returns the progress in the given skill.
+    ///
+    /// Skills that are unranked are assumed to be at their starting level.
+    pub fn skill_progress(&self, skill: Skill) -> SkillProgress {
+        let xp = self
+            .skill(skill)
+            .and_then(|entry| entry.to_xp())
+            .unwrap_or_else(|| Xp::from_level(&skill.starting_level()));
+
+        SkillProgress::new(xp)
+    }
+
+    /// Evaluates the progress towards each of the given goals.
+    pub fn evaluate_goals(
+        &self,
+        goals: impl IntoIterator<Item = (Skill, SkillGoal)>,
+    ) -> Vec<GoalProgress> {
+        goals
+            .into_iter()
+            .map(|(skill, goal)| GoalProgress::new(skill, goal, self.skill_progress(skill).xp()))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use pretty_assertions::assert_eq;
+    use strum::IntoEnumIterator;
+
+    use super::*;
+
+    #[test]
+    fn test_skill_progress() {
+        let progress = SkillProgress::new(Xp::new(1_000));
+        assert_eq!(progress.level(), Level(9));
+        assert_eq!(progress.xp_to_next_level(), Some(Xp::new(154)));
+        assert_eq!(progress.xp_to_level(Level(99)), Xp::new(13_033_431));
+        assert_eq!(progress.xp_to_level(Level(5)), Xp::ZERO);
+        assert_eq!(progress.xp_to(Xp::new(1_500)), Xp::new(500));
+        assert_eq!(progress.progress(), 31. / 185.);
+
+        let maxed = SkillProgress::new(Xp::MAX);
+        assert_eq!(maxed.level(), Level(99));
+        assert_eq!(maxed.virtual_level(), Level(126));
+        assert_eq!(maxed.xp_to_next_level(), None);
+        assert_eq!(maxed.progress(), 1.);
+    }
+
+    #[test]
+    fn test_evaluate_goals() {
+        let hiscore = Hiscore::from_str(include_str!("../fixtures/hiscores_1.txt")).unwrap();
+
+        let goals =
+            hiscore.evaluate_goals(Skill::iter().map(|skill| (skill, SkillGoal::Level(Level(99)))));
+        assert!(goals.iter().all(GoalProgress::is_complete));
+
+        let goals = hiscore.evaluate_goals([
+            (Skill::Sailing, SkillGoal::Xp(Xp::MAX)),
+            (Skill::Sailing, SkillGoal::Level(Level(101))),
+        ]);
+        assert_eq!(goals[0].remaining, Xp::new(184_562_474));
+        assert_eq!(goals[1].remaining, Xp::new(451_583));
+        assert!(!goals[1].is_complete());
+    }
+}
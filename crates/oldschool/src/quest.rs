@@ -1,7 +1,10 @@
+use std::collections::BTreeMap;
+
 use indexmap::IndexSet;
 use smol_str::SmolStr;
 
-use crate::{Level, Skill, Xp};
+use crate::content::quests::QuestNotFoundError;
+use crate::{Boost, Level, MiniquestId, PlayerState, Skill, Xp};
 
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone)]
 pub struct QuestId(pub(crate) SmolStr);
@@ -18,6 +21,100 @@ impl QuestId {
     pub fn as_str(&self) -> &str {
         self.0.as_str()
     }
+
+    /// Resolves the full set of requirements for this quest, including the
+    /// requirements of all of its prerequisite quests.
+    pub fn resolve_requirements(&self) -> Result<ResolvedRequirements, QuestNotFoundError> {
+        let mut resolved = ResolvedRequirements::default();
+        let mut visited = IndexSet::new();
+        resolved.visit(self, &mut visited)?;
+        resolved.quests.shift_remove(self);
+
+        Ok(resolved)
+    }
+}
+
+/// The full set of requirements for a quest, including those of all of its
+/// prerequisite quests.
+///
+/// The alternatives of a [`QuestRequirement::Any`] are not resolved, since
+/// which one applies depends on the player. Each is kept as is in
+/// [`ResolvedRequirements::other`].
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct ResolvedRequirements {
+    /// The prerequisite quests, ordered such that each quest comes after its
+    /// own prerequisites.
+    pub quests: IndexSet<QuestId>,
+    /// The highest level required in each skill.
+    pub skills: BTreeMap<Skill, Level>,
+    /// The highest quest point requirement.
+    pub quest_points: u16,
+    /// Any other requirements, such as combat level, Kudos, items, miniquests
+    /// and either-or requirements.
+    ///
+    /// The requirements of each miniquest are resolved along with those of the quests.
+    pub other: IndexSet<QuestRequirement>,
+}
+
+impl ResolvedRequirements {
+    fn visit(
+        &mut self,
+        id: &QuestId,
+        visited: &mut IndexSet<QuestId>,
+    ) -> Result<(), QuestNotFoundError> {
+        if !visited.insert(id.clone()) {
+            return Ok(());
+        }
+
+        let quest = id.quest()?;
+        for requirement in &quest.requirements {
+            self.add(requirement, visited)?;
+        }
+
+        self.quests.insert(id.clone());
+
+        Ok(())
+    }
+
+    fn add(
+        &mut self,
+        requirement: &QuestRequirement,
+        visited: &mut IndexSet<QuestId>,
+    ) -> Result<(), QuestNotFoundError> {
+        match requirement {
+            QuestRequirement::Skill { skill, level, .. } => {
+                let required = self.skills.entry(*skill).or_default();
+                *required = (*required).max(*level);
+            }
+            QuestRequirement::Quest(prerequisite) => self.visit(prerequisite, visited)?,
+            QuestRequirement::QuestPoints(quest_points) => {
+                self.quest_points = self.quest_points.max(*quest_points);
+            }
+            QuestRequirement::All(requirements) => {
+                for requirement in requirements {
+                    self.add(requirement, visited)?;
+                }
+            }
+            QuestRequirement::Miniquest(miniquest) => {
+                if self.other.insert(requirement.clone())
+                    && let Ok(miniquest) = miniquest.miniquest()
+                {
+                    for requirement in &miniquest.requirements {
+                        self.add(requirement, visited)?;
+                    }
+                }
+            }
+            QuestRequirement::SkillTotal { .. }
+            | QuestRequirement::Any(_)
+            | QuestRequirement::CombatLevel(_)
+            | QuestRequirement::Kudos(_)
+            | QuestRequirement::Item { .. } => {
+                self.other.insert(requirement.clone());
+            }
+        }
+
+        Ok(())
+    }
 }
 
 #[derive(Debug)]
@@ -25,18 +122,496 @@ pub struct Quest {
     pub id: QuestId,
     pub name: String,
     pub quest_points: u8,
+    pub difficulty: QuestDifficulty,
+    pub length: QuestLength,
+    pub members: bool,
+    pub series: Option<QuestSeries>,
+    pub release_date: ReleaseDate,
     pub requirements: IndexSet<QuestRequirement>,
     pub rewards: Vec<QuestReward>,
 }
 
+/// The official difficulty of a quest.
+///
+/// <https://oldschool.runescape.wiki/w/Quests#Difficulty>
+#[derive(
+    Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, strum::Display, strum::EnumIter,
+)]
+pub enum QuestDifficulty {
+    Novice,
+    Intermediate,
+    Experienced,
+    Master,
+    Grandmaster,
+    Special,
+}
+
+/// The official length of a quest.
+#[derive(
+    Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, strum::Display, strum::EnumIter,
+)]
+pub enum QuestLength {
+    #[strum(serialize = "Very Short")]
+    VeryShort,
+    Short,
+    Medium,
+    Long,
+    #[strum(serialize = "Very Long")]
+    VeryLong,
+}
+
+/// A series of related quests.
+///
+/// <https://oldschool.runescape.wiki/w/Quest_series>
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, strum::Display, strum::EnumIter)]
+pub enum QuestSeries {
+    Camelot,
+    Desert,
+    Dorgeshuun,
+    Dragonkin,
+    #[strum(serialize = "Elemental Workshop")]
+    ElementalWorkshop,
+    Elf,
+    #[strum(serialize = "Fairy Tale")]
+    FairyTale,
+    Fremennik,
+    Gnome,
+    #[strum(serialize = "Great Kourend")]
+    GreatKourend,
+    Mahjarrat,
+    Myreque,
+    Pirate,
+    #[strum(serialize = "Rag and Bone Man")]
+    RagAndBoneMan,
+    #[strum(serialize = "Red Axe")]
+    RedAxe,
+    #[strum(serialize = "Temple Knight")]
+    TempleKnight,
+    Troll,
+    Varlamore,
+}
+
+/// The date on which a piece of content was released.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, derive_more::Display)]
+#[display("{year:04}-{month:02}-{day:02}")]
+pub struct ReleaseDate {
+    pub year: u16,
+    pub month: u8,
+    pub day: u8,
+}
+
+impl ReleaseDate {
+    pub const fn new(year: u16, month: u8, day: u8) -> Self {
+        Self { year, month, day }
+    }
+}
+
+impl Quest {
+    /// Checks the requirements of this quest against the given player.
+    pub fn check(&self, player: &PlayerState) -> QuestCheck {
+        QuestCheck::new(&self.requirements, player)
+    }
+
+    /// Checks the requirements of this quest against the given player, allowing
+    /// boostable skill requirements to be met with a temporary boost.
+    ///
+    /// Requirements that can only be met with a boost are reported in
+    /// [`QuestCheck::boosted`], along with the weakest boost that meets them.
+    pub fn check_with_boosts(&self, player: &PlayerState) -> QuestCheck {
+        QuestCheck::with_boosts(&self.requirements, player)
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum QuestRequirement {
-    Skill { skill: Skill, level: Level },
+    Skill {
+        skill: Skill,
+        level: Level,
+        /// Whether the requirement can be met with a temporary [`Boost`].
+        boostable: bool,
+    },
+    /// The combined level of the given skills must be at least the given level.
+    SkillTotal {
+        skills: Vec<Skill>,
+        level: Level,
+    },
+    CombatLevel(Level),
     Quest(QuestId),
+    Miniquest(MiniquestId),
     QuestPoints(u16),
+    /// Kudos from the Varrock Museum.
+    Kudos(u16),
+    Item {
+        name: String,
+        quantity: u32,
+    },
+    /// At least one of the given requirements must be met.
+    Any(Vec<QuestRequirement>),
+    /// All of the given requirements must be met.
+    All(Vec<QuestRequirement>),
 }
 
-#[derive(Debug)]
+impl QuestRequirement {
+    /// Checks whether this requirement is met by the given player.
+    pub fn check(&self, player: &PlayerState) -> Result<(), UnmetRequirement> {
+        match self {
+            Self::Skill { skill, level, .. } => {
+                let current = player.level(*skill);
+                if current >= *level {
+                    return Ok(());
+                }
+
+                Err(UnmetRequirement::Skill {
+                    skill: *skill,
+                    required: *level,
+                    current,
+                })
+            }
+            Self::SkillTotal { skills, level } => {
+                let current = Level(skills.iter().map(|skill| player.level(*skill).0).sum());
+                if current >= *level {
+                    return Ok(());
+                }
+
+                Err(UnmetRequirement::SkillTotal {
+                    skills: skills.clone(),
+                    required: *level,
+                    current,
+                })
+            }
+            Self::CombatLevel(level) => {
+                let current = player.combat_level().level();
+                if current >= *level {
+                    return Ok(());
+                }
+
+                Err(UnmetRequirement::CombatLevel {
+                    required: *level,
+                    current,
+                })
+            }
+            Self::Quest(quest) => {
+                if player.has_completed(quest) {
+                    return Ok(());
+                }
+
+                Err(UnmetRequirement::Quest(quest.clone()))
+            }
+            Self::Miniquest(miniquest) => {
+                if player.has_completed_miniquest(miniquest) {
+                    return Ok(());
+                }
+
+                Err(UnmetRequirement::Miniquest(miniquest.clone()))
+            }
+            Self::QuestPoints(quest_points) => {
+                if player.quest_points >= *quest_points {
+                    return Ok(());
+                }
+
+                Err(UnmetRequirement::QuestPoints {
+                    required: *quest_points,
+                    current: player.quest_points,
+                })
+            }
+            Self::Kudos(kudos) => {
+                if player.kudos >= *kudos {
+                    return Ok(());
+                }
+
+                Err(UnmetRequirement::Kudos {
+                    required: *kudos,
+                    current: player.kudos,
+                })
+            }
+            Self::Item { name, quantity } => {
+                let current = player.item_quantity(name);
+                if current >= *quantity {
+                    return Ok(());
+                }
+
+                Err(UnmetRequirement::Item {
+                    name: name.clone(),
+                    required: *quantity,
+                    current,
+                })
+            }
+            Self::Any(requirements) => {
+                let mut unmet = Vec::new();
+                for requirement in requirements {
+                    match requirement.check(player) {
+                        Ok(()) => return Ok(()),
+                        Err(requirement) => unmet.push(requirement),
+                    }
+                }
+
+                Err(UnmetRequirement::Any(unmet))
+            }
+            Self::All(requirements) => {
+                let unmet = requirements
+                    .iter()
+                    .filter_map(|requirement| requirement.check(player).err())
+                    .collect::<Vec<_>>();
+                if unmet.is_empty() {
+                    return Ok(());
+                }
+
+                Err(UnmetRequirement::All(unmet))
+            }
+        }
+    }
+
+    pub fn is_met(&self, player: &PlayerState) -> bool {
+        self.check(player).is_ok()
+    }
+
+    /// Returns the weakest boost with which the given player would meet this
+    /// requirement, if it is a boostable skill requirement that is not already met.
+    ///
+    /// Only boosts that add a guaranteed amount are considered.
+    pub fn boost(&self, player: &PlayerState) -> Option<&'static Boost> {
+        let Self::Skill {
+            skill,
+            level,
+            boostable: true,
+        } = self
+        else {
+            return None;
+        };
+
+        let current = player.level(*skill);
+        if current >= *level {
+            return None;
+        }
+
+        Boost::for_skill(*skill)
+            .filter(|boost| !boost.random && boost.apply(current) >= *level)
+            .min_by_key(|boost| boost.amount(current))
+    }
+
+    /// Returns the simple requirements that make up this requirement.
+    ///
+    /// For [`QuestRequirement::Any`], only the first alternative that the given
+    /// player meets is included, or the first alternative if none are met.
+    pub(crate) fn flatten<'a>(&'a self, player: &PlayerState) -> Vec<&'a QuestRequirement> {
+        match self {
+            Self::Any(requirements) => requirements
+                .iter()
+                .find(|requirement| requirement.is_met(player))
+                .or(requirements.first())
+                .map(|requirement| requirement.flatten(player))
+                .unwrap_or_default(),
+            Self::All(requirements) => requirements
+                .iter()
+                .flat_map(|requirement| requirement.flatten(player))
+                .collect(),
+            requirement => vec![requirement],
+        }
+    }
+}
+
+/// A requirement that is not met by a player.
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub enum UnmetRequirement {
+    Skill {
+        skill: Skill,
+        required: Level,
+        current: Level,
+    },
+    SkillTotal {
+        skills: Vec<Skill>,
+        required: Level,
+        current: Level,
+    },
+    CombatLevel {
+        required: Level,
+        current: Level,
+    },
+    Quest(QuestId),
+    Miniquest(MiniquestId),
+    QuestPoints {
+        required: u16,
+        current: u16,
+    },
+    Kudos {
+        required: u16,
+        current: u16,
+    },
+    Item {
+        name: String,
+        required: u32,
+        current: u32,
+    },
+    /// None of the alternatives are met.
+    Any(Vec<UnmetRequirement>),
+    /// Some of the requirements are not met.
+    All(Vec<UnmetRequirement>),
+}
+
+impl UnmetRequirement {
+    /// Returns the number of levels by which a skill or combat level requirement falls short.
+    pub fn level_shortfall(&self) -> Option<i32> {
+        match self {
+            Self::Skill {
+                required, current, ..
+            }
+            | Self::SkillTotal {
+                required, current, ..
+            }
+            | Self::CombatLevel { required, current } => Some(required.0 - current.0),
+            _ => None,
+        }
+    }
+}
+
+/// A requirement that is met with a temporary boost.
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct BoostedRequirement {
+    pub requirement: QuestRequirement,
+    pub boost: Boost,
+}
+
+/// The result of checking a quest's requirements against a player.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct QuestCheck {
+    pub met: Vec<QuestRequirement>,
+    /// The requirements that are only met with a boost.
+    pub boosted: Vec<BoostedRequirement>,
+    pub unmet: Vec<UnmetRequirement>,
+}
+
+impl QuestCheck {
+    pub(crate) fn new<'a>(
+        requirements: impl IntoIterator<Item = &'a QuestRequirement>,
+        player: &PlayerState,
+    ) -> Self {
+        let mut check = Self::default();
+        for requirement in requirements {
+            match requirement.check(player) {
+                Ok(()) => check.met.push(requirement.clone()),
+                Err(unmet) => check.unmet.push(unmet),
+            }
+        }
+
+        check
+    }
+
+    pub(crate) fn with_boosts<'a>(
+        requirements: impl IntoIterator<Item = &'a QuestRequirement>,
+        player: &PlayerState,
+    ) -> Self {
+        let mut check = Self::default();
+        for requirement in requirements {
+            match requirement.check(player) {
+                Ok(()) => check.met.push(requirement.clone()),
+                Err(unmet) => match requirement.boost(player) {
+                    Some(boost) => check.boosted.push(BoostedRequirement {
+                        requirement: requirement.clone(),
+                        boost: *boost,
+                    }),
+                    None => check.unmet.push(unmet),
+                },
+            }
+        }
+
+        check
+    }
+
+    /// Returns whether all of the quest's requirements are met, possibly with boosts.
+    pub fn can_start(&self) -> bool {
+        self.unmet.is_empty()
+    }
+
+    /// Returns whether the quest's requirements can only be met with boosts.
+    pub fn needs_boosts(&self) -> bool {
+        !self.boosted.is_empty()
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum QuestReward {
-    Xp { skill: Skill, xp: Xp },
+    Xp {
+        skill: Skill,
+        xp: Xp,
+    },
+    /// One or more XP lamps, each of which grants XP in a skill of the player's choice.
+    Lamp {
+        lamp: XpLamp,
+        count: u8,
+    },
+    Item {
+        name: String,
+        quantity: u32,
+    },
+    Unlock(Unlock),
+    /// Kudos from the Varrock Museum.
+    Kudos(u16),
+    /// A choice between sets of rewards, of which the player receives one.
+    Choice(Vec<Vec<QuestReward>>),
+    /// Sets of rewards, of which the player receives one at random.
+    ///
+    /// Since the outcome cannot be planned for, these rewards are never claimed
+    /// or counted towards XP.
+    Random(Vec<Vec<QuestReward>>),
+}
+
+/// An XP reward that the player assigns to a skill of their choice, such as
+/// from an XP lamp or a tome of experience.
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct XpLamp {
+    pub xp: LampXp,
+    /// The skills that the XP can be assigned to.
+    pub skills: Vec<Skill>,
+    /// The level the chosen skill must be at before the XP can be assigned to it.
+    pub min_level: Option<Level>,
+}
+
+impl XpLamp {
+    /// Returns whether the given player can assign this lamp's XP to the given skill.
+    pub fn can_use_on(&self, skill: Skill, player: &PlayerState) -> bool {
+        self.skills.contains(&skill)
+            && self
+                .min_level
+                .is_none_or(|min_level| player.level(skill) >= min_level)
+    }
+
+    /// Returns the XP the given player would gain by assigning this lamp to the
+    /// given skill, or `None` if it cannot be used on that skill.
+    pub fn xp_for(&self, skill: Skill, player: &PlayerState) -> Option<Xp> {
+        if !self.can_use_on(skill, player) {
+            return None;
+        }
+
+        Some(match self.xp {
+            LampXp::Fixed(xp) => xp,
+            LampXp::PerLevel(xp) => Xp::from_tenths(xp.tenths() * i64::from(player.level(skill).0)),
+        })
+    }
+}
+
+/// The amount of XP granted by an [`XpLamp`].
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum LampXp {
+    /// The same amount of XP, regardless of the chosen skill.
+    Fixed(Xp),
+    /// The given amount of XP for each level in the chosen skill.
+    PerLevel(Xp),
+}
+
+/// Something a quest gives access to.
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct Unlock {
+    pub kind: UnlockKind,
+    pub name: String,
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, strum::Display)]
+pub enum UnlockKind {
+    Area,
+    Spell,
+    Spellbook,
+    Prayer,
+    Equipment,
+    Skill,
+    Feature,
 }
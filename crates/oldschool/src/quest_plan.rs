@@ -0,0 +1,651 @@
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+
+use indexmap::IndexSet;
+use thiserror::Error;
+
+use crate::{
+    COMBAT_SKILLS, Level, PlayerState, Quest, QuestId, QuestNotFoundError, QuestRequirement,
+    RewardChoice, Skill, Xp,
+};
+
+/// A step in a [`QuestPlan`].
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub enum QuestPlanStep {
+    /// Train the given skill up to the given level, gaining the given amount of XP.
+    Train { skill: Skill, level: Level, xp: Xp },
+    /// Meet a requirement that cannot be trained for or completed as part of
+    /// the plan, such as obtaining items or Kudos, or completing a miniquest.
+    Fulfil(QuestRequirement),
+    /// Complete the given quest.
+    Complete(QuestId),
+}
+
+#[derive(Error, Debug)]
+pub enum QuestPlanError {
+    #[error(transparent)]
+    QuestNotFound(#[from] QuestNotFoundError),
+    #[error("quest requirements contain a cycle: {0:?}")]
+    Cycle(Vec<QuestId>),
+    #[error("{quest:?} requires {required} quest points, but only {available} can be obtained")]
+    UnreachableQuestPoints {
+        quest: QuestId,
+        required: u16,
+        available: u16,
+    },
+}
+
+/// An ordered plan for completing a set of quests.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct QuestPlan {
+    pub steps: Vec<QuestPlanStep>,
+}
+
+impl QuestPlan {
+    /// Returns a plan for completing the given quests, starting from the given player state.
+    ///
+    /// Prerequisite quests are completed before the quests that require them,
+    /// and additional quests are completed when needed to meet quest point
+    /// requirements. Whenever a quest requires a higher level than the player
+    /// has, a training step is inserted before it. XP rewards from completed
+    /// quests are applied as the plan progresses.
+    pub fn new(
+        player: &PlayerState,
+        targets: impl IntoIterator<Item = QuestId>,
+    ) -> Result<Self, QuestPlanError> {
+        QuestPlanner::new(player, QuestId::all(), |id| id.quest()).plan(targets)
+    }
+
+    /// Returns a plan for completing the given quests that minimizes the amount
+    /// of manual training required.
+    ///
+    /// Whenever more than one quest can be started, the one requiring the least
+    /// training is completed first, so that the XP rewards from earlier quests
+    /// count towards the skill requirements of later ones.
+    pub fn optimized(
+        player: &PlayerState,
+        targets: impl IntoIterator<Item = QuestId>,
+    ) -> Result<Self, QuestPlanError> {
+        QuestPlanner::new(player, QuestId::all(), |id| id.quest())
+            .minimize_training()
+            .plan(targets)
+    }
+
+    /// Returns a plan for completing the given quests that minimizes the amount
+    /// of manual training required, claiming XP lamps and other choosable
+    /// rewards with the given strategy.
+    pub fn optimized_with_choice(
+        player: &PlayerState,
+        targets: impl IntoIterator<Item = QuestId>,
+        choice: RewardChoice,
+    ) -> Result<Self, QuestPlanError> {
+        QuestPlanner::new(player, QuestId::all(), |id| id.quest())
+            .minimize_training()
+            .reward_choice(choice)
+            .plan(targets)
+    }
+
+    /// Returns a plan for completing every quest, starting from the given player state.
+    pub fn quest_cape(player: &PlayerState) -> Result<Self, QuestPlanError> {
+        Self::new(player, QuestId::all().iter().cloned())
+    }
+
+    /// Returns the quests in this plan, in the order they should be completed.
+    pub fn quests(&self) -> impl Iterator<Item = &QuestId> {
+        self.steps.iter().filter_map(|step| match step {
+            QuestPlanStep::Complete(quest) => Some(quest),
+            QuestPlanStep::Train { .. } | QuestPlanStep::Fulfil(_) => None,
+        })
+    }
+
+    /// Returns the total XP that needs to be trained in each skill to complete this plan.
+    pub fn training(&self) -> BTreeMap<Skill, Xp> {
+        let mut training = BTreeMap::new();
+        for step in &self.steps {
+            if let QuestPlanStep::Train { skill, xp, .. } = step {
+                let total = training.entry(*skill).or_insert(Xp::ZERO);
+                *total = *total + *xp;
+            }
+        }
+
+        training
+    }
+
+    /// Returns the total XP that needs to be trained across all skills to complete this plan.
+    pub fn total_training(&self) -> Xp {
+        self.training().into_values().sum()
+    }
+}
+
+struct QuestPlanner<'a, F> {
+    state: PlayerState,
+    all_quests: &'a [QuestId],
+    lookup: F,
+    quests: HashMap<QuestId, Arc<Quest>>,
+    steps: Vec<QuestPlanStep>,
+    minimize_training: bool,
+    reward_choice: Option<RewardChoice>,
+}
+
+impl<'a, F> QuestPlanner<'a, F>
+where
+    F: Fn(&QuestId) -> Result<Arc<Quest>, QuestNotFoundError>,
+{
+    fn new(player: &PlayerState, all_quests: &'a [QuestId], lookup: F) -> Self {
+        Self {
+            state: player.clone(),
+            all_quests,
+            lookup,
+            quests: HashMap::new(),
+            steps: Vec::new(),
+            minimize_training: false,
+            reward_choice: None,
+        }
+    }
+
+    fn minimize_training(mut self) -> Self {
+        self.minimize_training = true;
+        self
+    }
+
+    fn reward_choice(mut self, choice: RewardChoice) -> Self {
+        self.reward_choice = Some(choice);
+        self
+    }
+
+    fn quest(&mut self, id: &QuestId) -> Result<Arc<Quest>, QuestNotFoundError> {
+        if let Some(quest) = self.quests.get(id) {
+            return Ok(quest.clone());
+        }
+
+        let quest = (self.lookup)(id)?;
+        self.quests.insert(id.clone(), quest.clone());
+
+        Ok(quest)
+    }
+
+    fn plan(
+        mut self,
+        targets: impl IntoIterator<Item = QuestId>,
+    ) -> Result<QuestPlan, QuestPlanError> {
+        let mut remaining = IndexSet::new();
+        let mut stack = IndexSet::new();
+        for target in targets {
+            self.visit(&target, &mut remaining, &mut stack)?;
+        }
+
+        while let Some(next) = remaining.first() {
+            let mut ready = remaining
+                .iter()
+                .map(|id| &self.quests[id])
+                .filter(|quest| self.is_ready(quest));
+            let ready = if self.minimize_training {
+                ready.min_by_key(|quest| self.training_required(quest))
+            } else {
+                ready.next()
+            };
+
+            if let Some(ready) = ready {
+                let ready = ready.clone();
+                remaining.shift_remove(&ready.id);
+                self.complete(&ready);
+                continue;
+            }
+
+            // Every remaining quest is waiting on quest points, so complete
+            // whichever other available quest awards the most (or, when
+            // minimizing training, requires the least training).
+            let Some(filler) = self.best_filler(&remaining)? else {
+                return Err(QuestPlanError::UnreachableQuestPoints {
+                    quest: next.clone(),
+                    required: self.quest_points_required(&self.quests[next]),
+                    available: self.state.quest_points,
+                });
+            };
+            self.complete(&filler);
+        }
+
+        Ok(QuestPlan { steps: self.steps })
+    }
+
+    /// Visits the given quest and its prerequisites in depth-first order, adding
+    /// each quest after its prerequisites.
+    fn visit(
+        &mut self,
+        id: &QuestId,
+        visited: &mut IndexSet<QuestId>,
+        stack: &mut IndexSet<QuestId>,
+    ) -> Result<(), QuestPlanError> {
+        if self.state.has_completed(id) || visited.contains(id) {
+            return Ok(());
+        }
+
+        if let Some(start) = stack.get_index_of(id) {
+            let mut cycle = stack.as_slice()[start..]
+                .iter()
+                .cloned()
+                .collect::<Vec<_>>();
+            cycle.push(id.clone());
+
+            return Err(QuestPlanError::Cycle(cycle));
+        }
+
+        stack.insert(id.clone());
+        let quest = self.quest(id)?;
+        for requirement in Self::requirements(&quest, &self.state) {
+            if let QuestRequirement::Quest(prerequisite) = requirement {
+                self.visit(prerequisite, visited, stack)?;
+            }
+        }
+        stack.pop();
+
+        visited.insert(id.clone());
+
+        Ok(())
+    }
+
+    /// Returns the simple requirements of the given quest, choosing between
+    /// alternatives based on the given player state.
+    fn requirements<'q>(quest: &'q Quest, state: &PlayerState) -> Vec<&'q QuestRequirement> {
+        quest
+            .requirements
+            .iter()
+            .flat_map(|requirement| requirement.flatten(state))
+            .collect()
+    }
+
+    /// Returns whether the given quest only has requirements that can be met
+    /// by training or by the steps that come before it.
+    fn is_ready(&self, quest: &Quest) -> bool {
+        Self::requirements(quest, &self.state)
+            .into_iter()
+            .all(|requirement| match requirement {
+                QuestRequirement::Quest(_) | QuestRequirement::QuestPoints(_) => {
+                    requirement.is_met(&self.state)
+                }
+                _ => true,
+            })
+    }
+
+    /// Returns the XP that needs to be trained before the given quest can be started.
+    fn training_required(&self, quest: &Quest) -> Xp {
+        Self::requirements(quest, &self.state)
+            .into_iter()
+            .filter_map(|requirement| match requirement {
+                QuestRequirement::Skill { skill, level, .. } => {
+                    Some(Xp::from_level(level).saturating_sub(self.state.xp(*skill)))
+                }
+                _ => None,
+            })
+            .sum()
+    }
+
+    fn quest_points_required(&self, quest: &Quest) -> u16 {
+        Self::requirements(quest, &self.state)
+            .into_iter()
+            .filter_map(|requirement| match requirement {
+                QuestRequirement::QuestPoints(quest_points) => Some(*quest_points),
+                _ => None,
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn best_filler(
+        &mut self,
+        remaining: &IndexSet<QuestId>,
+    ) -> Result<Option<Arc<Quest>>, QuestNotFoundError> {
+        let mut best: Option<Arc<Quest>> = None;
+        for id in self.all_quests {
+            if self.state.has_completed(id) || remaining.contains(id) {
+                continue;
+            }
+
+            let quest = self.quest(id)?;
+            if quest.quest_points == 0 || !self.is_ready(&quest) {
+                continue;
+            }
+
+            let is_better = best.as_ref().is_none_or(|best| {
+                if self.minimize_training {
+                    (self.training_required(&quest), Reverse(quest.quest_points))
+                        < (self.training_required(best), Reverse(best.quest_points))
+                } else {
+                    quest.quest_points > best.quest_points
+                }
+            });
+            if is_better {
+                best = Some(quest);
+            }
+        }
+
+        Ok(best)
+    }
+
+    fn complete(&mut self, quest: &Quest) {
+        for requirement in Self::requirements(quest, &self.state) {
+            match requirement {
+                QuestRequirement::Skill { skill, level, .. } => self.train(*skill, *level),
+                QuestRequirement::SkillTotal { skills, level } => {
+                    let mut shortfall = level.0
+                        - skills
+                            .iter()
+                            .map(|skill| self.state.level(*skill).0)
+                            .sum::<i32>();
+                    for skill in skills {
+                        if shortfall <= 0 {
+                            break;
+                        }
+
+                        let current = self.state.level(*skill);
+                        let target = Level((current.0 + shortfall).min(Level::MAX.0));
+                        self.train(*skill, target);
+                        shortfall -= target.0 - current.0;
+                    }
+                }
+                QuestRequirement::CombatLevel(level) => {
+                    // Repeatedly train whichever combat skill reaches the next
+                    // combat level in the fewest levels.
+                    let mut combat = self.state.combat_level();
+                    while combat.level() < *level {
+                        let Some((skill, levels)) = combat
+                            .levels_to_next()
+                            .into_iter()
+                            .min_by_key(|(_, levels)| *levels)
+                        else {
+                            break;
+                        };
+
+                        let current = combat.skill(skill).unwrap_or_default();
+                        combat = combat.with_skill(skill, current + Level(levels));
+                    }
+
+                    for skill in COMBAT_SKILLS {
+                        if let Some(level) = combat.skill(skill) {
+                            self.train(skill, level);
+                        }
+                    }
+                }
+                QuestRequirement::Miniquest(_)
+                | QuestRequirement::Kudos(_)
+                | QuestRequirement::Item { .. } => {
+                    if !requirement.is_met(&self.state) {
+                        self.steps.push(QuestPlanStep::Fulfil(requirement.clone()));
+                        Self::fulfil(&mut self.state, requirement);
+                    }
+                }
+                QuestRequirement::Quest(_)
+                | QuestRequirement::QuestPoints(_)
+                | QuestRequirement::Any(_)
+                | QuestRequirement::All(_) => {}
+            }
+        }
+
+        self.steps.push(QuestPlanStep::Complete(quest.id.clone()));
+        match &self.reward_choice {
+            Some(choice) => self.state.complete_quest_with(quest, choice),
+            None => self.state.complete_quest(quest),
+        }
+    }
+
+    fn train(&mut self, skill: Skill, level: Level) {
+        if self.state.level(skill) >= level {
+            return;
+        }
+
+        self.steps.push(QuestPlanStep::Train {
+            skill,
+            level,
+            xp: Xp::from_level(&level) - self.state.xp(skill),
+        });
+        self.state.set_level(skill, level);
+    }
+
+    fn fulfil(state: &mut PlayerState, requirement: &QuestRequirement) {
+        match requirement {
+            QuestRequirement::Miniquest(miniquest) => {
+                state.completed_miniquests.insert(miniquest.clone());
+            }
+            QuestRequirement::Kudos(kudos) => state.kudos = state.kudos.max(*kudos),
+            QuestRequirement::Item { name, quantity } => {
+                let current = state.items.entry(name.clone()).or_default();
+                *current = (*current).max(*quantity);
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::{QuestDifficulty, QuestLength, QuestReward, ReleaseDate};
+
+    use super::*;
+
+    fn assert_valid_plan(player: &PlayerState, plan: &QuestPlan, choice: Option<&RewardChoice>) {
+        let mut state = player.clone();
+        for step in &plan.steps {
+            match step {
+                QuestPlanStep::Train { skill, level, xp } => {
+                    assert_eq!(state.xp(*skill) + *xp, Xp::from_level(level));
+                    state.set_level(*skill, *level);
+                }
+                QuestPlanStep::Fulfil(requirement) => {
+                    QuestPlanner::<fn(&QuestId) -> _>::fulfil(&mut state, requirement)
+                }
+                QuestPlanStep::Complete(id) => {
+                    let quest = id.quest().unwrap();
+                    assert_eq!(
+                        quest.check(&state).unmet,
+                        Vec::new(),
+                        "{id:?} is not startable"
+                    );
+                    assert!(!state.has_completed(id), "{id:?} is completed twice");
+                    match choice {
+                        Some(choice) => state.complete_quest_with(&quest, choice),
+                        None => state.complete_quest(&quest),
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_quest_cape_plan() {
+        let player = PlayerState::new();
+        let plan = QuestPlan::quest_cape(&player).unwrap();
+
+        assert_valid_plan(&player, &plan, None);
+        assert_eq!(plan.quests().count(), QuestId::all().len());
+        assert!(plan.steps.iter().any(|step| matches!(
+            step,
+            QuestPlanStep::Train {
+                skill: Skill::Magic,
+                level: Level(75),
+                ..
+            }
+        )));
+        assert!(
+            plan.steps
+                .contains(&QuestPlanStep::Fulfil(QuestRequirement::Kudos(100)))
+        );
+    }
+
+    #[test]
+    fn test_optimized_quest_cape_plan() {
+        let player = PlayerState::new();
+        let plan = QuestPlan::quest_cape(&player).unwrap();
+        let optimized = QuestPlan::optimized(&player, QuestId::all().iter().cloned()).unwrap();
+
+        assert_valid_plan(&player, &optimized, None);
+        assert_eq!(optimized.quests().count(), QuestId::all().len());
+        assert!(optimized.total_training() < plan.total_training());
+    }
+
+    #[test]
+    fn test_optimized_plan_order() {
+        let player = PlayerState::new();
+        let targets = [QuestId::THE_KNIGHTS_SWORD, QuestId::DORICS_QUEST];
+
+        // The Knight's Sword requires level 10 Mining, which Doric's Quest rewards.
+        let plan = QuestPlan::new(&player, targets.clone()).unwrap();
+        assert_eq!(
+            plan.training(),
+            BTreeMap::from([(Skill::Mining, Xp::new(1_154))])
+        );
+
+        let optimized = QuestPlan::optimized(&player, targets).unwrap();
+        assert_valid_plan(&player, &optimized, None);
+        assert_eq!(optimized.training(), BTreeMap::new());
+        assert_eq!(
+            optimized.quests().collect::<Vec<_>>(),
+            vec![&QuestId::DORICS_QUEST, &QuestId::THE_KNIGHTS_SWORD]
+        );
+    }
+
+    #[test]
+    fn test_optimized_plan_with_reward_choice() {
+        let player = PlayerState::new();
+        let optimized = QuestPlan::optimized(&player, QuestId::all().iter().cloned()).unwrap();
+
+        let mut goals = BTreeMap::new();
+        for quest_id in QuestId::all() {
+            for (skill, level) in quest_id.resolve_requirements().unwrap().skills {
+                let goal = goals.entry(skill).or_insert(level);
+                *goal = Ord::max(*goal, level);
+            }
+        }
+        let choice = RewardChoice::Goals(goals);
+        let with_choice = QuestPlan::optimized_with_choice(
+            &player,
+            QuestId::all().iter().cloned(),
+            choice.clone(),
+        )
+        .unwrap();
+
+        assert_valid_plan(&player, &with_choice, Some(&choice));
+        assert_eq!(with_choice.quests().count(), QuestId::all().len());
+        assert!(with_choice.total_training() < optimized.total_training());
+    }
+
+    #[test]
+    fn test_optimized_plan_uses_xp_rewards() {
+        let quests = [
+            Quest {
+                id: QuestId::new_static("needs_mining"),
+                name: "Needs Mining".to_string(),
+                quest_points: 1,
+                difficulty: QuestDifficulty::Novice,
+                length: QuestLength::Short,
+                members: false,
+                series: None,
+                release_date: ReleaseDate::new(2001, 1, 4),
+                requirements: IndexSet::from_iter([QuestRequirement::Skill {
+                    skill: Skill::Mining,
+                    level: Level(20),
+                    boostable: true,
+                }]),
+                rewards: Vec::new(),
+            },
+            Quest {
+                id: QuestId::new_static("rewards_mining"),
+                name: "Rewards Mining".to_string(),
+                quest_points: 1,
+                difficulty: QuestDifficulty::Novice,
+                length: QuestLength::Short,
+                members: false,
+                series: None,
+                release_date: ReleaseDate::new(2001, 1, 4),
+                requirements: IndexSet::new(),
+                rewards: vec![QuestReward::Xp {
+                    skill: Skill::Mining,
+                    xp: Xp::new(5_000),
+                }],
+            },
+        ]
+        .map(|quest| (quest.id.clone(), Arc::new(quest)));
+        let all_quests = quests.iter().map(|(id, _)| id.clone()).collect::<Vec<_>>();
+        let quests = HashMap::from(quests);
+
+        let planner = || {
+            QuestPlanner::new(&PlayerState::new(), &all_quests, |id| {
+                quests
+                    .get(id)
+                    .cloned()
+                    .ok_or_else(|| QuestNotFoundError(id.clone()))
+            })
+        };
+
+        let plan = planner().plan(all_quests.clone()).unwrap();
+        assert_eq!(
+            plan.training(),
+            BTreeMap::from([(Skill::Mining, Xp::new(4_470))])
+        );
+
+        let optimized = planner()
+            .minimize_training()
+            .plan(all_quests.clone())
+            .unwrap();
+        assert_eq!(optimized.training(), BTreeMap::new());
+        assert_eq!(
+            optimized.quests().collect::<Vec<_>>(),
+            vec![
+                &QuestId::new_static("rewards_mining"),
+                &QuestId::new_static("needs_mining")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_plan_with_quest_point_requirement() {
+        let player = PlayerState::new();
+        let plan = QuestPlan::new(&player, [QuestId::DRAGON_SLAYER_II]).unwrap();
+
+        assert_valid_plan(&player, &plan, None);
+        assert_eq!(plan.quests().last(), Some(&QuestId::DRAGON_SLAYER_II));
+    }
+
+    #[test]
+    fn test_plan_cycle() {
+        fn quest(id: &'static str, prerequisite: &'static str) -> Arc<Quest> {
+            Arc::new(Quest {
+                id: QuestId::new_static(id),
+                name: id.to_string(),
+                quest_points: 1,
+                difficulty: QuestDifficulty::Novice,
+                length: QuestLength::Short,
+                members: false,
+                series: None,
+                release_date: ReleaseDate::new(2001, 1, 4),
+                requirements: IndexSet::from_iter([QuestRequirement::Quest(QuestId::new_static(
+                    prerequisite,
+                ))]),
+                rewards: Vec::new(),
+            })
+        }
+
+        let quests = [quest("a", "b"), quest("b", "c"), quest("c", "b")]
+            .into_iter()
+            .map(|quest| (quest.id.clone(), quest))
+            .collect::<HashMap<_, _>>();
+        let all_quests = quests.keys().cloned().collect::<Vec<_>>();
+
+        let planner = QuestPlanner::new(&PlayerState::new(), &all_quests, |id| {
+            quests
+                .get(id)
+                .cloned()
+                .ok_or_else(|| QuestNotFoundError(id.clone()))
+        });
+        let error = planner
+            .plan([QuestId::new_static("a")])
+            .expect_err("expected cycle");
+
+        assert_eq!(
+            error.to_string(),
+            r#"quest requirements contain a cycle: [QuestId("b"), QuestId("c"), QuestId("b")]"#
+        );
+    }
+}
@@ -0,0 +1,267 @@
+use std::collections::BTreeMap;
+
+use crate::{Level, PlayerState, QuestReward, Skill, Xp, XpLamp};
+
+/// A strategy for choosing how to claim rewards that let the player make a
+/// choice, such as XP lamps.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum RewardChoice {
+    /// Assign XP to the given skill.
+    ///
+    /// When the XP cannot be assigned to the skill, it is assigned to the
+    /// lowest eligible skill instead.
+    Skill(Skill),
+    /// Assign XP to the eligible skill with the least XP.
+    LowestSkill,
+    /// Assign XP to the eligible skill that is furthest from its goal level.
+    ///
+    /// Once every goal has been reached, XP is assigned to the lowest eligible skill.
+    Goals(BTreeMap<Skill, Level>),
+}
+
+impl RewardChoice {
+    /// Returns the skill the given player would assign the given lamp to, or
+    /// `None` if it cannot be used on any skill.
+    pub fn choose_skill(&self, lamp: &XpLamp, player: &PlayerState) -> Option<Skill> {
+        let eligible = lamp
+            .skills
+            .iter()
+            .copied()
+            .filter(|skill| lamp.can_use_on(*skill, player));
+
+        let chosen = match self {
+            Self::Skill(skill) => eligible.clone().find(|eligible| eligible == skill),
+            Self::LowestSkill => None,
+            Self::Goals(goals) => eligible
+                .clone()
+                .filter_map(|skill| Some((skill, Self::remaining(goals, skill, player)?)))
+                .max_by_key(|(_, remaining)| *remaining)
+                .map(|(skill, _)| skill),
+        };
+
+        chosen.or_else(|| eligible.min_by_key(|skill| player.xp(*skill)))
+    }
+
+    /// Returns the option the given player would choose from a
+    /// [`QuestReward::Choice`], or `None` if there are no options.
+    ///
+    /// Ties are broken in favor of the earliest option.
+    pub fn choose<'a>(
+        &self,
+        options: &'a [Vec<QuestReward>],
+        player: &PlayerState,
+    ) -> Option<&'a [QuestReward]> {
+        options
+            .iter()
+            .map(|option| (option, self.score(&self.resolve(option, player), player)))
+            .fold(
+                None,
+                |best: Option<(&Vec<QuestReward>, _)>, (option, score)| match best {
+                    Some((_, best_score)) if best_score >= score => best,
+                    _ => Some((option, score)),
+                },
+            )
+            .map(|(option, _)| option.as_slice())
+    }
+
+    /// Returns the XP the given player would gain in each skill from the given
+    /// rewards, in the order it is gained.
+    ///
+    /// Lamps are claimed one at a time, so that the XP from earlier lamps
+    /// counts towards the level requirements and scaling of later ones.
+    pub fn resolve(&self, rewards: &[QuestReward], player: &PlayerState) -> Vec<(Skill, Xp)> {
+        let mut state = player.clone();
+        let mut gained = Vec::new();
+        self.resolve_into(rewards, &mut state, &mut gained);
+
+        gained
+    }
+
+    fn resolve_into(
+        &self,
+        rewards: &[QuestReward],
+        state: &mut PlayerState,
+        gained: &mut Vec<(Skill, Xp)>,
+    ) {
+        for reward in rewards {
+            match reward {
+                QuestReward::Xp { skill, xp } => {
+                    state.add_xp(*skill, *xp);
+                    gained.push((*skill, *xp));
+                }
+                QuestReward::Lamp { lamp, count } => {
+                    for _ in 0..*count {
+                        let Some((skill, xp)) = self
+                            .choose_skill(lamp, state)
+                            .and_then(|skill| Some((skill, lamp.xp_for(skill, state)?)))
+                        else {
+                            break;
+                        };
+
+                        state.add_xp(skill, xp);
+                        gained.push((skill, xp));
+                    }
+                }
+                QuestReward::Choice(options) => {
+                    if let Some(option) = self.choose(options, state) {
+                        self.resolve_into(option, state, gained);
+                    }
+                }
+                QuestReward::Item { .. }
+                | QuestReward::Unlock(_)
+                | QuestReward::Kudos(_)
+                | QuestReward::Random(_) => {}
+            }
+        }
+    }
+
+    /// Returns how well the given XP gains suit this strategy, where higher is better.
+    fn score(&self, gained: &[(Skill, Xp)], player: &PlayerState) -> (Xp, Xp) {
+        let total = gained.iter().map(|(_, xp)| *xp).sum();
+
+        match self {
+            Self::Skill(chosen) => (
+                gained
+                    .iter()
+                    .filter(|(skill, _)| skill == chosen)
+                    .map(|(_, xp)| *xp)
+                    .sum(),
+                total,
+            ),
+            Self::LowestSkill => {
+                // Prefer options that go towards the player's lowest skills.
+                let lowest = gained
+                    .iter()
+                    .map(|(skill, _)| player.xp(*skill))
+                    .min()
+                    .unwrap_or(Xp::MAX);
+
+                (Xp::ZERO - lowest, total)
+            }
+            Self::Goals(goals) => {
+                let mut by_skill = BTreeMap::<Skill, Xp>::new();
+                for (skill, xp) in gained {
+                    let total = by_skill.entry(*skill).or_insert(Xp::ZERO);
+                    *total = *total + *xp;
+                }
+
+                let useful = by_skill
+                    .into_iter()
+                    .filter_map(|(skill, xp)| {
+                        Some(Ord::min(xp, Self::remaining(goals, skill, player)?))
+                    })
+                    .sum();
+
+                (useful, total)
+            }
+        }
+    }
+
+    /// Returns the XP the given player still needs to reach their goal in the
+    /// given skill, or `None` if there is no goal for it or it has been reached.
+    fn remaining(goals: &BTreeMap<Skill, Level>, skill: Skill, player: &PlayerState) -> Option<Xp> {
+        let goal = Xp::from_level(goals.get(&skill)?);
+
+        (goal > player.xp(skill)).then(|| goal - player.xp(skill))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::LampXp;
+
+    use super::*;
+
+    fn lamp(xp: LampXp, min_level: Option<Level>) -> XpLamp {
+        XpLamp {
+            xp,
+            skills: vec![Skill::Attack, Skill::Strength, Skill::Defence],
+            min_level,
+        }
+    }
+
+    #[test]
+    fn test_choose_skill() {
+        let mut player = PlayerState::new();
+        player.set_level(Skill::Attack, Level(40));
+        player.set_level(Skill::Strength, Level(30));
+        player.set_level(Skill::Defence, Level(20));
+
+        let lamp = lamp(LampXp::Fixed(Xp::new(1_000)), Some(Level(30)));
+        assert_eq!(
+            RewardChoice::Skill(Skill::Attack).choose_skill(&lamp, &player),
+            Some(Skill::Attack)
+        );
+        assert_eq!(
+            RewardChoice::Skill(Skill::Defence).choose_skill(&lamp, &player),
+            Some(Skill::Strength)
+        );
+        assert_eq!(
+            RewardChoice::Skill(Skill::Magic).choose_skill(&lamp, &player),
+            Some(Skill::Strength)
+        );
+
+        let goals = RewardChoice::Goals(BTreeMap::from([
+            (Skill::Attack, Level(60)),
+            (Skill::Strength, Level(35)),
+        ]));
+        assert_eq!(goals.choose_skill(&lamp, &player), Some(Skill::Attack));
+
+        assert_eq!(
+            RewardChoice::LowestSkill.choose_skill(&lamp, &PlayerState::new()),
+            None
+        );
+    }
+
+    #[test]
+    fn test_resolve() {
+        let mut player = PlayerState::new();
+        player.set_level(Skill::Attack, Level(50));
+
+        let rewards = [
+            QuestReward::Lamp {
+                lamp: lamp(LampXp::PerLevel(Xp::new(10)), None),
+                count: 2,
+            },
+            QuestReward::Choice(vec![
+                vec![QuestReward::Xp {
+                    skill: Skill::Attack,
+                    xp: Xp::new(2_000),
+                }],
+                vec![QuestReward::Xp {
+                    skill: Skill::Defence,
+                    xp: Xp::new(1_500),
+                }],
+            ]),
+        ];
+
+        assert_eq!(
+            RewardChoice::Skill(Skill::Attack).resolve(&rewards, &player),
+            vec![
+                (Skill::Attack, Xp::new(500)),
+                (Skill::Attack, Xp::new(500)),
+                (Skill::Attack, Xp::new(2_000)),
+            ]
+        );
+        assert_eq!(
+            RewardChoice::LowestSkill.resolve(&rewards, &player),
+            vec![
+                (Skill::Strength, Xp::new(10)),
+                (Skill::Defence, Xp::new(10)),
+                (Skill::Defence, Xp::new(1_500)),
+            ]
+        );
+
+        let goals = RewardChoice::Goals(BTreeMap::from([(Skill::Defence, Level(10))]));
+        assert_eq!(
+            goals.resolve(&rewards, &player),
+            vec![
+                (Skill::Defence, Xp::new(10)),
+                (Skill::Defence, Xp::new(10)),
+                (Skill::Defence, Xp::new(1_500)),
+            ]
+        );
+    }
+}
@@ -1,3 +1,5 @@
+use crate::Level;
+
 #[derive(
     Debug,
     Clone,
@@ -38,3 +40,13 @@ pub enum Skill {
     Construction,
     Sailing,
 }
+
+impl Skill {
+    /// Returns the level that a new player starts with in this skill.
+    pub fn starting_level(&self) -> Level {
+        match self {
+            Self::Hitpoints => Level(10),
+            _ => Level(1),
+        }
+    }
+}
@@ -1,26 +1,93 @@
+use std::fmt;
 use std::iter::Sum;
 use std::ops::{Add, Div, Sub};
 
 use crate::{Level, xp_table};
 
-#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
-pub struct Xp(pub f32);
+/// An amount of experience.
+///
+/// XP is stored in tenths, as it is in-game, so that it can be represented exactly.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Default)]
+pub struct Xp(i64);
 
 impl Xp {
-    pub const ZERO: Xp = Xp(0.);
+    pub const ZERO: Xp = Xp(0);
+
+    /// The maximum XP that can be gained in a skill.
+    pub const MAX: Xp = Xp::new(200_000_000);
+
+    /// Returns the given amount of whole XP.
+    pub const fn new(xp: i64) -> Self {
+        Self(xp * 10)
+    }
+
+    /// Returns the given amount of XP, in tenths.
+    pub const fn from_tenths(tenths: i64) -> Self {
+        Self(tenths)
+    }
+
+    /// Returns this amount of XP in tenths.
+    pub const fn tenths(&self) -> i64 {
+        self.0
+    }
+
+    /// Returns this amount of XP in whole XP, discarding any fractional XP.
+    pub const fn whole(&self) -> i64 {
+        self.0 / 10
+    }
+
+    /// Returns this amount of XP as a floating-point number.
+    pub fn as_f64(&self) -> f64 {
+        self.0 as f64 / 10.
+    }
 
     pub fn from_level(level: &Level) -> Xp {
-        *xp_table().get(&level).unwrap()
+        *xp_table().get(level).unwrap()
     }
 
     pub fn max(&self, other: &Self) -> Self {
         Self(self.0.max(other.0))
     }
+
+    /// Returns whether this is the maximum XP that can be gained in a skill.
+    pub fn is_maxed(&self) -> bool {
+        *self >= Self::MAX
+    }
+
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        self.0.checked_add(rhs.0).map(Self)
+    }
+
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.0.checked_sub(rhs.0).map(Self)
+    }
+
+    /// Adds the given XP, capping the result at [`Xp::MAX`].
+    pub fn saturating_add(self, rhs: Self) -> Self {
+        Self(self.0.saturating_add(rhs.0).min(Self::MAX.0))
+    }
+
+    /// Subtracts the given XP, flooring the result at [`Xp::ZERO`].
+    pub fn saturating_sub(self, rhs: Self) -> Self {
+        Self(self.0.saturating_sub(rhs.0).max(Self::ZERO.0))
+    }
+}
+
+impl fmt::Display for Xp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sign = if self.0 < 0 { "-" } else { "" };
+        let tenths = self.0.unsigned_abs();
+
+        match tenths % 10 {
+            0 => write!(f, "{sign}{}", tenths / 10),
+            fraction => write!(f, "{sign}{}.{fraction}", tenths / 10),
+        }
+    }
 }
 
 impl From<i32> for Xp {
     fn from(xp: i32) -> Self {
-        Self(xp as f32)
+        Self::new(xp.into())
     }
 }
 
@@ -40,7 +107,7 @@ impl Add for Xp {
 
 impl Sum for Xp {
     fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
-        iter.fold(Xp(0.), Add::add)
+        iter.fold(Xp::ZERO, Add::add)
     }
 }
 
@@ -53,9 +120,40 @@ impl Sub for Xp {
 }
 
 impl Div for Xp {
-    type Output = Self;
+    type Output = f64;
 
+    /// Returns the ratio between two amounts of XP.
     fn div(self, rhs: Self) -> Self::Output {
-        Self(self.0 / rhs.0)
+        self.0 as f64 / rhs.0 as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_xp_is_exact() {
+        let xp = Xp::from(199_999_999);
+        assert!(!xp.is_maxed());
+        assert_eq!(xp + Xp::from_tenths(10), Xp::MAX);
+
+        let overall = Xp::from(424_043_990);
+        assert_eq!(overall.whole(), 424_043_990);
+        assert_eq!(overall.to_string(), "424043990");
+
+        assert_eq!(Xp::from_tenths(28_124).to_string(), "2812.4");
+        assert_eq!(Xp::from_tenths(-5).to_string(), "-0.5");
+    }
+
+    #[test]
+    fn test_xp_arithmetic() {
+        assert_eq!(Xp::MAX.checked_add(Xp::new(1)), Some(Xp::new(200_000_001)));
+        assert_eq!(Xp::MAX.saturating_add(Xp::new(1)), Xp::MAX);
+        assert_eq!(Xp::new(5).saturating_sub(Xp::new(10)), Xp::ZERO);
+        assert_eq!(Xp::new(5).checked_sub(Xp::new(10)), Some(Xp::new(-5)));
+        assert_eq!(Xp::new(5) / Xp::new(10), 0.5);
     }
 }